@@ -209,13 +209,13 @@ impl Div<&Fixed32> for Fixed32 {
 
 impl DivAssign<Fixed32> for Fixed32 {
     fn div_assign(&mut self, rhs: Fixed32) {
-        *self = Fixed32((((self.0 as i64).wrapping_div((rhs.0 as i64) << 20)) >> 32) as i32);
+        *self = *self / rhs;
     }
 }
 
 impl DivAssign<&Fixed32> for Fixed32 {
     fn div_assign(&mut self, rhs: &Fixed32) {
-        *self = Fixed32((((self.0 as i64).wrapping_div((rhs.0 as i64) << 20)) >> 32) as i32);
+        *self = *self / rhs;
     }
 }
 
@@ -489,4 +489,15 @@ mod tests {
         assert_eq!(val!(10.5) * 2, val!(21));
         assert_eq!(val!(1000) / 25, 40);
     }
+
+    #[test]
+    fn div_assign() {
+        let mut x = val!(1000);
+        x /= val!(25);
+        assert_eq!(x, val!(40));
+        x /= &val!(-0.5);
+        assert_eq!(x, val!(-80));
+        x /= val!(1.45);
+        assert_eq!(x, val!(-80) / val!(1.45));
+    }
 }
@@ -1,22 +1,43 @@
 use crate::{
+    controller::Controller,
+    creep::Creep,
+    damage::{self, Modifiers},
     effect::{Effect, Stat},
-    unit::{Base, BasicUpgrades, Collision, Cost, Projectile, Unit},
+    unit::{Base, BasicUpgrades, Collision, Cost, Faction, Filter, Projectile, Research, Unit},
     *,
 };
-use rand::prelude::*;
 use utils::unsafe_borrow;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum ActionState {
+    /// Idle, no target and no orders
     #[default]
     Wait,
+    /// Has a target and is about to start an attack, or find out that it can't
     Attack,
-    /// Contains the timestamp at which DmgPoint ends, as well as the current attack number
+    /// Moving towards its target until it is in range
+    Approach,
+    /// Contains the timestamp at which DmgPoint ends, as well as the current attack number. The
+    /// unit can't move during the damage point (unless it's `move_and_shoot`), and the attack is
+    /// cancelled if it's given a new order before the first hit
     DmgPoint(Real, u8),
+    /// Contains the timestamp at which the backswing ends. The unit doesn't move during the
+    /// backswing (unless it's `move_and_shoot`), but any new order cancels it
+    Backswing(Real),
+    /// In range of its target, but turning to face it
+    Turn,
+    /// In range of and facing its target, waiting for its weapon to come off cooldown
+    Cooldown,
+    /// Following a move order
     Move,
     Dead,
     /// Contains the handle of the unit that contains this unit
     Cargo(u32),
+    /// Not on the field yet. Contains the timestamp at which the unit arrives
+    Pending(Real),
+    /// On the field and targetable, but can't act until the contained timestamp (e.g. while
+    /// warping in)
+    Inactive(Real),
 }
 
 #[derive(Debug, Clone)]
@@ -32,7 +53,10 @@ pub struct State {
     pub shields: Real,
     pub energy: Option<Real>,
     pub target: Option<u32>,
+    /// Cooldown shared by all of the unit's weapons
     pub attack_cd: Real,
+    /// Index into `Unit::weapons` of the weapon used for the current attack
+    pub weapon: u8,
     pub last_damaged: Option<Real>,
     pub invisible: bool,
     pub burrowed: bool,
@@ -45,6 +69,27 @@ pub struct State {
     // pub spells: Vec<Effect>,
     pub effects: Vec<Effect>,
     pub parent: Option<u32>,
+    /// Where the unit is moving to, if anywhere
+    pub destination: Option<Pos>,
+    /// The direction the unit is looking, in radians. 0 is along the positive x axis
+    pub facing: Real,
+    /// Current speed. Units always move in the direction they're facing
+    pub speed: Real,
+    /// Remaining waypoints when pathing around terrain, in reverse order
+    pub path: Vec<Pos>,
+    /// The terrain cell that `path` leads to
+    pub path_goal: Option<(usize, usize)>,
+    /// True if the unit is standing on creep
+    pub on_creep: bool,
+    /// Contains the timestamp at which transfusion's heal over time ends
+    pub transfusion: Option<Real>,
+    /// The transport (or bunker) the unit is loaded in
+    pub transport: Option<u32>,
+    /// The transport the unit is walking to in order to load into it
+    pub boarding: Option<u32>,
+    /// For transports that are unloading, the timestamp at which the next unit comes out
+    pub unload_at: Option<Real>,
+    pub modifiers: Modifiers,
 }
 
 impl State {
@@ -56,17 +101,35 @@ impl State {
             shields: unit.shields.max,
             target: None,
             attack_cd: const_real!(0),
+            weapon: 0,
             last_damaged: None,
-            invisible: false,
+            invisible: matches!(unit.base, Base::DarkTemplar | Base::Observer),
             move_and_shoot: unit.base == Base::Phoenix,
             untargetable: false,
-            can_attack: unit.weapons[0].is_some() || unit.weapons[1].is_some(),
+            can_attack: unit.has_weapons(),
             energy: (unit.energy_max > 0).then_some(unit.energy_start),
-            burrowed: false,
+            burrowed: matches!(
+                unit.base,
+                Base::WidowMineBurrowed
+                    | Base::RoachBurrowed
+                    | Base::LurkerBurrowed
+                    | Base::InfestorBurrowed
+            ),
             collision: unit.collision,
             effects: Vec::new(),
             parent: None,
             max_speed: unit.movement.speed,
+            destination: None,
+            facing: const_real!(0),
+            speed: const_real!(0),
+            path: Vec::new(),
+            path_goal: None,
+            on_creep: false,
+            transfusion: None,
+            transport: None,
+            boarding: None,
+            unload_at: None,
+            modifiers: Modifiers::default(),
         }
     }
 
@@ -83,6 +146,30 @@ impl State {
         self.hull <= 0
     }
 
+    /// True if the unit has launched an attack that hasn't finished hitting yet (i.e. between the
+    /// hits of a multihit weapon). The unit can't be given new orders until it's done
+    pub fn is_mid_volley(&self) -> bool {
+        matches!(self.state, ActionState::DmgPoint(_, n) if n > 0)
+    }
+
+    /// True if the unit is locked in place by its current attack, or is loaded in a transport
+    pub fn is_movement_locked(&self) -> bool {
+        self.is_loaded()
+            || (!self.move_and_shoot
+                && matches!(
+                    self.state,
+                    ActionState::DmgPoint(..) | ActionState::Backswing(_)
+                ))
+    }
+
+    /// Interrupts the unit's current attack. If the attack hasn't hit yet, the weapon's cooldown
+    /// is refunded. The caller is responsible for setting the new state
+    pub fn interrupt(&mut self, time: Real) {
+        if let ActionState::DmgPoint(_, 0) = self.state {
+            self.attack_cd = time;
+        }
+    }
+
     pub fn reset_speed(&mut self) {
         let effects = unsafe_borrow(&self.effects);
         for effect in effects {
@@ -114,15 +201,48 @@ pub struct Tracker {
     pub death_timestamp: Option<Real>,
 }
 
+/// How a scheduled unit enters the fight
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arrival {
+    /// The unit is on the field and active at the scheduled time, e.g. when walking in from a rally
+    /// point
+    Rally,
+    /// The scheduled time is when the warpgate cycle starts. The unit appears (and is vulnerable)
+    /// for the duration of the warp-in before it can act. Timings come from
+    /// `Unit::with_fast_warpin` and `Unit::with_slow_warpin`
+    WarpIn { fast: bool },
+}
+
+/// A unit that joins the fight after time 0
+#[derive(Debug, Clone, Copy)]
+pub struct Reinforcement {
+    pub handle: u32,
+    /// The unit can't be targeted before this point
+    pub appears: Real,
+    /// The unit can't act before this point
+    pub active: Real,
+    pub pos: Pos,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct Army {
     pub id: usize,
     pub upgrades: BasicUpgrades,
+    pub research: Research,
     pub base_units: Map<Base, Unit>,
     pub units: Vec<State>,
     pub positions: Vec<CollCircle>,
+    /// Where each unit starts the fight. Positions are restored to these values on `reset`
+    pub spawns: Vec<Pos>,
     pub trackers: Vec<Tracker>,
     pub projectiles: Vec<Projectile>,
+    pub reinforcements: Vec<Reinforcement>,
+    pub controller: Controller,
+    /// Which enemy units this army can see, indexed by handle. Empty (i.e. everything is visible)
+    /// until the fight starts
+    pub visible: Vec<bool>,
+    /// (cargo, transport) pairs that are loaded at the start of the fight. See `add_cargo`
+    pub starting_cargo: Vec<(u32, u32)>,
     pub live_carriers: u32,
     pub live_interceptors: u32,
 }
@@ -146,27 +266,52 @@ impl Army {
             let base = &self.base_units[&u.base];
             u.hull = base.hull.max;
             u.shields = base.shields.max;
+            u.untargetable = false;
+            u.max_speed = base.movement.speed;
             u.state = ActionState::Wait;
             u.target = None;
+            u.destination = None;
+            u.facing = const_real!(0);
+            u.speed = const_real!(0);
+            u.path.clear();
+            u.path_goal = None;
+            u.on_creep = false;
+            u.transfusion = None;
+            u.transport = None;
+            u.boarding = None;
+            u.unload_at = None;
+            u.modifiers = Modifiers {
+                hallucination: u.modifiers.hallucination,
+                ..Default::default()
+            };
             u.attack_cd = const_real!(0);
-            u.can_attack = base.weapons[0].is_some() || base.weapons[1].is_some();
+            u.weapon = 0;
+            u.last_damaged = None;
+            u.can_attack = base.has_weapons();
             u.energy = (base.energy_max > 0).then_some(base.energy_start);
             u.effects.clear();
         }
-        self.positions.iter_mut().for_each(|x| {
-            x.pos = Pos {
-                x: const_real!(0),
-                y: const_real!(0),
-            };
-        });
+        for (circle, &spawn) in self.positions.iter_mut().zip(self.spawns.iter()) {
+            circle.pos = spawn;
+        }
         self.projectiles.clear();
         self.trackers.fill(Tracker::default());
+
+        for r in self.reinforcements.iter() {
+            let u = &mut self.units[r.handle as usize];
+            u.state = ActionState::Pending(r.appears);
+            u.untargetable = true;
+            u.can_attack = false;
+        }
+
+        self.reset_cargo();
     }
 
     /// Used internally to bump the capacity of all non-projectile vecs in the army
     fn reserve(&mut self, count: usize) {
         self.units.reserve(count);
         self.positions.reserve(count);
+        self.spawns.reserve(count);
         self.trackers.reserve(count);
     }
 
@@ -200,19 +345,140 @@ impl Army {
                 r: unit.size,
                 plane: unit.collision,
             });
+            self.spawns.push(Pos::default());
+            self.trackers.push(Tracker::default());
             if unit.base == Base::Carrier {
                 let handle = self.units.len() - 1;
                 for _ in 0..8 {
                     self.units
-                        .push(State::new(&Unit::INTERCEPTOR).with_parent(handle))
+                        .push(State::new(&Unit::INTERCEPTOR).with_parent(handle));
+                    // every unit needs a position and tracker so that handles stay aligned
+                    self.positions.push(CollCircle {
+                        pos: Pos::default(),
+                        r: Unit::INTERCEPTOR.size,
+                        plane: Unit::INTERCEPTOR.collision,
+                    });
+                    self.spawns.push(Pos::default());
+                    self.trackers.push(Tracker::default());
                 }
             }
-            self.trackers.push(Tracker::default());
         }
 
         self.base_units.insert(unit.base, unit);
     }
 
+    /// Adds `count` copies of the specified unit to the army in a square formation centered on
+    /// `pos`.
+    pub fn add_unit_at(&mut self, unit: Unit, count: usize, pos: Pos) {
+        let start = self.units.len();
+        let spacing = unit.size * 2;
+        let columns = (count as f64).sqrt().ceil() as usize;
+        let offset = spacing * real!(columns.saturating_sub(1)) / 2;
+
+        self.add_unit(unit, count);
+
+        let mut i = 0;
+        for handle in start..self.units.len() {
+            // interceptors launch from their carrier
+            let spot = match self.units[handle].parent {
+                Some(parent) => self.spawns[parent as usize],
+                None => {
+                    let spot = Pos {
+                        x: pos.x + spacing * real!(i / columns) - offset,
+                        y: pos.y + spacing * real!(i % columns) - offset,
+                    };
+                    i += 1;
+                    spot
+                }
+            };
+
+            self.positions[handle].pos = spot;
+            self.spawns[handle] = spot;
+        }
+    }
+
+    /// Adds `count` copies of the specified unit that join the fight at `time` and `pos`. See
+    /// `Arrival` for how `time` is interpreted.
+    ///
+    /// Units that haven't arrived yet still count as alive, so a fight isn't over until every
+    /// scheduled unit has arrived and died.
+    pub fn schedule_unit(&mut self, unit: Unit, count: usize, time: Real, pos: Pos, arrival: Arrival) {
+        let (appears, active) = match arrival {
+            Arrival::Rally => (time, time),
+            Arrival::WarpIn { fast } => {
+                let (warped, duration) = if fast {
+                    (unit.clone().with_fast_warpin(), Unit::FAST_WARPIN)
+                } else {
+                    (unit.clone().with_slow_warpin(), Unit::SLOW_WARPIN)
+                };
+                let active = time + warped.cost.build_time;
+                (active - duration, active)
+            }
+        };
+
+        let start = self.units.len();
+        self.add_unit(unit, count);
+
+        for handle in start..self.units.len() {
+            self.positions[handle].pos = pos;
+            self.spawns[handle] = pos;
+            let u = &mut self.units[handle];
+            u.state = ActionState::Pending(appears);
+            u.untargetable = true;
+            u.can_attack = false;
+
+            self.reinforcements.push(Reinforcement {
+                handle: handle as u32,
+                appears,
+                active,
+                pos,
+            });
+        }
+    }
+
+    /// Adds `count` units that come out of `structures` production buildings in parallel, starting
+    /// at `start`, and take `travel` seconds to walk from the rally point to `pos`
+    pub fn schedule_production(
+        &mut self,
+        unit: Unit,
+        count: usize,
+        start: Real,
+        structures: usize,
+        travel: Real,
+        pos: Pos,
+    ) {
+        let structures = structures.max(1);
+        for i in 0..count {
+            let round = real!(i / structures + 1);
+            let time = start + unit.cost.build_time * round + travel;
+            self.schedule_unit(unit.clone(), 1, time, pos, Arrival::Rally);
+        }
+    }
+
+    /// Moves scheduled units onto the field and activates them once their timers are up
+    pub(crate) fn update_arrivals(&mut self, time: Real) {
+        for r in self.reinforcements.iter() {
+            let handle = r.handle as usize;
+            let u = &mut self.units[handle];
+            match u.state {
+                ActionState::Pending(t) if t <= time => {
+                    u.untargetable = false;
+                    u.state = ActionState::Inactive(r.active);
+                    self.positions[handle].pos = r.pos;
+                }
+                _ => (),
+            }
+
+            if let ActionState::Inactive(t) = u.state {
+                if t <= time {
+                    let base = &self.base_units[&u.base];
+                    u.state = ActionState::Wait;
+                    u.can_attack = base.has_weapons();
+                }
+            }
+        }
+    }
+
     // /// Adds `count` copies of the specified unit to the army, each containing `cargo.1` copies of `cargo.0`
     // ///
     // /// Useful for things like carriers, medivacs, bunkers, etc.
@@ -230,6 +496,27 @@ impl Army {
     //     self.base_units.insert(unit.base, unit);
     // }
 
+    /// The `Filter`s of the unit at `handle`, including its current state. `visible` is whether the
+    /// attacker can see it
+    pub fn filter_props(&self, handle: usize, visible: bool) -> u32 {
+        let unit = &self.units[handle];
+        let mut props = self.unit_from_handle(handle).filter_props();
+
+        if visible {
+            props |= Filter::Visible as u32;
+        }
+        if unit.is_dead() {
+            props |= Filter::Dead as u32;
+        }
+        if unit.is_loaded() {
+            props |= Filter::Hidden as u32;
+        } else if unit.untargetable {
+            props |= Filter::Invulnerable as u32;
+        }
+
+        props
+    }
+
     pub fn unit_from_handle<N: TryInto<usize>>(&self, handle: N) -> &Unit
     where
         <N as TryInto<usize>>::Error: std::fmt::Debug,
@@ -250,11 +537,18 @@ impl Army {
         &self.units
     }
 
-    pub(crate) fn heal(&mut self, time: Real) {
+    pub(crate) fn heal(&mut self, time: Real, creep: Option<&Creep>) {
         self.units.iter_mut().for_each(|unit| {
             if unit.is_alive() {
                 let base = &self.base_units[&unit.base];
-                unit.hull = base.hull.max.min(unit.hull + (base.hull.regen * TICK));
+                let mut regen = base.hull.regen;
+                if unit.on_creep && base.faction == Faction::Zerg {
+                    regen *= creep.map_or(const_real!(1), |c| c.regen);
+                }
+                if unit.transfusion.is_some_and(|t| t > time) {
+                    regen += TRANSFUSION_REGEN;
+                }
+                unit.hull = base.hull.max.min(unit.hull + (regen * TICK));
 
                 if unit
                     .last_damaged
@@ -267,42 +561,68 @@ impl Army {
                 }
 
                 if let Some(x) = unit.energy {
-                    unit.energy = Some(base.energy_max.min(x + ENERGY_REGEN))
+                    unit.energy = Some(base.energy_max.min(x + ENERGY_REGEN * TICK))
                 }
             }
         });
     }
 
-    /// Any units with no target or a dead target swap
-    pub(crate) fn acquire_targets(&mut self, opnt: &mut Army, rng: &mut StdRng) {
-        for unit in self.units.iter_mut() {
-            if unit
-                .target
-                .is_some_and(|x| opnt.units[x as usize].is_dead())
-            {
-                unit.target = None;
+    /// Shield batteries automatically recharge the shields of the ally in range with the lowest
+    /// shield fraction, for as long as they have energy. The in-game minimum of 10 energy to start
+    /// recharging isn't modelled
+    pub(crate) fn recharge_shields(&mut self) {
+        for b in 0..self.units.len() {
+            let battery = &self.units[b];
+            let Some(energy) = battery.energy else {
+                continue;
+            };
+            if battery.base != Base::ShieldBattery || battery.is_dead() || energy <= 0 {
+                continue;
             }
 
-            if unit.target.is_none() {
-                let mut handle = rng.gen_range(0..opnt.units.len());
-                while opnt.units[handle].is_dead()
-                    || self.base_units[&unit.base]
-                        .try_get_weapon(&opnt.base_units[&opnt.units[handle].base])
-                        .is_none()
-                {
-                    handle = rng.gen_range(0..opnt.units.len());
-                }
+            let pos = self.positions[b];
+            let target = (0..self.units.len())
+                .filter(|&t| {
+                    let u = &self.units[t];
+                    let c = self.positions[t];
+                    t != b
+                        && u.is_alive()
+                        && !u.is_loaded()
+                        && !matches!(u.state, ActionState::Pending(_))
+                        && u.shields < self.unit_from_handle(t).shields.max
+                        && pos.pos.within(c.pos, SHIELD_BATTERY_RANGE + pos.r + c.r)
+                })
+                .min_by_key(|&t| {
+                    (
+                        self.units[t].shields / self.unit_from_handle(t).shields.max,
+                        pos.pos.dist_squared(self.positions[t].pos),
+                    )
+                });
+            let Some(t) = target else {
+                continue;
+            };
 
-                unit.target = Some(handle as u32);
-                unit.state = ActionState::Attack;
-            }
+            let missing = self.unit_from_handle(t).shields.max - self.units[t].shields;
+            let amount = (SHIELD_BATTERY_RATE * TICK)
+                .min(missing)
+                .min(energy / SHIELD_BATTERY_DRAIN);
+            self.units[t].shields += amount;
+            self.units[b].energy = Some(energy - amount * SHIELD_BATTERY_DRAIN);
         }
     }
 
     pub(crate) fn reset_speed(&mut self, handle: usize, stat: Stat) {
-        let speed = self.unit_from_handle(handle).movement.speed;
+        let movement = &self.unit_from_handle(handle).movement;
+        let (speed, creep_speed) = (movement.speed, movement.creep_speed);
         let state = &mut self.units[handle];
-        state.max_speed = speed;
+        state.max_speed = if state.on_creep && creep_speed > 0 {
+            speed * creep_speed
+        } else {
+            speed
+        };
+        if let Some(mult) = damage::speed_multiplier(&state.modifiers) {
+            state.max_speed *= mult;
+        }
         let effects = unsafe_borrow(&state.effects);
 
         for effect in effects {
@@ -340,7 +660,72 @@ impl Army {
         self.units.iter().map(|x| x.hull + x.shields).sum()
     }
 
+    /// Current hull + shields of the units that are still alive. Unlike `total_health_curr`, dead
+    /// units' (possibly negative) hull isn't counted
+    pub fn living_health(&self) -> Real {
+        self.units
+            .iter()
+            .filter(|x| x.is_alive())
+            .map(|x| x.hull + x.shields)
+            .sum()
+    }
+
     pub fn damage_dealt(&self) -> Real {
         self.trackers.iter().map(|x| x.damage_dealt).sum()
     }
+
+    pub fn overkill(&self) -> Real {
+        self.trackers.iter().map(|x| x.overkill).sum()
+    }
+
+    pub fn units_alive(&self) -> usize {
+        self.units.iter().filter(|x| x.is_alive()).count()
+    }
+
+    /// Sum of the cost of all living units
+    pub fn resources_remaining(&self) -> Cost {
+        self.units
+            .iter()
+            .filter(|x| x.is_alive())
+            .map(|x| self.base_units[&x.base].cost)
+            .sum()
+    }
+
+    pub fn supply_remaining(&self) -> Real {
+        self.resources_remaining().supply
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn energy_regen() {
+        let mut army = Army::default();
+        army.add_unit(Unit::SENTRY, 1);
+        army.units[0].energy = Some(const_real!(0));
+
+        // ten seconds' worth of ticks
+        for i in 0..224 {
+            army.heal(TICK * i, None);
+        }
+
+        let energy: Real = army.units[0].energy.unwrap();
+        let expected: Real = ENERGY_REGEN * 10;
+        assert!(
+            (energy - expected).abs() < const_real!(0.1),
+            "{energy} vs {expected}"
+        );
+    }
+
+    #[test]
+    fn living_health() {
+        let mut army = Army::default();
+        army.add_unit(Unit::MARINE, 2);
+        army.units[1].hull = const_real!(-5);
+
+        assert_eq!(army.living_health(), 45);
+        assert_eq!(army.total_health_curr(), 40);
+    }
 }
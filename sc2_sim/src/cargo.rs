@@ -0,0 +1,313 @@
+use crate::{
+    army::{ActionState, Army, State},
+    unit::{Base, Collision, Faction, Flag, Unit},
+    *,
+};
+
+impl State {
+    /// True if the unit is inside a transport or bunker
+    pub fn is_loaded(&self) -> bool {
+        self.transport.is_some()
+    }
+
+    /// True if the unit can move on its own. Loaded units are carried by their transport
+    pub fn can_move(&self) -> bool {
+        self.max_speed > 0 && self.transport.is_none()
+    }
+}
+
+/// Where the unit at `handle` attacks from. Units in a bunker attack from the bunker itself, with
+/// the bunker's range bonus folded into its radius so that `Weapon::in_range` can be used as-is.
+///
+/// Only bunker cargo can attack, everything else that's loaded is in `ActionState::Cargo`
+pub(crate) fn attack_circle(unit: &State, positions: &[CollCircle], handle: usize) -> CollCircle {
+    match unit.transport {
+        Some(t) if !matches!(unit.state, ActionState::Cargo(_)) => {
+            let bunker = positions[t as usize];
+            CollCircle {
+                r: bunker.r + BUNKER_RANGE_BONUS,
+                ..bunker
+            }
+        }
+        _ => positions[handle],
+    }
+}
+
+impl Army {
+    /// Total `cargo_size` of the units currently loaded into `transport`
+    pub fn cargo_used(&self, transport: usize) -> Real {
+        self.units
+            .iter()
+            .enumerate()
+            .filter(|(_, u)| u.is_alive() && u.transport == Some(transport as u32))
+            .map(|(h, _)| self.unit_from_handle(h).cargo_size)
+            .sum()
+    }
+
+    /// True if the unit at `cargo` fits into `transport` right now. Bunkers only take Terran
+    /// infantry and planetary fortresses only take SCVs. Range isn't checked
+    pub fn can_load(&self, transport: usize, cargo: usize) -> bool {
+        let t = &self.units[transport];
+        let c = &self.units[cargo];
+        let t_base = self.unit_from_handle(transport);
+        let c_base = self.unit_from_handle(cargo);
+
+        let allowed = match t.base {
+            Base::Bunker => c_base.faction == Faction::Terran && c_base.has_flag(Flag::Biological),
+            Base::PlanetaryFortress => c.base == Base::SCV,
+            _ => true,
+        };
+
+        allowed
+            && transport != cargo
+            && t.is_alive()
+            && c.is_alive()
+            && !t.is_loaded()
+            && !c.is_loaded()
+            && c.parent.is_none()
+            && !matches!(t.state, ActionState::Pending(_))
+            && !matches!(c.state, ActionState::Pending(_) | ActionState::Inactive(_))
+            && c.collision == Collision::Ground
+            && c_base.cargo_size > 0
+            && c_base.cargo_size <= t_base.max_cargo_size
+            && self.cargo_used(transport) + c_base.cargo_size <= t_base.cargo_capacity
+    }
+
+    /// Loads the unit at `cargo` into `transport`. Returns false (and does nothing) if it doesn't
+    /// fit, see `can_load`.
+    ///
+    /// Loaded units can't be targeted. Units in a bunker keep attacking from it, everything else
+    /// is put in `ActionState::Cargo` until it's unloaded.
+    pub fn load(&mut self, transport: usize, cargo: usize, time: Real) -> bool {
+        if !self.can_load(transport, cargo) {
+            return false;
+        }
+
+        let bunker = self.units[transport].base == Base::Bunker;
+        let c = &mut self.units[cargo];
+        c.interrupt(time);
+        c.transport = Some(transport as u32);
+        c.boarding = None;
+        c.target = None;
+        c.destination = None;
+        c.path.clear();
+        c.path_goal = None;
+        c.speed = const_real!(0);
+        c.untargetable = true;
+        c.state = if bunker {
+            ActionState::Wait
+        } else {
+            ActionState::Cargo(transport as u32)
+        };
+        self.positions[cargo].pos = self.positions[transport].pos;
+
+        true
+    }
+
+    /// Adds `count` copies of `unit` loaded into the transport at `transport`. Units that don't
+    /// fit are left standing next to it. The cargo is reloaded on `reset`
+    pub fn add_cargo(&mut self, transport: usize, unit: Unit, count: usize) {
+        let start = self.units.len();
+        let pos = self.spawns[transport];
+        self.add_unit(unit, count);
+
+        for handle in start..self.units.len() {
+            self.positions[handle].pos = pos;
+            self.spawns[handle] = pos;
+            if self.load(transport, handle, const_real!(0)) {
+                self.starting_cargo.push((handle as u32, transport as u32));
+            }
+        }
+    }
+
+    /// Drops the unit at `cargo` out of its transport. Flying transports drop units straight down,
+    /// ground ones place them in a ring around themselves
+    fn eject(&mut self, cargo: usize, slot: usize) {
+        let Some(transport) = self.units[cargo].transport else {
+            return;
+        };
+        let t_circle = self.positions[transport as usize];
+        let c_circle = &mut self.positions[cargo];
+
+        c_circle.pos = if t_circle.plane == Collision::Ground {
+            let angle = std::f64::consts::TAU * (slot % 8) as f64 / 8.0;
+            let dist = t_circle.r + c_circle.r;
+            Pos {
+                x: t_circle.pos.x + dist * real!(angle.cos()),
+                y: t_circle.pos.y + dist * real!(angle.sin()),
+            }
+        } else {
+            t_circle.pos
+        };
+
+        let c = &mut self.units[cargo];
+        c.transport = None;
+        c.untargetable = false;
+        c.target = None;
+        c.destination = None;
+        c.state = ActionState::Wait;
+    }
+
+    /// Starts unloading the transport at `transport`, one unit every `UNLOAD_PERIOD` (or all at
+    /// once for ground transports like bunkers)
+    pub fn unload(&mut self, transport: usize, time: Real) {
+        if self.units[transport].is_alive() && self.units[transport].unload_at.is_none() {
+            self.units[transport].unload_at = Some(time);
+        }
+    }
+
+    /// Handles boarding, unloading, and transports dying, and keeps cargo on top of its transport.
+    ///
+    /// Cargo is ejected when a bunker (or planetary fortress) dies, but dies along with a flying
+    /// transport.
+    pub(crate) fn update_cargo(&mut self, time: Real) {
+        for handle in 0..self.units.len() {
+            let unit = &self.units[handle];
+            if unit.is_dead() {
+                continue;
+            }
+
+            if let Some(t) = unit.boarding {
+                let t = t as usize;
+                if !self.can_load(t, handle) {
+                    let u = &mut self.units[handle];
+                    u.boarding = None;
+                    u.destination = None;
+                    u.state = ActionState::Wait;
+                } else if self.positions[handle].pos.within(
+                    self.positions[t].pos,
+                    LOAD_RANGE + self.positions[handle].r + self.positions[t].r,
+                ) {
+                    self.load(t, handle, time);
+                } else {
+                    self.units[handle].destination = Some(self.positions[t].pos);
+                }
+                continue;
+            }
+
+            let Some(t) = unit.transport else {
+                continue;
+            };
+            let t = t as usize;
+
+            if self.units[t].is_dead() {
+                if self.positions[t].plane == Collision::Ground {
+                    self.eject(handle, handle);
+                } else {
+                    self.units[handle].hull = const_real!(0);
+                    self.trackers[handle].death_timestamp = Some(time);
+                }
+                continue;
+            }
+
+            self.positions[handle].pos = self.positions[t].pos;
+        }
+
+        for t in 0..self.units.len() {
+            let Some(at) = self.units[t].unload_at else {
+                continue;
+            };
+            if at > time {
+                continue;
+            }
+
+            let cargo: Vec<usize> = (0..self.units.len())
+                .filter(|&h| self.units[h].is_alive() && self.units[h].transport == Some(t as u32))
+                .collect();
+            let ground = self.positions[t].plane == Collision::Ground;
+
+            for (slot, &c) in cargo.iter().enumerate() {
+                self.eject(c, slot);
+                if !ground {
+                    break;
+                }
+            }
+
+            self.units[t].unload_at = (!ground && cargo.len() > 1).then_some(time + UNLOAD_PERIOD);
+        }
+    }
+
+    /// Reloads the cargo that the army started the fight with. Called from `reset`
+    pub(crate) fn reset_cargo(&mut self) {
+        for i in 0..self.starting_cargo.len() {
+            let (cargo, transport) = self.starting_cargo[i];
+            self.positions[cargo as usize].pos = self.spawns[transport as usize];
+            self.load(transport as usize, cargo as usize, const_real!(0));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::controller::{in_range, View};
+
+    fn loaded(transport: Unit) -> Army {
+        let mut army = Army::default();
+        army.add_unit(transport, 1);
+        army.add_cargo(0, Unit::MARINE, 1);
+        army
+    }
+
+    #[test]
+    fn bunker_range() {
+        let army = loaded(Unit::BUNKER);
+        let mut enemy = Army::default();
+        enemy.add_unit(Unit::MARINE, 1);
+
+        // half a unit past the marine's range, measured from the edge of the bunker
+        let range = Unit::MARINE.weapons[0].range.end();
+        let edge = army.positions[0].r + enemy.positions[0].r;
+        enemy.positions[0].pos.x = edge + range + const_real!(0.5);
+
+        let view = View {
+            army: &army,
+            enemy: &enemy,
+            time: real!(0),
+            creep: None,
+        };
+        assert!(in_range(&view, 1, 0));
+
+        enemy.positions[0].pos.x = edge + range + BUNKER_RANGE_BONUS + const_real!(0.5);
+        let view = View {
+            army: &army,
+            enemy: &enemy,
+            time: real!(0),
+            creep: None,
+        };
+        assert!(!in_range(&view, 1, 0));
+    }
+
+    #[test]
+    fn cargo_untargetable() {
+        let army = loaded(Unit::MEDIVAC);
+        let mut enemy = Army::default();
+        enemy.add_unit(Unit::VIKINGAIR, 1);
+
+        let view = View {
+            army: &enemy,
+            enemy: &army,
+            time: real!(0),
+            creep: None,
+        };
+        assert!(view.can_target(0, 0));
+        assert!(!view.can_target(0, 1));
+    }
+
+    #[test]
+    fn transport_death() {
+        // cargo dies with a medivac
+        let mut army = loaded(Unit::MEDIVAC);
+        army.units[0].hull = const_real!(0);
+        army.update_cargo(real!(0));
+        assert!(army.units[1].is_dead());
+
+        // but walks out of a bunker
+        let mut army = loaded(Unit::BUNKER);
+        army.units[0].hull = const_real!(0);
+        army.update_cargo(real!(0));
+        assert!(army.units[1].is_alive());
+        assert!(!army.units[1].is_loaded());
+        assert!(!army.units[1].untargetable);
+    }
+}
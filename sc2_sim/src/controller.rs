@@ -0,0 +1,388 @@
+use std::cmp::Reverse;
+
+use rand::prelude::*;
+
+use crate::{
+    army::{ActionState, Army},
+    cargo::attack_circle,
+    creep::Creep,
+    damage::Buff,
+    effect::Effect,
+    unit::{Priority, ThreatLevel, Weapon},
+    utils::angle_diff,
+    *,
+};
+
+/// An order issued to a single unit
+#[derive(Debug, Clone)]
+pub enum Command {
+    /// Attack the enemy unit with the given handle
+    Attack(u32),
+    /// Move to a position, ignoring enemies along the way
+    Move(Pos),
+    /// Drop the current target and stop moving
+    Hold,
+    /// Applies an effect to the unit itself (e.g. stim)
+    Ability(Effect),
+    /// Queen only: transfuse the friendly unit with the given handle. Ignored unless the queen is on
+    /// creep, has the energy, and is in range. See `Army::transfuse`
+    Transfuse(u32),
+    /// Walk to the friendly transport (or bunker) with the given handle and load into it. See
+    /// `Army::can_load` for what fits where
+    Load(u32),
+    /// Transports only: unload all cargo. See `Army::unload`
+    Unload,
+    /// Cast a buff, e.g. stim or Guardian Shield. Ignored if the unit can't cast it right now. See
+    /// `Army::cast`
+    Buff(Buff),
+}
+
+/// A read-only snapshot of the fight, passed to `ArmyController`s each tick
+#[derive(Debug, Clone, Copy)]
+pub struct View<'a> {
+    pub army: &'a Army,
+    pub enemy: &'a Army,
+    pub time: Real,
+    pub creep: Option<&'a Creep>,
+}
+
+impl<'a> View<'a> {
+    /// True if the unit at `handle` in `self.army` can act this tick
+    pub fn is_active(&self, handle: usize) -> bool {
+        let unit = &self.army.units[handle];
+        unit.is_alive()
+            && !matches!(
+                unit.state,
+                ActionState::Pending(_) | ActionState::Inactive(_) | ActionState::Cargo(_)
+            )
+    }
+
+    /// True if the unit at `handle` in `self.army` is able to attack the enemy unit at `target`
+    pub fn can_target(&self, handle: usize, target: usize) -> bool {
+        let t = &self.enemy.units[target];
+        let visible = self.army.can_see(target);
+        t.is_alive()
+            && !t.untargetable
+            && visible
+            && self
+                .army
+                .unit_from_handle(handle)
+                .weapon_for(self.enemy.filter_props(target, visible))
+                .is_some()
+    }
+
+    /// True if the unit's current target is still valid. Units that can't move (e.g. static
+    /// defense) also need their target to be in range
+    pub fn has_valid_target(&self, handle: usize) -> bool {
+        self.army.units[handle]
+            .target
+            .is_some_and(|t| self.can_acquire(handle, t as usize))
+    }
+
+    /// The weapon the unit at `handle` would attack the enemy at `target` with from where they
+    /// both are now, see `Unit::select_weapon`
+    pub fn weapon_against(&self, handle: usize, target: usize) -> Option<&'a Weapon> {
+        let army: &'a Army = self.army;
+        let props = self.enemy.filter_props(target, army.can_see(target));
+        let circle = attack_circle(&army.units[handle], &army.positions, handle);
+        army.unit_from_handle(handle)
+            .weapon_against(props, circle, self.enemy.positions[target])
+    }
+
+    /// True if the unit at `handle` could pick the enemy at `target` as a new target. Same as
+    /// `can_target`, except that units which can't move only acquire targets in range
+    pub fn can_acquire(&self, handle: usize, target: usize) -> bool {
+        if !self.army.units[handle].can_move() {
+            in_range(self, handle, target)
+        } else {
+            self.can_target(handle, target)
+        }
+    }
+
+    /// The highest threat level among the enemies that the unit at `handle` could acquire
+    pub fn top_priority(&self, handle: usize) -> Option<ThreatLevel> {
+        (0..self.enemy.units.len())
+            .filter(|&t| self.can_acquire(handle, t))
+            .map(|t| self.enemy.unit_from_handle(t).target_priority)
+            .max()
+    }
+}
+
+/// Decides what each unit in an army does. The coordinator calls `commands` once per tick, before
+/// any attacks are processed.
+///
+/// Units that aren't given a command keep doing whatever they were doing.
+pub trait ArmyController: ControllerClone + std::fmt::Debug {
+    fn commands(&mut self, view: &View, rng: &mut StdRng) -> Vec<(u32, Command)>;
+}
+
+/// Allows `Box<dyn ArmyController>` to be cloned. Implemented automatically for any controller that
+/// is `Clone`
+pub trait ControllerClone {
+    fn box_clone(&self) -> Box<dyn ArmyController>;
+}
+
+impl<T: ArmyController + Clone + 'static> ControllerClone for T {
+    fn box_clone(&self) -> Box<dyn ArmyController> {
+        Box::new(self.clone())
+    }
+}
+
+/// Wrapper so that `Army` can keep deriving `Clone`, `Debug`, and `Default`
+#[derive(Debug)]
+pub struct Controller(pub Box<dyn ArmyController>);
+
+impl Controller {
+    pub fn new(controller: impl ArmyController + 'static) -> Self {
+        Self(Box::new(controller))
+    }
+}
+
+impl Clone for Controller {
+    fn clone(&self) -> Self {
+        Self(self.0.box_clone())
+    }
+}
+
+impl Default for Controller {
+    fn default() -> Self {
+        Self::new(AMove)
+    }
+}
+
+/// The default behavior: every unit without a valid target picks a random enemy it can hit
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AMove;
+
+impl ArmyController for AMove {
+    fn commands(&mut self, view: &View, rng: &mut StdRng) -> Vec<(u32, Command)> {
+        let mut result = Vec::new();
+
+        for (handle, unit) in view.army.units.iter().enumerate() {
+            if !view.is_active(handle) || unit.boarding.is_some() || view.has_valid_target(handle) {
+                continue;
+            }
+
+            let target = match view.army.unit_from_handle(handle).weapons.first() {
+                Some(Weapon {
+                    priority: Priority::LeastAngle,
+                    ..
+                }) => least_angle_target(view, handle),
+                _ => random_target(view, handle, rng),
+            };
+
+            match target {
+                Some(t) => result.push((handle as u32, Command::Attack(t))),
+                None if unit.target.is_some() => result.push((handle as u32, Command::Hold)),
+                None => (),
+            }
+        }
+
+        result
+    }
+}
+
+/// Units never move, and only attack the closest enemy in range. Useful for walling off chokes
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HoldPosition;
+
+impl ArmyController for HoldPosition {
+    fn commands(&mut self, view: &View, _rng: &mut StdRng) -> Vec<(u32, Command)> {
+        let mut result = Vec::new();
+
+        for (handle, unit) in view.army.units.iter().enumerate() {
+            if !view.is_active(handle) || unit.boarding.is_some() {
+                continue;
+            }
+
+            if unit.target.is_some_and(|t| in_range(view, handle, t as usize)) {
+                continue;
+            }
+
+            match closest_in_range(view, handle) {
+                Some(t) => result.push((handle as u32, Command::Attack(t))),
+                None if unit.target.is_some() || unit.destination.is_some() => {
+                    result.push((handle as u32, Command::Hold))
+                }
+                None => (),
+            }
+        }
+
+        result
+    }
+}
+
+/// Returns a random enemy that the unit at `handle` can attack, or `None` if there aren't any.
+/// Only enemies with the highest threat level are considered (e.g. units are picked over most
+/// structures)
+pub fn random_target(view: &View, handle: usize, rng: &mut StdRng) -> Option<u32> {
+    let count = view.enemy.units.len();
+
+    // without this, the loop below never terminates
+    let priority = view.top_priority(handle)?;
+    let valid = |t: usize| {
+        view.can_acquire(handle, t) && view.enemy.unit_from_handle(t).target_priority == priority
+    };
+
+    let mut target = rng.gen_range(0..count);
+    while !valid(target) {
+        target = rng.gen_range(0..count);
+    }
+
+    Some(target as u32)
+}
+
+/// True if the unit at `handle` can attack the enemy at `target` without moving
+pub fn in_range(view: &View, handle: usize, target: usize) -> bool {
+    view.can_target(handle, target)
+        && view.weapon_against(handle, target).is_some_and(|w| {
+            w.in_range(
+                attack_circle(&view.army.units[handle], &view.army.positions, handle),
+                view.enemy.positions[target],
+            )
+        })
+}
+
+/// Returns the closest of the most threatening enemies that the unit at `handle` can attack without
+/// moving
+pub fn closest_in_range(view: &View, handle: usize) -> Option<u32> {
+    let pos = view.army.positions[handle].pos;
+    (0..view.enemy.units.len())
+        .filter(|&t| in_range(view, handle, t))
+        .min_by_key(|&t| {
+            (
+                Reverse(view.enemy.unit_from_handle(t).target_priority),
+                pos.dist_squared(view.enemy.positions[t].pos),
+            )
+        })
+        .map(|t| t as u32)
+}
+
+/// Returns the enemy that the unit at `handle` has to turn the least to face, or `None` if it can't
+/// attack anything
+pub fn least_angle_target(view: &View, handle: usize) -> Option<u32> {
+    let pos = view.army.positions[handle];
+    let facing = view.army.units[handle].facing;
+
+    (0..view.enemy.units.len())
+        .filter(|&t| view.can_acquire(handle, t))
+        .min_by_key(|&t| {
+            let angle = pos
+                .collision_angle(view.enemy.positions[t])
+                .map_or(const_real!(0), |angle| angle_diff(facing, angle).abs());
+            (Reverse(view.enemy.unit_from_handle(t).target_priority), angle)
+        })
+        .map(|t| t as u32)
+}
+
+impl Army {
+    pub fn set_controller(&mut self, controller: impl ArmyController + 'static) {
+        self.controller = Controller::new(controller);
+    }
+
+    /// Asks the army's controller for commands and applies them
+    pub(crate) fn issue_commands(
+        &mut self,
+        enemy: &Army,
+        time: Real,
+        creep: Option<&Creep>,
+        rng: &mut StdRng,
+    ) {
+        // the controller needs a shared reference to the army it lives in
+        let mut controller = std::mem::take(&mut self.controller);
+        let commands = controller.0.commands(
+            &View {
+                army: self,
+                enemy,
+                time,
+                creep,
+            },
+            rng,
+        );
+        self.controller = controller;
+
+        for (handle, command) in commands {
+            self.apply_command(handle as usize, command, time);
+        }
+    }
+
+    /// Orders cancel any attack that hasn't hit yet (refunding its cooldown) as well as the
+    /// backswing of one that has. Units in the middle of a multihit volley ignore orders other than
+    /// abilities. Abilities (including transfusion and buffs) don't interrupt anything.
+    ///
+    /// Loaded units only take abilities, plus attack and hold orders if they're in a bunker.
+    pub fn apply_command(&mut self, handle: usize, command: Command, time: Real) {
+        let unit = &mut self.units[handle];
+        if unit.is_dead()
+            || (unit.is_mid_volley()
+                && !matches!(
+                    command,
+                    Command::Ability(_) | Command::Transfuse(_) | Command::Unload | Command::Buff(_)
+                ))
+        {
+            return;
+        }
+
+        if unit.is_loaded() {
+            let in_bunker = !matches!(unit.state, ActionState::Cargo(_));
+            let allowed = match command {
+                Command::Ability(_) | Command::Buff(_) => true,
+                Command::Attack(_) | Command::Hold => in_bunker,
+                _ => false,
+            };
+            if !allowed {
+                return;
+            }
+        }
+
+        match command {
+            Command::Attack(t) => {
+                if unit.target != Some(t) {
+                    unit.interrupt(time);
+                    unit.target = Some(t);
+                    unit.boarding = None;
+                    unit.state = ActionState::Attack;
+                    unit.destination = None;
+                }
+            }
+            Command::Move(pos) => {
+                unit.interrupt(time);
+                unit.target = None;
+                unit.boarding = None;
+                unit.destination = Some(pos);
+                unit.state = ActionState::Move;
+            }
+            Command::Hold => {
+                unit.interrupt(time);
+                unit.target = None;
+                unit.boarding = None;
+                unit.destination = None;
+                unit.state = ActionState::Wait;
+            }
+            Command::Ability(effect) => match effect {
+                Effect::StatModOnce { apply } => apply(unit),
+                Effect::StatModTemp { apply, .. } => {
+                    apply(unit);
+                    unit.effects.push(effect);
+                }
+            },
+            Command::Transfuse(t) => {
+                self.transfuse(handle, t as usize, time);
+            }
+            Command::Load(t) => {
+                if self.can_load(t as usize, handle) {
+                    let unit = &mut self.units[handle];
+                    unit.interrupt(time);
+                    unit.target = None;
+                    unit.boarding = Some(t);
+                    unit.destination = Some(self.positions[t as usize].pos);
+                    unit.state = ActionState::Move;
+                }
+            }
+            Command::Unload => self.unload(handle, time),
+            Command::Buff(buff) => {
+                self.cast(handle, buff, time);
+            }
+        }
+    }
+}
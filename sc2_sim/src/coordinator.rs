@@ -2,6 +2,8 @@ use std::collections::HashMap;
 
 use crate::*;
 use army::{ActionState, Army};
+use cargo::attack_circle;
+use damage::Modifiers;
 use effect::{Affects, AoE};
 use itertools::Itertools;
 use rand::prelude::*;
@@ -25,10 +27,15 @@ pub enum Team {
     Team2,
 }
 
-use unit::{Base, Cost, Multihit, Projectile, Weapon, WeaponKind};
+use unit::{Base, Collision, Cost, Filter, Multihit, Projectile, Weapon, WeaponKind};
 use Team::*;
 
 use self::army::{State, Tracker};
+use creep::Creep;
+use terrain::Terrain;
+use utils::{angle_diff, radians, turn_towards};
+use estimate::{Estimate, Law};
+use metrics::Metrics;
 
 #[derive(Debug, Clone)]
 pub struct Coordinator {
@@ -37,6 +44,14 @@ pub struct Coordinator {
     pub time: Real,
     pub rng: StdRng,
     pub seed: u64,
+    pub metrics: Metrics,
+    /// Fights that last longer than this are considered a draw. Mostly matters for armies that
+    /// can't damage each other at all (e.g. zealots vs mutalisks)
+    pub time_limit: Real,
+    /// The arena the fight takes place in. `None` is an infinite flat plane
+    pub terrain: Option<Terrain>,
+    /// Creep on the battlefield, if any. See `Creep`
+    pub creep: Option<Creep>,
 }
 
 /*
@@ -47,10 +62,13 @@ pub struct Coordinator {
     should be fine.
 */
 impl Coordinator {
+    pub const DEFAULT_TIME_LIMIT: Real = const_real!(300);
+
     pub fn reset(&mut self) {
         self.a1.reset();
         self.a2.reset();
         self.time = const_real!(0);
+        self.metrics.clear();
     }
 
     pub fn seed_rng(&mut self, seed: u64) {
@@ -65,6 +83,12 @@ impl Coordinator {
         seed
     }
 
+    /// Analytic estimate of the fight's outcome. Orders of magnitude faster than `simulate`, but
+    /// less accurate. See `estimate::estimate`
+    pub fn estimate(&self, law: Law) -> Estimate {
+        estimate::estimate(&self.a1, &self.a2, law)
+    }
+
     pub fn units_left(&self, team: Team) -> usize {
         match team {
             Team1 => self
@@ -85,49 +109,278 @@ impl Coordinator {
     pub fn simulate(&mut self) -> Outcome {
         let mut a1_units = self.a1.units.len();
         let mut a2_units = self.a2.units.len();
+        let mut tick = 0;
 
-        while a1_units != 0 && a2_units != 0 {
-            self.acquire_targets();
-            self.heal();
-            // self.tick_weapons();
-            self.attack();
-            self.tick_projectiles();
+        self.metrics.record(tick, self.time, &self.a1, &self.a2, true);
 
-            self.time += TICK;
+        while a1_units != 0 && a2_units != 0 && self.time < self.time_limit {
+            self.step();
+            tick += 1;
             a1_units = self.units_left(Team1);
             a2_units = self.units_left(Team2);
+            self.metrics.record(tick, self.time, &self.a1, &self.a2, false);
         }
 
         while !self.a1.projectiles.is_empty() && !self.a2.projectiles.is_empty() {
             self.heal();
             self.tick_projectiles();
             self.time += TICK;
+            tick += 1;
             a1_units = self.units_left(Team1);
             a2_units = self.units_left(Team2);
+            self.metrics.record(tick, self.time, &self.a1, &self.a2, false);
         }
 
+        self.metrics.record(tick, self.time, &self.a1, &self.a2, true);
+
         Outcome {
             winner: match (a1_units == 0, a2_units == 0) {
                 (true, true) => None,
                 (true, false) => Some(Team::Team2),
                 (false, true) => Some(Team::Team1),
-                (_, _) => panic!("Unreachable"),
+                // timed out
+                (false, false) => None,
             },
             inner: self,
         }
     }
 
+    /// Advances the fight by one tick
+    fn step(&mut self) {
+        self.update_arrivals();
+        self.update_creep();
+        self.update_vision();
+        self.issue_commands();
+        self.move_units();
+        self.separate_units();
+        self.update_cargo();
+        self.update_buffs();
+        self.heal();
+        // self.tick_weapons();
+        self.attack();
+        self.tick_projectiles();
+
+        self.time += TICK;
+    }
+
+    /// Runs one simulation per seed, resetting the armies between each run. Passing the same seeds
+    /// to two different scenarios makes their results directly comparable.
+    pub fn simulate_batch(&mut self, seeds: &[u64]) -> BatchResult {
+        let mut result = BatchResult::default();
+        let cost = [self.a1.total_cost(), self.a2.total_cost()];
+
+        for &seed in seeds {
+            self.reset();
+            self.seed_rng(seed);
+            let winner = self.simulate().winner();
+
+            result.runs.push(Run {
+                seed,
+                winner,
+                duration: self.time,
+                resources_lost: [
+                    (cost[0] - self.a1.resources_remaining()).total_resources(),
+                    (cost[1] - self.a2.resources_remaining()).total_resources(),
+                ],
+            });
+        }
+
+        self.reset();
+        result
+    }
+
+    /// Shorthand for `simulate_batch` with `count` sequential seeds starting from `self.seed`
+    pub fn simulate_n(&mut self, count: usize) -> BatchResult {
+        let seeds = (0..count as u64)
+            .map(|i| self.seed.wrapping_add(i))
+            .collect::<Vec<_>>();
+        self.simulate_batch(&seeds)
+    }
+
     // for the following few functions, it's easier to delegat to the `Army` impls since they each
     // require mutable references which would break if we did this iteratively, and I don't want
     // 2 full copies of the internal laying around.
-    fn acquire_targets(&mut self) {
-        self.a1.acquire_targets(&mut self.a2, &mut self.rng);
-        self.a2.acquire_targets(&mut self.a1, &mut self.rng);
+    fn issue_commands(&mut self) {
+        self.a1
+            .issue_commands(&self.a2, self.time, self.creep.as_ref(), &mut self.rng);
+        self.a2
+            .issue_commands(&self.a1, self.time, self.creep.as_ref(), &mut self.rng);
+    }
+
+    fn update_buffs(&mut self) {
+        self.a1.update_buffs(self.time);
+        self.a2.update_buffs(self.time);
+    }
+
+    fn update_cargo(&mut self) {
+        self.a1.update_cargo(self.time);
+        self.a2.update_cargo(self.time);
+    }
+
+    fn move_units(&mut self) {
+        self.a1.move_units(self.terrain.as_ref());
+        self.a2.move_units(self.terrain.as_ref());
+    }
+
+    fn update_vision(&mut self) {
+        self.a1.update_vision(&self.a2, self.terrain.as_ref());
+        self.a2.update_vision(&self.a1, self.terrain.as_ref());
+    }
+
+    /// Pushes overlapping ground units apart, so that they can form walls and clump up in chokes.
+    /// Only done with terrain, since on the default flat plane each army is stacked on one point.
+    ///
+    /// Units that can both be pushed split the overlap. Immobile units (e.g. sieged tanks) are never
+    /// pushed, and enemies can only push units that are trying to move. Those collisions are
+    /// resolved fully afterwards, so that crowds can't squeeze through a wall.
+    fn separate_units(&mut self) {
+        let Some(terrain) = &self.terrain else {
+            return;
+        };
+
+        let bodies: Vec<(Team, usize)> = [(Team1, &self.a1), (Team2, &self.a2)]
+            .into_iter()
+            .flat_map(|(team, army)| {
+                army.units.iter().enumerate().filter_map(move |(h, u)| {
+                    (u.is_alive()
+                        && army.positions[h].plane == Collision::Ground
+                        && !u.is_loaded()
+                        && !matches!(u.state, ActionState::Pending(_)))
+                    .then_some((team, h))
+                })
+            })
+            .collect();
+
+        let circle = |c: &Coordinator, (team, h): (Team, usize)| match team {
+            Team1 => c.a1.positions[h],
+            Team2 => c.a2.positions[h],
+        };
+        let pushable = |c: &Coordinator, (team, h): (Team, usize), by: Team| {
+            let u = match team {
+                Team1 => &c.a1.units[h],
+                Team2 => &c.a2.units[h],
+            };
+            u.max_speed > 0 && (team == by || u.destination.is_some())
+        };
+
+        // units that can both be pushed split the difference
+        let mut pushes = vec![Pos::default(); bodies.len()];
+        for a in 0..bodies.len() {
+            for b in (a + 1)..bodies.len() {
+                if !pushable(self, bodies[a], bodies[b].0) || !pushable(self, bodies[b], bodies[a].0)
+                {
+                    continue;
+                }
+                let Some((nx, ny, overlap)) =
+                    Self::overlap(circle(self, bodies[a]), circle(self, bodies[b]))
+                else {
+                    continue;
+                };
+
+                let half = overlap / 2;
+                pushes[a].x -= nx * half;
+                pushes[a].y -= ny * half;
+                pushes[b].x += nx * half;
+                pushes[b].y += ny * half;
+            }
+        }
+
+        for (&(team, h), push) in bodies.iter().zip(pushes) {
+            let army = match team {
+                Team1 => &mut self.a1,
+                Team2 => &mut self.a2,
+            };
+            Self::nudge(terrain, &mut army.positions[h].pos, push);
+        }
+
+        // then units that can't be pushed
+        for a in 0..bodies.len() {
+            for b in 0..bodies.len() {
+                if a == b
+                    || !pushable(self, bodies[a], bodies[b].0)
+                    || pushable(self, bodies[b], bodies[a].0)
+                {
+                    continue;
+                }
+                let Some((nx, ny, overlap)) =
+                    Self::overlap(circle(self, bodies[a]), circle(self, bodies[b]))
+                else {
+                    continue;
+                };
+
+                let push = Pos {
+                    x: -nx * overlap,
+                    y: -ny * overlap,
+                };
+                let (team, h) = bodies[a];
+                let army = match team {
+                    Team1 => &mut self.a1,
+                    Team2 => &mut self.a2,
+                };
+                Self::nudge(terrain, &mut army.positions[h].pos, push);
+            }
+        }
+    }
+
+    /// If the circles overlap, returns the direction from `a` to `b` and the amount they overlap by
+    fn overlap(a: CollCircle, b: CollCircle) -> Option<(Real, Real, Real)> {
+        let min = a.r + b.r;
+        let dx = b.pos.x - a.pos.x;
+        let dy = b.pos.y - a.pos.y;
+        // cheap rejection before the sqrt
+        if dx.abs() >= min || dy.abs() >= min {
+            return None;
+        }
+        let dist = ((dx * dx) + (dy * dy)).sqrt();
+        if dist >= min {
+            return None;
+        }
+
+        // units on the exact same spot are split along the x axis
+        if dist == 0 {
+            return Some((const_real!(1), const_real!(0), min));
+        }
+
+        Some((dx / dist, dy / dist, min - dist))
+    }
+
+    /// Moves a unit by `push`, sliding along walls rather than getting pushed into them
+    fn nudge(terrain: &Terrain, pos: &mut Pos, push: Pos) {
+        let options = [
+            Pos {
+                x: pos.x + push.x,
+                y: pos.y + push.y,
+            },
+            Pos {
+                x: pos.x + push.x,
+                y: pos.y,
+            },
+            Pos {
+                x: pos.x,
+                y: pos.y + push.y,
+            },
+        ];
+
+        if let Some(next) = options.into_iter().find(|&p| terrain.is_pathable(p)) {
+            *pos = next;
+        }
+    }
+
+    fn update_arrivals(&mut self) {
+        self.a1.update_arrivals(self.time);
+        self.a2.update_arrivals(self.time);
+    }
+
+    fn update_creep(&mut self) {
+        self.a1.update_creep(self.creep.as_ref());
+        self.a2.update_creep(self.creep.as_ref());
     }
 
     fn heal(&mut self) {
-        self.a1.heal(self.time);
-        self.a2.heal(self.time);
+        self.a1.heal(self.time, self.creep.as_ref());
+        self.a2.heal(self.time, self.creep.as_ref());
+        self.a1.recharge_shields();
+        self.a2.recharge_shields();
     }
 
     // fn tick_weapons(&mut self) {
@@ -143,12 +396,16 @@ impl Coordinator {
                 if a1.projectiles[i].timer < self.time {
                     let p = a1.projectiles.swap_remove(i);
 
+                    // projectiles can't follow their target into a transport
+                    if a2.units[p.target as usize].is_loaded() {
+                        continue;
+                    }
+
                     let base = &a1.base_units[&a1.units[p.source as usize].base];
-                    let weapon = base
-                        .try_get_weapon(a2.unit_from_handle(p.target))
-                        .expect("Cannot fire projectile without weapon");
+                    let weapon = &base.weapons[p.weapon as usize];
                     Coordinator::apply_damage(
                         &mut a1.trackers[p.source as usize],
+                        &a1.units[p.source as usize].modifiers,
                         p.target,
                         a2,
                         weapon,
@@ -169,70 +426,151 @@ impl Coordinator {
     fn attack(&mut self) {
         // eliminates code duplication. I use a closure so it captures (and partial borrows) self
         let mut _inner = |attk: &mut Army, dfnd: &mut Army| {
+            let visible = &attk.visible;
+            let can_see = |t: u32| visible.get(t as usize).copied().unwrap_or(true);
+
             for (u_handle, unit) in attk.units.iter_mut().enumerate() {
-                if unit.is_dead() || unit.target.is_none() || !unit.can_attack {
+                if unit.is_dead() || !unit.can_attack {
                     continue;
                 }
 
-                // launch attack if we've finished backswing
-                if let ActionState::DmgPoint(timestamp, next_dmgpoint_idx) = unit.state {
-                    // backswing is a timestamp
-                    if timestamp > self.time {
+                match unit.state {
+                    // launch attack if we've finished the damage point
+                    ActionState::DmgPoint(timestamp, next_dmgpoint_idx) => {
+                        if timestamp > self.time {
+                            continue;
+                        }
+
+                        let t_handle = unit
+                            .target
+                            .expect("Cannot be in DmgPoint without a target");
+
+                        let weapon =
+                            &unit_from_base!(attk, unit.base).weapons[unit.weapon as usize];
+
+                        // the target died, became untargetable (or hidden) or moved out of range
+                        // before the attack landed. Hits after the first have already been
+                        // launched, so they land regardless
+                        let t_state = &dfnd.units[t_handle as usize];
+                        if next_dmgpoint_idx == 0
+                            && (t_state.is_dead()
+                                || t_state.untargetable
+                                || !can_see(t_handle)
+                                || !weapon.in_slop_range(
+                                    attack_circle(unit, &attk.positions, u_handle),
+                                    dfnd.positions[t_handle as usize],
+                                ))
+                        {
+                            unit.interrupt(self.time);
+                            unit.state = ActionState::Attack;
+                            continue;
+                        }
+
+                        match weapon.kind {
+                            WeaponKind::Projectile => attk.projectiles.push(Projectile::new(
+                                u_handle,
+                                t_handle as usize,
+                                unit.weapon as usize,
+                                weapon.range.end(),
+                                self.time,
+                            )),
+                            _ => Coordinator::apply_damage(
+                                &mut attk.trackers[u_handle],
+                                &unit.modifiers,
+                                t_handle,
+                                dfnd,
+                                weapon,
+                                self.time,
+                            ),
+                        }
+
+                        unit.state = match weapon.multihit {
+                            Multihit::TimeOffset(offsets)
+                                if (next_dmgpoint_idx as usize + 1) < offsets.len() =>
+                            {
+                                ActionState::DmgPoint(
+                                    offsets[next_dmgpoint_idx as usize + 1] + self.time,
+                                    next_dmgpoint_idx + 1,
+                                )
+                            }
+                            _ => ActionState::Backswing(self.time + weapon.backswing),
+                        };
                         continue;
                     }
-
-                    let t_handle = unit
-                        .target
-                        .expect("Cannot be in backswing without a target");
-                    let target = unit_from_handle!(dfnd, t_handle);
-
-                    let weapon = unit_from_base!(attk, unit.base)
-                        .try_get_weapon(target)
-                        .expect("Can only enter State::Backswing with a weapon");
-
-                    match weapon.kind {
-                        WeaponKind::Projectile => attk.projectiles.push(Projectile::new(
-                            u_handle,
-                            t_handle as usize,
-                            weapon.range.end(),
-                            self.time,
-                        )),
-                        _ => Coordinator::apply_damage(
-                            &mut attk.trackers[u_handle],
-                            t_handle,
-                            dfnd,
-                            weapon,
-                            self.time,
-                        ),
+                    // the backswing never delays the next attack
+                    ActionState::Backswing(timestamp)
+                        if timestamp > self.time && unit.attack_cd > self.time =>
+                    {
+                        continue;
                     }
+                    ActionState::Backswing(_)
+                    | ActionState::Attack
+                    | ActionState::Approach
+                    | ActionState::Turn
+                    | ActionState::Cooldown => (),
+                    _ => continue,
+                }
 
-                    unit.state = match weapon.multihit {
-                        Multihit::TimeOffset(offsets)
-                            if (next_dmgpoint_idx as usize) < offsets.len() =>
-                        {
-                            ActionState::DmgPoint(
-                                offsets[next_dmgpoint_idx as usize] + self.time,
-                                next_dmgpoint_idx + 1,
-                            )
-                        }
-                        _ => ActionState::Attack,
-                    };
+                let Some(t_handle) = unit.target else {
+                    unit.state = ActionState::Wait;
+                    unit.destination = None;
+                    continue;
+                };
+                if !can_see(t_handle) {
+                    continue;
                 }
+                let base = unit_from_base!(attk, unit.base);
+                let props =
+                    unit_from_handle!(dfnd, t_handle).filter_props() | Filter::Visible as u32;
+                let circle = attack_circle(unit, &attk.positions, u_handle);
+                let t_pos = dfnd.positions[t_handle as usize];
+                let Some(w_idx) = base.select_weapon(props, |w| w.in_range(circle, t_pos)) else {
+                    continue;
+                };
+                let weapon = &base.weapons[w_idx];
 
-                if unit.attack_cd > self.time {
+                // chase the target if it's out of range
+                if !weapon.in_range(circle, t_pos) {
+                    unit.state = ActionState::Approach;
+                    unit.destination = Some(t_pos.pos);
                     continue;
                 }
+                unit.destination = None;
+
+                // turn to face the target. Units that are already engaged get a little leeway
+                // before they have to turn again
+                let u_pos = attk.positions[u_handle];
+                if let Some(angle) = u_pos.collision_angle(t_pos) {
+                    let engaged = matches!(
+                        unit.state,
+                        ActionState::Cooldown | ActionState::Backswing(_)
+                    );
+                    let arc = weapon.arc / 2 + if engaged { weapon.arc_slop } else { const_real!(0) };
+                    let turn_rate = base.movement.turn_rate;
 
-                // not dead, can attack, has target, not in the middle of a multihit:
+                    unit.facing = turn_towards(unit.facing, angle, radians(turn_rate) * TICK);
 
-                let t_handle = unit.target.expect("Cannot attack without a target");
-                let target = unit_from_handle!(dfnd, t_handle);
-                let Some(weapon) = unit_from_base!(attk, unit.base).try_get_weapon(target) else {
+                    if angle_diff(unit.facing, angle).abs() > radians(arc) {
+                        unit.state = ActionState::Turn;
+                        continue;
+                    }
+                }
+
+                if unit.attack_cd > self.time {
+                    unit.state = ActionState::Cooldown;
                     continue;
-                };
+                }
+
+                // not dead, can attack, has target, in range, facing the target, weapon is ready:
 
-                unit.attack_cd = self.time + weapon.get_cooldown(&mut self.rng);
-                unit.state = ActionState::DmgPoint(weapon.damage_point + self.time, 0);
+                unit.attack_cd = self.time
+                    + damage::cooldown(weapon.get_cooldown(&mut self.rng), &unit.modifiers);
+                unit.weapon = w_idx as u8;
+                let first_hit = match weapon.multihit {
+                    Multihit::TimeOffset(offsets) => offsets.first().copied().unwrap_or_default(),
+                    _ => const_real!(0),
+                };
+                unit.state = ActionState::DmgPoint(weapon.damage_point + first_hit + self.time, 0);
             }
         };
 
@@ -245,48 +583,50 @@ impl Coordinator {
     // That means we're limited to freestanding and associated functions.
     fn apply_damage(
         u_tracker: &mut Tracker,
+        attacker: &Modifiers,
         target: u32,
         dfnd: &mut Army,
         weapon: &Weapon,
         time: Real,
     ) {
-        let mut hull_damage = Real::default();
-        let mut overkill = Real::default();
-
-        // let upgrade_bonus = match
-
         let t_base = unit_from_handle!(dfnd, target);
+        let research = dfnd.research;
         let t = &mut dfnd.units[target as usize];
+        let hull_armor = damage::hull_armor(t_base, &research);
+        let on_shields = t.shields != 0;
 
-        if t.shields != 0 {
-            let mut shield_damage = MIN_DAMAGE.max(weapon.get_shield_damage(t_base));
-            if let Multihit::Instant(x) = weapon.multihit {
-                shield_damage *= x;
-            }
+        let raw = damage::raw_damage(weapon, t_base, attacker);
+        let armor = if on_shields {
+            t_base.shields.armor
+        } else {
+            hull_armor
+        };
+        let mut dmg = damage::mitigate(raw, armor, !weapon.is_melee(), &t.modifiers);
+        if on_shields && t.base == Base::Immortal && research.hardened_shields {
+            dmg = damage::hardened_shields(dmg);
+        }
+        dmg = damage::scale(dmg, weapon.multihit, &t.modifiers);
+        damage::trigger_barrier(t.base, &mut t.modifiers, &research, time);
+        dmg = damage::absorb(dmg, &mut t.modifiers, time);
+
+        let hull_damage = if on_shields {
+            let (shield_damage, spillover) = damage::split(dmg, t.shields, hull_armor);
+            // shields bottom out at 0 rather than going negative, so that regen starts from there
             t.shields -= shield_damage;
             u_tracker.damage_dealt += shield_damage;
-
-            if t.shields < 0 {
-                // yes carryover damage has the hull armor applied, I checked this in-game
-                let spillover = t.shields.abs() - t_base.hull.armor;
-                t.hull -= spillover;
-                hull_damage += spillover;
-
-                // we don't ever want negative shields past the spillover damage, as shields can
-                // be regenerated and we want to begin the healing from 0
-                t.shields = real!(0);
-            }
+            spillover
         } else {
-            hull_damage += MIN_DAMAGE.max(weapon.get_damage(t_base));
-            if let Multihit::Instant(x) = weapon.multihit {
-                hull_damage *= x;
-            }
+            dmg
+        };
 
-            t.hull -= hull_damage;
-            if t.hull < 0 {
-                overkill = t.hull.abs();
-                dfnd.trackers[target as usize].death_timestamp = Some(time);
-            }
+        let mut overkill = Real::default();
+        t.hull -= hull_damage;
+        if t.hull < 0 {
+            overkill = t.hull.abs();
+        }
+        // only the killing blow sets the timestamp, later projectiles can still land on the corpse
+        if t.hull <= 0 && t.hull + hull_damage > 0 {
+            dfnd.trackers[target as usize].death_timestamp = Some(time);
         }
 
         u_tracker.damage_dealt += hull_damage - overkill;
@@ -323,10 +663,85 @@ impl Default for Coordinator {
             // It's as good a default seed as any
             rng: StdRng::seed_from_u64(17313471783455232199),
             seed: 17313471783455232199,
+            metrics: Metrics::default(),
+            time_limit: Self::DEFAULT_TIME_LIMIT,
+            terrain: None,
+            creep: None,
         }
     }
 }
 
+/// The result of a single run within a batch
+#[derive(Debug, Clone, Copy)]
+pub struct Run {
+    pub seed: u64,
+    pub winner: Option<Team>,
+    pub duration: Real,
+    /// Resources (minerals + gas) lost by each team, in the form [team 1, team 2]
+    pub resources_lost: [Real; 2],
+}
+
+impl Run {
+    /// The resources lost by the enemy divided by the resources lost by `team`. Losing nothing is
+    /// treated as losing 1 resource to avoid dividing by 0.
+    pub fn cost_efficiency(&self, team: Team) -> f64 {
+        let (own, enemy) = match team {
+            Team1 => (self.resources_lost[0], self.resources_lost[1]),
+            Team2 => (self.resources_lost[1], self.resources_lost[0]),
+        };
+
+        enemy.as_f64() / own.as_f64().max(1.0)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct BatchResult {
+    pub runs: Vec<Run>,
+}
+
+impl BatchResult {
+    pub fn len(&self) -> usize {
+        self.runs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.runs.is_empty()
+    }
+
+    pub fn wins(&self, team: Team) -> usize {
+        self.runs.iter().filter(|r| r.winner == Some(team)).count()
+    }
+
+    pub fn draws(&self) -> usize {
+        self.runs.iter().filter(|r| r.winner.is_none()).count()
+    }
+
+    pub fn win_rate(&self, team: Team) -> f64 {
+        if self.runs.is_empty() {
+            return 0.0;
+        }
+
+        self.wins(team) as f64 / self.runs.len() as f64
+    }
+
+    /// Mean cost efficiency of `team` across all runs. See `Run::cost_efficiency`
+    pub fn cost_efficiency(&self, team: Team) -> f64 {
+        if self.runs.is_empty() {
+            return 0.0;
+        }
+
+        self.runs.iter().map(|r| r.cost_efficiency(team)).sum::<f64>() / self.runs.len() as f64
+    }
+
+    pub fn avg_duration(&self) -> Real {
+        if self.runs.is_empty() {
+            return Real::default();
+        }
+
+        self.runs.iter().map(|r| r.duration).sum::<Real>() / real!(self.runs.len())
+    }
+}
+
 pub struct Outcome<'a> {
     winner: Option<Team>,
     inner: &'a Coordinator,
@@ -349,6 +764,20 @@ impl<'a> Outcome<'a> {
         self.inner.time
     }
 
+    /// Per-team time series recorded during the fight. Empty unless `Coordinator::metrics` was
+    /// enabled before simulating
+    pub fn metrics(&self) -> &Metrics {
+        &self.inner.metrics
+    }
+
+    /// The point after which the winner held the health lead for the rest of the fight. Requires
+    /// metrics to be enabled.
+    pub fn tipping_point(&self) -> Option<Real> {
+        self.inner
+            .metrics
+            .tipping_point(self.winner?, &self.inner.a1, &self.inner.a2)
+    }
+
     pub fn total_cost(&self, team: Team) -> Cost {
         match team {
             Team1 => self.inner.a1.total_cost(),
@@ -439,3 +868,134 @@ impl<'a> Outcome<'a> {
         HashMap::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unit::Unit;
+
+    /// `attacker` at the origin, against a `target` at `pos` that doesn't move or fight back
+    fn duel(attacker: Unit, target: Unit, pos: Pos) -> Coordinator {
+        let mut c = Coordinator::default();
+        c.a1.add_unit(attacker, 1);
+        c.a2.add_unit_at(target, 1, pos);
+        c.a2.units[0].can_attack = false;
+        c.a2.units[0].max_speed = const_real!(0);
+        c
+    }
+
+    /// Steps until `done` returns true, returning the number of ticks that took
+    fn step_until(c: &mut Coordinator, done: impl Fn(&Coordinator) -> bool) -> usize {
+        for tick in 1..=224 {
+            c.step();
+            if done(c) {
+                return tick;
+            }
+        }
+        panic!("gave up after 10 seconds");
+    }
+
+    fn at_angle(dist: Real, degrees: f64) -> Pos {
+        let angle = degrees.to_radians();
+        Pos {
+            x: dist * real!(angle.cos()),
+            y: dist * real!(angle.sin()),
+        }
+    }
+
+    #[test]
+    fn backswing_lock() {
+        let mut c = duel(Unit::MARINE, Unit::MARINE, at_angle(const_real!(3), 0.0));
+        step_until(&mut c, |c| {
+            matches!(c.a1.units[0].state, ActionState::Backswing(_))
+        });
+        assert!(c.a2.units[0].hull < 45);
+
+        let start = c.a1.positions[0].pos;
+        c.a1.units[0].destination = Some(at_angle(const_real!(10), 0.0));
+        c.step();
+        assert_eq!(c.a1.positions[0].pos.x, start.x);
+        assert!(matches!(c.a1.units[0].state, ActionState::Backswing(_)));
+
+        c.a1.units[0].move_and_shoot = true;
+        c.step();
+        assert!(c.a1.positions[0].pos.x > start.x);
+    }
+
+    #[test]
+    fn dmgpoint_cancel() {
+        let in_dmgpoint =
+            |c: &Coordinator| matches!(c.a1.units[0].state, ActionState::DmgPoint(..));
+        let weapon = &Unit::MARINE.weapons[0];
+        let r = Unit::MARINE.size;
+        let range = |extra| at_angle(weapon.range.end() + r * 2 + extra, 0.0);
+
+        // stepping back within the range slop doesn't save the target
+        let mut c = duel(Unit::MARINE, Unit::MARINE, at_angle(const_real!(3), 0.0));
+        step_until(&mut c, in_dmgpoint);
+        c.a2.positions[0].pos = range(weapon.range_slop - const_real!(0.25));
+        step_until(&mut c, |c| !in_dmgpoint(c));
+        assert!(c.a2.units[0].hull < 45);
+
+        // stepping out of it does, and refunds the attacker's cooldown
+        let mut c = duel(Unit::MARINE, Unit::MARINE, at_angle(const_real!(3), 0.0));
+        step_until(&mut c, in_dmgpoint);
+        c.a2.positions[0].pos = range(weapon.range_slop + const_real!(0.25));
+        step_until(&mut c, |c| !in_dmgpoint(c));
+        assert_eq!(c.a2.units[0].hull, 45);
+        assert_eq!(c.a1.units[0].state, ActionState::Attack);
+        assert!(c.a1.units[0].attack_cd <= c.time);
+    }
+
+    #[test]
+    fn arc_slop() {
+        let mut c = duel(Unit::MARINE, Unit::MARINE, at_angle(const_real!(3), 5.0));
+        // a marine that can't turn, so that only the arc checks matter
+        c.a1.base_units
+            .get_mut(&Base::Marine)
+            .unwrap()
+            .movement
+            .turn_rate = const_real!(0);
+
+        // 5 degrees off is outside the marine's arc of 0
+        for _ in 0..45 {
+            c.step();
+            assert_eq!(c.a1.units[0].state, ActionState::Turn);
+        }
+        assert_eq!(c.a2.units[0].hull, 45);
+
+        c.a1.units[0].facing = c.a1.positions[0]
+            .collision_angle(c.a2.positions[0])
+            .unwrap();
+        step_until(&mut c, |c| c.a2.units[0].hull < 45);
+
+        // once engaged, 5 degrees off is within the arc slop of 11.25
+        c.a2.positions[0].pos = at_angle(const_real!(3), 0.0);
+        let hull = c.a2.units[0].hull;
+        step_until(&mut c, |c| c.a2.units[0].hull < hull);
+
+        // but 15 degrees isn't
+        c.a2.positions[0].pos = at_angle(const_real!(3), -10.0);
+        let hull = c.a2.units[0].hull;
+        step_until(&mut c, |c| c.a1.units[0].state == ActionState::Turn);
+        for _ in 0..45 {
+            c.step();
+        }
+        assert_eq!(c.a2.units[0].hull, hull);
+    }
+
+    #[test]
+    fn turn_delay() {
+        let first_shot = |degrees| {
+            let mut c = duel(Unit::THOR, Unit::MARINE, at_angle(const_real!(4), degrees));
+            step_until(&mut c, |c| {
+                matches!(c.a1.units[0].state, ActionState::DmgPoint(..))
+            })
+        };
+
+        // thors turn 360 degrees per game second, 22.5 degrees per tick. Turning 100 degrees takes
+        // 5 ticks, the last of which ends facing the target, so the shot is 4 ticks later than one
+        // at a target straight ahead
+        assert_eq!(first_shot(100.0) - first_shot(0.0), 4);
+    }
+}
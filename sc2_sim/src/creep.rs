@@ -0,0 +1,181 @@
+use crate::{
+    army::{ActionState, Army},
+    effect::Stat,
+    unit::{Base, Collision},
+    *,
+};
+
+/// A circular patch of creep, e.g. the area around a hatchery or a creep tumor
+#[derive(Debug, Clone, Copy)]
+pub struct CreepPatch {
+    pub pos: Pos,
+    pub radius: Real,
+}
+
+/// An optional creep layer. Without one, nothing is on creep.
+///
+/// Ground units on creep move at their `Movement::creep_speed` multiplier (only Zerg units have
+/// one above 1), and queens can only cast transfusion while standing on it. Creep is made up of
+/// circular patches, which is close enough to how tumors spread it.
+#[derive(Debug, Clone)]
+pub struct Creep {
+    pub patches: Vec<CreepPatch>,
+    /// Multiplier on the life regen of Zerg units standing on creep. Creep doesn't affect regen in
+    /// the standard game, so this defaults to 1
+    pub regen: Real,
+}
+
+impl Default for Creep {
+    fn default() -> Self {
+        Self {
+            patches: Vec::new(),
+            regen: const_real!(1),
+        }
+    }
+}
+
+impl Creep {
+    /// How far a fully grown creep tumor spreads creep
+    pub const TUMOR_RADIUS: Real = const_real!(10);
+    // how far inside the edge of the creep `nearest` aims, so units don't stop right on the border
+    const EDGE_MARGIN: Real = const_real!(0.5);
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_patch(&mut self, pos: Pos, radius: Real) {
+        self.patches.push(CreepPatch { pos, radius });
+    }
+
+    pub fn is_on_creep(&self, pos: Pos) -> bool {
+        self.patches.iter().any(|p| p.pos.within(pos, p.radius))
+    }
+
+    /// The closest point to `pos` that is on creep, or `None` if there's no creep at all
+    pub fn nearest(&self, pos: Pos) -> Option<Pos> {
+        if self.is_on_creep(pos) {
+            return Some(pos);
+        }
+
+        self.patches
+            .iter()
+            .map(|p| {
+                let dist = p.pos.dist(pos);
+                let inside = (p.radius - Self::EDGE_MARGIN).max(const_real!(0));
+                if dist == 0 {
+                    return (dist, p.pos);
+                }
+                let edge = Pos {
+                    x: p.pos.x + (pos.x - p.pos.x) * inside / dist,
+                    y: p.pos.y + (pos.y - p.pos.y) * inside / dist,
+                };
+                (dist - p.radius, edge)
+            })
+            .min_by_key(|&(dist, _)| dist)
+            .map(|(_, edge)| edge)
+    }
+}
+
+impl Army {
+    /// Updates which units are standing on creep, adjusting their speed when that changes
+    pub(crate) fn update_creep(&mut self, creep: Option<&Creep>) {
+        for handle in 0..self.units.len() {
+            let unit = &self.units[handle];
+            if unit.is_dead() || unit.is_loaded() || matches!(unit.state, ActionState::Pending(_)) {
+                continue;
+            }
+
+            let circle = self.positions[handle];
+            let on_creep = creep
+                .is_some_and(|c| circle.plane == Collision::Ground && c.is_on_creep(circle.pos));
+
+            if on_creep != unit.on_creep {
+                self.units[handle].on_creep = on_creep;
+                self.reset_speed(handle, Stat::Speed);
+            }
+        }
+    }
+
+    /// Has the queen at `caster` transfuse the ally at `target`, healing it instantly and then over
+    /// time. Returns false (and does nothing) if the queen is off creep, out of energy, or out of
+    /// range, or if the target is at full health.
+    pub fn transfuse(&mut self, caster: usize, target: usize, time: Real) -> bool {
+        let queen = &self.units[caster];
+        let t = &self.units[target];
+        let max_hull = self.unit_from_handle(target).hull.max;
+
+        let castable = queen.base == Base::Queen
+            && queen.is_alive()
+            && queen.on_creep
+            && queen.energy.is_some_and(|e| e >= TRANSFUSION_ENERGY)
+            && caster != target
+            && t.is_alive()
+            && t.hull < max_hull
+            && !t.is_loaded()
+            && !matches!(t.state, ActionState::Pending(_))
+            && self.in_transfusion_range(caster, target);
+
+        if !castable {
+            return false;
+        }
+
+        let queen = &mut self.units[caster];
+        queen.energy = queen.energy.map(|e| e - TRANSFUSION_ENERGY);
+
+        let t = &mut self.units[target];
+        t.hull = max_hull.min(t.hull + TRANSFUSION_HEAL);
+        t.transfusion = Some(time + TRANSFUSION_DURATION);
+
+        true
+    }
+
+    pub fn in_transfusion_range(&self, caster: usize, target: usize) -> bool {
+        let c = self.positions[caster];
+        let t = self.positions[target];
+        c.pos.within(t.pos, TRANSFUSION_RANGE + c.r + t.r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit::Unit;
+
+    fn creep() -> Creep {
+        let mut creep = Creep::new();
+        creep.add_patch(Pos::default(), Creep::TUMOR_RADIUS);
+        creep
+    }
+
+    #[test]
+    fn creep_speed() {
+        let mut army = Army::default();
+        army.add_unit(Unit::ZERGLING, 1);
+        army.add_unit(Unit::MARINE, 1);
+        let ling = &Unit::ZERGLING.movement;
+
+        army.update_creep(Some(&creep()));
+        assert_eq!(army.units[0].max_speed, ling.speed * ling.creep_speed);
+        assert_eq!(army.units[1].max_speed, Unit::MARINE.movement.speed);
+
+        army.update_creep(None);
+        assert_eq!(army.units[0].max_speed, ling.speed);
+    }
+
+    #[test]
+    fn transfusion_needs_creep() {
+        let mut army = Army::default();
+        army.add_unit(Unit::QUEEN, 2);
+        army.units[0].energy = Some(TRANSFUSION_ENERGY);
+        army.units[1].hull = const_real!(10);
+
+        army.update_creep(None);
+        assert!(!army.transfuse(0, 1, real!(0)));
+        assert_eq!(army.units[1].hull, 10);
+
+        army.update_creep(Some(&creep()));
+        assert!(army.transfuse(0, 1, real!(0)));
+        assert!(army.units[1].hull > 10);
+    }
+}
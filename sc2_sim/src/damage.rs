@@ -0,0 +1,483 @@
+//! Damage is worked out in stages, each of which is a plain function so it can be checked against
+//! in-game numbers on its own. For a single hit:
+//!
+//! 1. `raw_damage`: weapon damage, attribute bonus, and attacker buffs (Prismatic Alignment)
+//! 2. `mitigate`: armor and Guardian Shield, clamped to `MIN_DAMAGE`
+//! 3. `hardened_shields`: the per-hit cap on Immortals with Hardened Shields
+//! 4. `scale`: `Multihit::Instant` and the double damage taken by hallucinations
+//! 5. `absorb`: the Immortal's Barrier
+//! 6. `split`: shield damage, and the damage that spills over onto the hull
+//!
+//! `Coordinator::apply_damage` runs the stages in order.
+
+use crate::{
+    army::{ActionState, Army},
+    effect::Stat,
+    unit::{Base, Flag, Multihit, Research, Unit, Weapon},
+    *,
+};
+
+/// Buffs and debuffs that change how much damage a unit deals and takes
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Modifiers {
+    /// Contains the timestamp at which stim wears off
+    pub stim: Option<Real>,
+    /// Sentry only: contains the timestamp at which its Guardian Shield ends
+    pub guardian_shield: Option<Real>,
+    /// True if the unit is inside a friendly Guardian Shield
+    pub guarded: bool,
+    /// Contains the timestamp at which Prismatic Alignment wears off
+    pub prismatic_alignment: Option<Real>,
+    /// The Immortal's active Barrier, as (damage left to absorb, timestamp at which it ends)
+    pub barrier: Option<(Real, Real)>,
+    /// When the unit's cooldown-limited ability (Barrier, Prismatic Alignment) is ready again
+    pub ready: Real,
+    /// Hallucinations deal no damage and take double damage
+    pub hallucination: bool,
+}
+
+/// Abilities that buff the caster (or, for Guardian Shield, allies around it). See `Army::cast`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Buff {
+    Stim,
+    GuardianShield,
+    PrismaticAlignment,
+}
+
+/// Stage 1: the weapon's damage against `target`, including its attribute bonus and any damage
+/// buffs on the attacker
+pub fn raw_damage(weapon: &Weapon, target: &Unit, attacker: &Modifiers) -> Real {
+    if attacker.hallucination {
+        return const_real!(0);
+    }
+
+    let mut dmg = weapon.damage + weapon.bonus_against(target);
+    if attacker.prismatic_alignment.is_some() && target.has_flag(Flag::Armored) {
+        dmg += PRISMATIC_ALIGNMENT_BONUS;
+    }
+
+    dmg
+}
+
+/// Stage 2: subtracts armor, plus Guardian Shield's reduction for ranged attacks. Attacks never do
+/// less than `MIN_DAMAGE`
+pub fn mitigate(damage: Real, armor: Real, ranged: bool, defender: &Modifiers) -> Real {
+    let mut dmg = damage - armor;
+    if ranged && defender.guarded {
+        dmg -= GUARDIAN_SHIELD_REDUCTION;
+    }
+
+    MIN_DAMAGE.max(dmg)
+}
+
+/// Stage 3: Hardened Shields limits each hit to 10 damage while the Immortal has shields
+pub fn hardened_shields(damage: Real) -> Real {
+    damage.min(HARDENED_SHIELDS_CAP)
+}
+
+/// Stage 4: multiplies the per-hit damage by the number of simultaneous hits, and doubles it
+/// against hallucinations
+pub fn scale(damage: Real, multihit: Multihit, defender: &Modifiers) -> Real {
+    let mut dmg = damage;
+    if let Multihit::Instant(x) = multihit {
+        dmg *= x;
+    }
+    if defender.hallucination {
+        dmg *= 2;
+    }
+
+    dmg
+}
+
+/// Stage 5: the Barrier soaks up damage until it's used up or expires. Returns the damage that gets
+/// through
+pub fn absorb(damage: Real, defender: &mut Modifiers, time: Real) -> Real {
+    let Some((left, ends)) = defender.barrier else {
+        return damage;
+    };
+    if ends <= time {
+        defender.barrier = None;
+        return damage;
+    }
+
+    let absorbed = damage.min(left);
+    defender.barrier = (left > absorbed).then_some((left - absorbed, ends));
+
+    damage - absorbed
+}
+
+/// Stage 6: splits damage dealt to `shields` into (shield damage, hull damage). Whatever the
+/// shields can't soak up spills over onto the hull, with hull armor applied again
+pub fn split(damage: Real, shields: Real, hull_armor: Real) -> (Real, Real) {
+    if damage <= shields {
+        return (damage, const_real!(0));
+    }
+
+    // yes carryover damage has the hull armor applied, I checked this in-game
+    let spillover = (damage - shields - hull_armor).max(const_real!(0));
+    (shields, spillover)
+}
+
+/// The unit's hull armor, including researched upgrades
+pub fn hull_armor(unit: &Unit, research: &Research) -> Real {
+    match unit.base {
+        Base::Ultralisk if research.chitinous_plating => unit.hull.armor + CHITINOUS_PLATING_ARMOR,
+        _ => unit.hull.armor,
+    }
+}
+
+/// The weapon cooldown after attack speed buffs
+pub fn cooldown(cooldown: Real, attacker: &Modifiers) -> Real {
+    if attacker.stim.is_some() {
+        cooldown / STIM_ATTACK_SPEED
+    } else {
+        cooldown
+    }
+}
+
+/// Movement speed multiplier from the unit's buffs, or `None` if it has none
+pub fn speed_multiplier(unit: &Modifiers) -> Option<Real> {
+    match (unit.stim.is_some(), unit.prismatic_alignment.is_some()) {
+        (false, false) => None,
+        (stim, pa) => {
+            let mut mult = const_real!(1);
+            if stim {
+                mult *= STIM_SPEED;
+            }
+            if pa {
+                mult *= PRISMATIC_ALIGNMENT_SPEED;
+            }
+            Some(mult)
+        }
+    }
+}
+
+impl Army {
+    /// Casts `buff` with the unit at `handle`. Returns false (and does nothing) if the unit doesn't
+    /// have the ability or can't afford it right now.
+    ///
+    /// Stim costs life and can be recast to refresh it, Guardian Shield costs energy, and Prismatic
+    /// Alignment has a cooldown.
+    pub fn cast(&mut self, handle: usize, buff: Buff, time: Real) -> bool {
+        let unit = &mut self.units[handle];
+        if unit.is_dead() || matches!(unit.state, ActionState::Pending(_) | ActionState::Cargo(_)) {
+            return false;
+        }
+
+        match (buff, unit.base) {
+            (Buff::Stim, Base::Marine | Base::Marauder) => {
+                let cost = if unit.base == Base::Marine {
+                    STIM_COST_MARINE
+                } else {
+                    STIM_COST_MARAUDER
+                };
+                if unit.hull <= cost {
+                    return false;
+                }
+                unit.hull -= cost;
+                unit.modifiers.stim = Some(time + STIM_DURATION);
+            }
+            (Buff::GuardianShield, Base::Sentry) => {
+                if unit.energy.is_none_or(|e| e < GUARDIAN_SHIELD_ENERGY) {
+                    return false;
+                }
+                unit.energy = unit.energy.map(|e| e - GUARDIAN_SHIELD_ENERGY);
+                unit.modifiers.guardian_shield = Some(time + GUARDIAN_SHIELD_DURATION);
+            }
+            (Buff::PrismaticAlignment, Base::VoidRay) => {
+                if unit.modifiers.ready > time {
+                    return false;
+                }
+                unit.modifiers.ready = time + PRISMATIC_ALIGNMENT_COOLDOWN;
+                unit.modifiers.prismatic_alignment = Some(time + PRISMATIC_ALIGNMENT_DURATION);
+            }
+            _ => return false,
+        }
+
+        self.reset_speed(handle, Stat::Speed);
+        true
+    }
+
+    /// Expires buffs that have run out and works out which units are inside a Guardian Shield
+    pub(crate) fn update_buffs(&mut self, time: Real) {
+        let expired = |t: Option<Real>| t.filter(|&t| t > time);
+
+        for handle in 0..self.units.len() {
+            let m = &mut self.units[handle].modifiers;
+            let speed = m.stim.is_some() || m.prismatic_alignment.is_some();
+
+            m.stim = expired(m.stim);
+            m.prismatic_alignment = expired(m.prismatic_alignment);
+            m.guardian_shield = expired(m.guardian_shield);
+            m.barrier = m.barrier.filter(|&(_, ends)| ends > time);
+
+            if speed && m.stim.is_none() && m.prismatic_alignment.is_none() {
+                self.reset_speed(handle, Stat::Speed);
+            }
+        }
+
+        let shields: Vec<Pos> = (0..self.units.len())
+            .filter(|&h| {
+                self.units[h].is_alive() && self.units[h].modifiers.guardian_shield.is_some()
+            })
+            .map(|h| self.positions[h].pos)
+            .collect();
+
+        for (unit, circle) in self.units.iter_mut().zip(self.positions.iter()) {
+            unit.modifiers.guarded = shields
+                .iter()
+                .any(|&s| s.within(circle.pos, GUARDIAN_SHIELD_RADIUS + circle.r));
+        }
+    }
+
+    /// Adds `count` hallucinated copies of `unit`. Hallucinations deal no damage and take double
+    /// damage
+    pub fn add_hallucination(&mut self, unit: Unit, count: usize) {
+        let start = self.units.len();
+        self.add_unit(unit, count);
+        for u in self.units[start..].iter_mut() {
+            u.modifiers.hallucination = true;
+        }
+    }
+}
+
+/// Triggers the Immortal's Barrier when it takes damage, if it's off cooldown. Units with Hardened
+/// Shields don't have a Barrier
+pub(crate) fn trigger_barrier(
+    base: Base,
+    defender: &mut Modifiers,
+    research: &Research,
+    time: Real,
+) {
+    if base != Base::Immortal || research.hardened_shields || defender.ready > time {
+        return;
+    }
+
+    defender.barrier = Some((BARRIER_ABSORB, time + BARRIER_DURATION));
+    defender.ready = time + BARRIER_COOLDOWN;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit::{Bonus, Collision, Filter};
+
+    fn unit(base: Base) -> Unit {
+        Unit::from_base(base).unwrap()
+    }
+
+    fn weapon(base: Base) -> Weapon {
+        unit(base).weapons[0].clone()
+    }
+
+    #[test]
+    fn raw_damage_bonus() {
+        let none = Modifiers::default();
+        // 13 (+5 vs armored)
+        assert_eq!(
+            raw_damage(&weapon(Base::Stalker), &unit(Base::Zealot), &none),
+            13
+        );
+        assert_eq!(
+            raw_damage(&weapon(Base::Stalker), &unit(Base::Stalker), &none),
+            18
+        );
+    }
+
+    #[test]
+    fn raw_damage_multiple_bonuses() {
+        let mut w = weapon(Base::Marine);
+        w.bonuses[0] = Some(Bonus {
+            vs: Flag::Armored,
+            damage: const_real!(4),
+        });
+        w.bonuses[1] = Some(Bonus {
+            vs: Flag::Mechanical,
+            damage: const_real!(2),
+        });
+        let none = Modifiers::default();
+        // thors are armored and mechanical
+        assert_eq!(raw_damage(&w, &unit(Base::Thor), &none), 12);
+        // zealots are neither
+        assert_eq!(raw_damage(&w, &unit(Base::Zealot), &none), 6);
+    }
+
+    #[test]
+    fn target_filters() {
+        // ground weapons can hit colossi, and the thor's anti-air can't hit ground units
+        assert!(unit(Base::Marauder)
+            .try_get_weapon(&unit(Base::Colossus))
+            .is_some());
+        assert!(unit(Base::Stalker)
+            .try_get_weapon(&unit(Base::Colossus))
+            .is_some());
+        let javelin = Weapon::THOR_JAVELINMISSILELAUNCHERS;
+        assert!(!javelin.can_hit(unit(Base::Marine).filter_props() | Filter::Visible as u32));
+
+        // liberators can't hit structures
+        let liberator = unit(Base::LiberatorSieged);
+        assert!(liberator
+            .try_get_weapon(&unit(Base::PhotonCannon))
+            .is_none());
+        assert!(liberator.try_get_weapon(&unit(Base::Marine)).is_some());
+
+        // nothing can hit units it can't see
+        assert!(unit(Base::Marine)
+            .weapon_for(unit(Base::Zealot).filter_props())
+            .is_none());
+    }
+
+    #[test]
+    fn weapon_selection() {
+        let visible = |base| unit(base).filter_props() | Filter::Visible as u32;
+
+        // the battlecruiser picks its weapon by the target's plane
+        let bc = unit(Base::Battlecruiser);
+        let ats = bc.select_weapon(visible(Base::Marine), |_| true).unwrap();
+        let ata = bc
+            .select_weapon(visible(Base::VikingAir), |_| true)
+            .unwrap();
+        assert_eq!(bc.weapons[ats].can_target, Collision::Ground);
+        assert_eq!(bc.weapons[ata].can_target, Collision::Flying);
+
+        // hydras prefer their melee attack, but only when it's in range. They approach with their
+        // ranged attack
+        let hydra = unit(Base::Hydralisk);
+        let zealot = visible(Base::Zealot);
+        assert_eq!(hydra.select_weapon(zealot, |_| true), Some(0));
+        assert_eq!(hydra.select_weapon(zealot, |w| !w.is_melee()), Some(1));
+        assert_eq!(hydra.select_weapon(zealot, |_| false), Some(1));
+    }
+
+    #[test]
+    fn prismatic_alignment() {
+        let pa = Modifiers {
+            prismatic_alignment: Some(const_real!(1)),
+            ..Default::default()
+        };
+        let base = raw_damage(
+            &weapon(Base::VoidRay),
+            &unit(Base::Stalker),
+            &Modifiers::default(),
+        );
+        assert_eq!(
+            raw_damage(&weapon(Base::VoidRay), &unit(Base::Stalker), &pa),
+            base + 6
+        );
+        // no bonus against light units
+        assert_eq!(
+            raw_damage(&weapon(Base::VoidRay), &unit(Base::Zealot), &pa),
+            raw_damage(
+                &weapon(Base::VoidRay),
+                &unit(Base::Zealot),
+                &Modifiers::default()
+            )
+        );
+    }
+
+    #[test]
+    fn armor_and_min_damage() {
+        let none = Modifiers::default();
+        assert_eq!(mitigate(const_real!(6), const_real!(1), true, &none), 5);
+        assert_eq!(
+            mitigate(const_real!(2), const_real!(5), true, &none),
+            MIN_DAMAGE
+        );
+    }
+
+    #[test]
+    fn guardian_shield() {
+        let guarded = Modifiers {
+            guarded: true,
+            ..Default::default()
+        };
+        // marine vs guarded zealot shields
+        assert_eq!(mitigate(const_real!(6), const_real!(0), true, &guarded), 4);
+        // melee attacks aren't reduced
+        assert_eq!(mitigate(const_real!(8), const_real!(0), false, &guarded), 8);
+    }
+
+    #[test]
+    fn chitinous_plating() {
+        let ultra = unit(Base::Ultralisk);
+        let research = Research {
+            chitinous_plating: true,
+            ..Default::default()
+        };
+        assert_eq!(hull_armor(&ultra, &Research::default()), ultra.hull.armor);
+        assert_eq!(hull_armor(&ultra, &research), ultra.hull.armor + 2);
+    }
+
+    #[test]
+    fn hardened_shields_cap() {
+        assert_eq!(hardened_shields(const_real!(70)), 10);
+        assert_eq!(hardened_shields(const_real!(6)), 6);
+    }
+
+    #[test]
+    fn multihit_and_hallucination() {
+        let none = Modifiers::default();
+        let hallucination = Modifiers {
+            hallucination: true,
+            ..Default::default()
+        };
+        // zealot: 2 hits of 8
+        assert_eq!(
+            scale(const_real!(8), Multihit::Instant(const_real!(2)), &none),
+            16
+        );
+        assert_eq!(
+            scale(
+                const_real!(6),
+                Multihit::Instant(const_real!(1)),
+                &hallucination
+            ),
+            12
+        );
+        // hallucinations don't deal damage
+        assert_eq!(
+            raw_damage(&weapon(Base::Stalker), &unit(Base::Zealot), &hallucination),
+            0
+        );
+    }
+
+    #[test]
+    fn barrier() {
+        let mut m = Modifiers::default();
+        trigger_barrier(Base::Immortal, &mut m, &Research::default(), const_real!(0));
+        assert_eq!(absorb(const_real!(70), &mut m, const_real!(0)), 0);
+        assert_eq!(absorb(const_real!(70), &mut m, const_real!(0)), 40);
+        assert!(m.barrier.is_none());
+
+        // still on cooldown
+        trigger_barrier(Base::Immortal, &mut m, &Research::default(), const_real!(1));
+        assert!(m.barrier.is_none());
+
+        // expires
+        let mut m = Modifiers::default();
+        trigger_barrier(Base::Immortal, &mut m, &Research::default(), const_real!(0));
+        assert_eq!(absorb(const_real!(10), &mut m, BARRIER_DURATION), 10);
+    }
+
+    #[test]
+    fn spillover() {
+        // 13 damage into 5 shields on a 1 armor zealot: 5 to shields, 7 to hull
+        let (shields, hull) = split(const_real!(13), const_real!(5), const_real!(1));
+        assert!(shields == 5 && hull == 7);
+        let (shields, hull) = split(const_real!(5), const_real!(13), const_real!(1));
+        assert!(shields == 5 && hull == 0);
+        // armor can soak up the whole spillover, but never heals
+        let (shields, hull) = split(const_real!(6), const_real!(5), const_real!(2));
+        assert!(shields == 5 && hull == 0);
+    }
+
+    #[test]
+    fn stim_cooldown() {
+        let stim = Modifiers {
+            stim: Some(const_real!(1)),
+            ..Default::default()
+        };
+        assert_eq!(cooldown(const_real!(1.5), &stim), 1);
+        assert_eq!(cooldown(const_real!(1.5), &Modifiers::default()), 1.5);
+    }
+}
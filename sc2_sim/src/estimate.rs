@@ -0,0 +1,337 @@
+use std::time::{Duration, Instant};
+
+use itertools::Itertools;
+
+use crate::{
+    army::Army,
+    coordinator::{Outcome, Team},
+    unit::{Base, Unit, Weapon},
+    *,
+};
+
+/// Which of Lanchester's laws to use when estimating a fight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Law {
+    /// Every unit can engage at once, so fighting strength scales with the square of unit count.
+    /// Fits ranged armies.
+    Square,
+    /// The number of units that can engage at once is fixed by the initial frontage, so fighting
+    /// strength scales linearly with unit count. Fits melee armies.
+    Linear,
+    /// Uses `Linear` if most of the combined DPS of both armies comes from melee weapons,
+    /// otherwise `Square`
+    #[default]
+    Auto,
+}
+
+/// The result of an analytic fight estimate. Useful for pruning large searches before running the
+/// full tick simulation.
+#[derive(Debug, Clone, Copy)]
+pub struct Estimate {
+    pub winner: Option<Team>,
+    pub law: Law,
+    /// Estimated fraction of the winner's units that survive
+    pub remaining_frac: f64,
+    /// Estimated resource value (minerals + gas) of the winner's surviving units
+    pub remaining_value: Real,
+    pub duration: Real,
+    /// Wall-clock time spent producing the estimate
+    pub elapsed: Duration,
+}
+
+/// The difference between an `Estimate` and the outcome of a full simulation of the same fight
+#[derive(Debug, Clone, Copy)]
+pub struct EstimateError {
+    pub winner_correct: bool,
+    /// Estimated duration minus the simulated duration
+    pub duration: Real,
+    /// Estimated remaining value minus the simulated remaining value
+    pub remaining_value: Real,
+}
+
+impl Estimate {
+    pub fn error(&self, outcome: &Outcome) -> EstimateError {
+        EstimateError {
+            winner_correct: self.winner == outcome.winner(),
+            duration: self.duration - outcome.duration(),
+            remaining_value: self.remaining_value
+                - outcome.cost_units_remaining().total_resources(),
+        }
+    }
+}
+
+/// Estimates the outcome of a fight between `a1` and `a2` using Lanchester's laws. Each side's
+/// attrition rate is derived from the effective DPS of every unit type against every enemy unit
+/// type, which accounts for armor, bonus damage, shields, and multihit.
+pub fn estimate(a1: &Army, a2: &Army, law: Law) -> Estimate {
+    let start = Instant::now();
+
+    // f64s are used throughout since the squared unit counts and kill rates can easily exceed the
+    // range (or precision) of a `Real`
+    let n1 = a1.units_alive() as f64;
+    let n2 = a2.units_alive() as f64;
+
+    // per-unit kill rates, in enemy units killed per second
+    let (k1, melee1, total1) = kill_rate(a1, a2);
+    let (k2, melee2, total2) = kill_rate(a2, a1);
+
+    let law = match law {
+        Law::Auto if melee1 + melee2 > (total1 + total2) / 2.0 => Law::Linear,
+        Law::Auto => Law::Square,
+        x => x,
+    };
+
+    let (winner, remaining_frac, duration) = match law {
+        Law::Square => square_law(n1, k1, n2, k2),
+        _ => linear_law(n1, k1, n2, k2),
+    };
+
+    let remaining_value = match winner {
+        Some(Team::Team1) => a1.resources_remaining().total_resources() * real!(remaining_frac),
+        Some(Team::Team2) => a2.resources_remaining().total_resources() * real!(remaining_frac),
+        None => Real::default(),
+    };
+
+    Estimate {
+        winner,
+        law,
+        remaining_frac,
+        remaining_value,
+        duration: real!(duration.min(Real::MAX.as_f64())),
+        elapsed: start.elapsed(),
+    }
+}
+
+/// Returns (winner, fraction of winner's units remaining, duration)
+fn square_law(n1: f64, k1: f64, n2: f64, k2: f64) -> (Option<Team>, f64, f64) {
+    let s1 = k1 * n1 * n1;
+    let s2 = k2 * n2 * n2;
+
+    if s1 == s2 {
+        return (None, 0.0, f64::INFINITY);
+    }
+
+    // the loser's count hits 0 when tanh(sqrt(k1 * k2) * t) == (n_l / n_w) * sqrt(k_l / k_w)
+    let (team, nw, kw, nl, kl) = if s1 > s2 {
+        (Team::Team1, n1, k1, n2, k2)
+    } else {
+        (Team::Team2, n2, k2, n1, k1)
+    };
+
+    let remaining = (nw * nw - (kl / kw) * nl * nl).sqrt();
+    let duration = if kl == 0.0 {
+        nl / (kw * nw)
+    } else {
+        ((nl / nw) * (kl / kw).sqrt()).atanh() / (kw * kl).sqrt()
+    };
+
+    (Some(team), remaining / nw, duration)
+}
+
+/// Returns (winner, fraction of winner's units remaining, duration)
+fn linear_law(n1: f64, k1: f64, n2: f64, k2: f64) -> (Option<Team>, f64, f64) {
+    let s1 = k1 * n1;
+    let s2 = k2 * n2;
+
+    if s1 == s2 {
+        return (None, 0.0, f64::INFINITY);
+    }
+
+    let frontage = n1.min(n2);
+
+    let (team, nw, kw, nl, kl) = if s1 > s2 {
+        (Team::Team1, n1, k1, n2, k2)
+    } else {
+        (Team::Team2, n2, k2, n1, k1)
+    };
+
+    let remaining = nw - (kl / kw) * nl;
+    let duration = nl / (kw * frontage);
+
+    (Some(team), remaining / nw, duration)
+}
+
+/// Returns the average per-unit kill rate of `attk` against `dfnd`, as well as the melee and total
+/// DPS of `attk` (used to pick a `Law`).
+///
+/// Units are assumed to spread their attacks evenly across every enemy unit they can hit, which
+/// mirrors the simulator's default target acquisition.
+fn kill_rate(attk: &Army, dfnd: &Army) -> (f64, f64, f64) {
+    let attackers = alive_counts(attk);
+    let defenders = alive_counts(dfnd);
+
+    let mut kills = 0.0;
+    let mut melee = 0.0;
+    let mut total = 0.0;
+    let mut count = 0.0;
+
+    for (a_base, a_count) in &attackers {
+        let a_unit = &attk.base_units[a_base];
+        count += *a_count as f64;
+
+        let mut hittable = 0.0;
+        let mut time_to_kill = 0.0;
+        let mut dps = 0.0;
+        let mut melee_dps = 0.0;
+
+        for (d_base, d_count) in &defenders {
+            let d_unit = &dfnd.base_units[d_base];
+            let Some(weapon) = a_unit.try_get_weapon(d_unit) else {
+                continue;
+            };
+
+            let ttk = time_to_kill_one(weapon, d_unit);
+            hittable += *d_count as f64;
+            time_to_kill += ttk * *d_count as f64;
+            let unit_dps = (d_unit.hull.max + d_unit.shields.max).as_f64() / ttk;
+            dps += unit_dps * *d_count as f64;
+            if weapon.is_melee() {
+                melee_dps += unit_dps * *d_count as f64;
+            }
+        }
+
+        if hittable == 0.0 {
+            continue;
+        }
+
+        kills += *a_count as f64 / (time_to_kill / hittable);
+        total += dps / hittable * *a_count as f64;
+        melee += melee_dps / hittable * *a_count as f64;
+    }
+
+    if count == 0.0 {
+        return (0.0, 0.0, 0.0);
+    }
+
+    (kills / count, melee / count, total / count)
+}
+
+fn alive_counts(army: &Army) -> Vec<(Base, usize)> {
+    army.units
+        .iter()
+        .filter(|u| u.is_alive())
+        .map(|u| u.base)
+        .counts()
+        .into_iter()
+        .collect()
+}
+
+/// The expected number of seconds it takes a single unit with `weapon` to kill a full health
+/// `target`
+fn time_to_kill_one(weapon: &Weapon, target: &Unit) -> f64 {
+    let hits = weapon.multihit.multiplier().as_f64();
+    let shield_dmg = MIN_DAMAGE.max(weapon.get_shield_damage(target)).as_f64() * hits;
+    let hull_dmg = MIN_DAMAGE.max(weapon.get_damage(target)).as_f64() * hits;
+
+    let attacks = target.shields.max.as_f64() / shield_dmg + target.hull.max.as_f64() / hull_dmg;
+    let delay = (weapon.random_delay.start() + weapon.random_delay.end()).as_f64() / 2.0;
+
+    attacks * (weapon.attack_speed.as_f64() + delay)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coordinator::Coordinator;
+
+    fn army(units: &[(Unit, usize)]) -> Army {
+        let mut army = Army::default();
+        for (unit, count) in units {
+            army.add_unit(unit.clone(), *count);
+        }
+        army
+    }
+
+    #[test]
+    fn auto_law() {
+        let marines = army(&[(Unit::MARINE, 20)]);
+
+        let zealots = army(&[(Unit::ZEALOT, 12)]);
+        assert_eq!(estimate(&zealots, &marines, Law::Auto).law, Law::Linear);
+
+        let stalkers = army(&[(Unit::STALKER, 12)]);
+        assert_eq!(estimate(&stalkers, &marines, Law::Auto).law, Law::Square);
+
+        // a couple of zealots don't make a mostly ranged army melee, no matter how many targets
+        // they have
+        let mixed = army(&[(Unit::ZEALOT, 2), (Unit::STALKER, 10)]);
+        assert_eq!(estimate(&mixed, &marines, Law::Auto).law, Law::Square);
+    }
+
+    /// Per-marine kill rate against other marines: 45 health at 6 damage a shot is 7.5 shots
+    fn marine_kill_rate() -> f64 {
+        let weapon = &Unit::MARINE.weapons[0];
+        let delay = (weapon.random_delay.start() + weapon.random_delay.end()).as_f64() / 2.0;
+        1.0 / (7.5 * (weapon.attack_speed.as_f64() + delay))
+    }
+
+    #[test]
+    fn square_law() {
+        let est = estimate(
+            &army(&[(Unit::MARINE, 10)]),
+            &army(&[(Unit::MARINE, 6)]),
+            Law::Square,
+        );
+
+        // sqrt(10^2 - 6^2) = 8 of the 10 marines survive, worth 400 minerals. The loser runs out
+        // when tanh(k * t) = 6 / 10
+        assert_eq!(est.winner, Some(Team::Team1));
+        assert!((est.remaining_frac - 0.8).abs() < 1e-9);
+        assert!(
+            (est.remaining_value - const_real!(400)).abs() < 1,
+            "{}",
+            est.remaining_value
+        );
+        let duration = 0.6f64.atanh() / marine_kill_rate();
+        assert!(
+            (est.duration.as_f64() - duration).abs() < 0.01,
+            "{}",
+            est.duration
+        );
+    }
+
+    #[test]
+    fn linear_law() {
+        let est = estimate(
+            &army(&[(Unit::MARINE, 6)]),
+            &army(&[(Unit::MARINE, 10)]),
+            Law::Linear,
+        );
+
+        // 10 - 6 = 4 of the 10 marines survive, worth 200 minerals. The 6 losers are killed 6 at a
+        // time
+        assert_eq!(est.winner, Some(Team::Team2));
+        assert!((est.remaining_frac - 0.4).abs() < 1e-9);
+        assert!(
+            (est.remaining_value - const_real!(200)).abs() < 1,
+            "{}",
+            est.remaining_value
+        );
+        let duration = 1.0 / marine_kill_rate();
+        assert!(
+            (est.duration.as_f64() - duration).abs() < 0.01,
+            "{}",
+            est.duration
+        );
+    }
+
+    #[test]
+    fn error_against_simulation() {
+        let mut c = Coordinator {
+            a1: army(&[(Unit::MARINE, 10)]),
+            a2: army(&[(Unit::MARINE, 6)]),
+            ..Default::default()
+        };
+        let est = c.estimate(Law::Square);
+        let outcome = c.simulate();
+        let err = est.error(&outcome);
+
+        let remaining = outcome.cost_units_remaining().total_resources();
+        assert!(err.winner_correct);
+        assert_eq!(err.duration, est.duration - outcome.duration());
+        assert_eq!(err.remaining_value, est.remaining_value - remaining);
+        // the simulation isn't exactly Lanchester, but it shouldn't be off by more than a couple
+        // of marines
+        assert!(err.remaining_value.abs() <= 100, "{err:?}");
+    }
+}
@@ -3,6 +3,18 @@
 pub mod unit;
 pub mod army;
 pub mod coordinator;
+pub mod controller;
+pub mod micro;
+pub mod movement;
+pub mod terrain;
+pub mod creep;
+pub mod cargo;
+pub mod damage;
+pub mod vision;
+pub mod metrics;
+pub mod estimate;
+pub mod optimize;
+pub mod sensitivity;
 pub mod utils;
 pub use utils::{Range, RangeInclusive, CollCircle, Pos};
 pub mod effect;
@@ -189,6 +201,47 @@ pub const SHIELD_RECHARGE_RATE: Real = rate!(2.0);
 pub const ZERG_REGEN: Real = rate!(0.2734);
 pub const MUTA_REGEN: Real = rate!(1.0);
 pub const ENERGY_REGEN: Real = rate!(0.5625);
+pub const TRANSFUSION_ENERGY: Real = unit::Ability::TRANSFUSION.energy_cost;
+pub const TRANSFUSION_RANGE: Real = unit::Ability::TRANSFUSION.range;
+/// Instant heal, on top of the heal over time below
+pub const TRANSFUSION_HEAL: Real = const_real!(75);
+/// 50 life over ~7.14 seconds
+pub const TRANSFUSION_REGEN: Real = rate!(5.0);
+pub const TRANSFUSION_DURATION: Real = duration!(10.0);
+/// ~50.4 shields/s
+pub const SHIELD_BATTERY_RATE: Real = rate!(36.0);
+pub const SHIELD_BATTERY_RANGE: Real = const_real!(6);
+/// Energy drained per point of shields restored
+pub const SHIELD_BATTERY_DRAIN: Real = const_real!(0.33);
+/// Units in a bunker get +1 range
+pub const BUNKER_RANGE_BONUS: Real = const_real!(1);
+/// How close (edge to edge) a unit has to get to its transport to load
+pub const LOAD_RANGE: Real = const_real!(1);
+/// Flying transports drop one unit per period. Bunkers unload everything at once
+pub const UNLOAD_PERIOD: Real = duration!(1.0);
+/// Marines and marauders lose this much life to stim
+pub const STIM_COST_MARINE: Real = unit::Ability::STIMPACK.life_cost;
+pub const STIM_COST_MARAUDER: Real = unit::Ability::STIMPACKMARAUDER.life_cost;
+pub const STIM_DURATION: Real = duration!(15.0);
+pub const STIM_ATTACK_SPEED: Real = const_real!(1.5);
+pub const STIM_SPEED: Real = const_real!(1.5);
+pub const GUARDIAN_SHIELD_ENERGY: Real = unit::Ability::GUARDIANSHIELD.energy_cost;
+pub const GUARDIAN_SHIELD_RADIUS: Real = const_real!(4.5);
+/// 36 periods of 0.5
+pub const GUARDIAN_SHIELD_DURATION: Real = duration!(18.0);
+/// Ranged attacks against units in a Guardian Shield deal this much less damage
+pub const GUARDIAN_SHIELD_REDUCTION: Real = const_real!(2);
+/// Extra damage against armored units
+pub const PRISMATIC_ALIGNMENT_BONUS: Real = const_real!(6);
+pub const PRISMATIC_ALIGNMENT_DURATION: Real = duration!(20.0);
+pub const PRISMATIC_ALIGNMENT_COOLDOWN: Real = duration!(60.0);
+pub const PRISMATIC_ALIGNMENT_SPEED: Real = const_real!(0.75);
+pub const BARRIER_ABSORB: Real = const_real!(100);
+pub const BARRIER_DURATION: Real = duration!(3.0);
+pub const BARRIER_COOLDOWN: Real = duration!(45.0);
+/// Hits on an Immortal with Hardened Shields are capped at this much damage while it has shields
+pub const HARDENED_SHIELDS_CAP: Real = const_real!(10);
+pub const CHITINOUS_PLATING_ARMOR: Real = const_real!(2);
 /// Attacks must do at least this much damage. If an attack's damage is reduced below this amount via
 /// armor or other damage reduction, it is clamped to 0.5
 pub const MIN_DAMAGE: Real = const_real!(0.5);
@@ -0,0 +1,144 @@
+use crate::{army::Army, coordinator::Team, unit::Cost, *};
+
+/// A snapshot of a single army's state at one point during a fight
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sample {
+    pub time: Real,
+    /// Current hull + shields of all living units
+    pub health: Real,
+    /// Cost of all living units
+    pub resources: Cost,
+    pub supply: Real,
+    pub units_alive: u32,
+    /// Damage dealt per second since the previous sample
+    pub dps: Real,
+    /// Cumulative damage dealt, not including overkill
+    pub damage_dealt: Real,
+    /// Cumulative overkill
+    pub overkill: Real,
+}
+
+impl Sample {
+    pub fn new(army: &Army, time: Real, prev: Option<&Sample>) -> Self {
+        let damage_dealt = army.damage_dealt();
+        let dps = match prev {
+            Some(p) if time > p.time => (damage_dealt - p.damage_dealt) / (time - p.time),
+            _ => Real::default(),
+        };
+
+        Self {
+            time,
+            health: army.living_health(),
+            resources: army.resources_remaining(),
+            supply: army.supply_remaining(),
+            units_alive: army.units_alive() as u32,
+            dps,
+            damage_dealt,
+            overkill: army.overkill(),
+        }
+    }
+
+    /// The fraction of the army's starting health that is still remaining
+    pub fn health_frac(&self, army: &Army) -> Real {
+        let max = army.total_health();
+        if max == 0 {
+            return Real::default();
+        }
+
+        self.health / max
+    }
+}
+
+/// Optional time-series sampling of both armies during `Coordinator::simulate`. Sampling is
+/// disabled by default since it adds a bit of overhead to every tick.
+#[derive(Debug, Clone, Default)]
+pub struct Metrics {
+    /// Number of ticks between samples. `None` disables sampling
+    pub interval: Option<u32>,
+    pub team_1: Vec<Sample>,
+    pub team_2: Vec<Sample>,
+}
+
+impl Metrics {
+    /// Samples both armies every `ticks` ticks. The first and last tick of a fight are always
+    /// sampled.
+    pub fn every(ticks: u32) -> Self {
+        Self {
+            interval: Some(ticks.max(1)),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.interval.is_some()
+    }
+
+    pub fn clear(&mut self) {
+        self.team_1.clear();
+        self.team_2.clear();
+    }
+
+    pub fn series(&self, team: Team) -> &[Sample] {
+        match team {
+            Team::Team1 => &self.team_1,
+            Team::Team2 => &self.team_2,
+        }
+    }
+
+    /// Records a sample if `tick` lands on the sampling interval, or unconditionally if `force` is
+    /// set.
+    pub(crate) fn record(&mut self, tick: u32, time: Real, a1: &Army, a2: &Army, force: bool) {
+        let Some(interval) = self.interval else {
+            return;
+        };
+
+        if !force && !tick.is_multiple_of(interval) {
+            return;
+        }
+
+        // the final forced sample can land on the same tick as a regular one
+        if self.team_1.last().is_some_and(|s| s.time == time) {
+            return;
+        }
+
+        let s1 = Sample::new(a1, time, self.team_1.last());
+        let s2 = Sample::new(a2, time, self.team_2.last());
+        self.team_1.push(s1);
+        self.team_2.push(s2);
+    }
+
+    /// Returns the team with the larger fraction of its starting health remaining at each sample,
+    /// or `None` if they're even.
+    pub fn leader(&self, a1: &Army, a2: &Army) -> Vec<(Real, Option<Team>)> {
+        self.team_1
+            .iter()
+            .zip(self.team_2.iter())
+            .map(|(s1, s2)| {
+                let h1 = s1.health_frac(a1);
+                let h2 = s2.health_frac(a2);
+                let leader = match h1.cmp(&h2) {
+                    std::cmp::Ordering::Greater => Some(Team::Team1),
+                    std::cmp::Ordering::Less => Some(Team::Team2),
+                    std::cmp::Ordering::Equal => None,
+                };
+                (s1.time, leader)
+            })
+            .collect()
+    }
+
+    /// Returns the timestamp at which `team` took the lead for the last time, i.e. the point after
+    /// which the fight never swung back. Returns `None` if `team` is not leading at the final sample
+    pub fn tipping_point(&self, team: Team, a1: &Army, a2: &Army) -> Option<Real> {
+        let leader = self.leader(a1, a2);
+        if leader.last()?.1 != Some(team) {
+            return None;
+        }
+
+        leader
+            .iter()
+            .rev()
+            .take_while(|(_, l)| *l == Some(team))
+            .last()
+            .map(|(t, _)| *t)
+    }
+}
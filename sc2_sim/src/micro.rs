@@ -0,0 +1,427 @@
+use std::cmp::Reverse;
+
+use rand::prelude::*;
+
+use crate::{
+    army::{ActionState, State},
+    cargo::attack_circle,
+    controller::{closest_in_range, in_range, random_target, ArmyController, Command, View},
+    unit::Base,
+    *,
+};
+
+/// Units retreat out of the enemy's range once their health drops too low, and rejoin the fight
+/// once they've regenerated.
+///
+/// Health is measured as `(hull + shields) / (max hull + max shields)`. Units that can't regenerate
+/// (and aren't healed) never return.
+#[derive(Debug, Clone, Copy)]
+pub struct Retreat {
+    pub below: Real,
+    pub return_above: Real,
+    /// Retreating units won't move further than this from where they spawned. Stands in for the
+    /// edge of the map, otherwise faster units could run forever.
+    pub leash: Real,
+}
+
+impl Default for Retreat {
+    fn default() -> Self {
+        Self {
+            below: const_real!(0.25),
+            return_above: const_real!(0.9),
+            leash: const_real!(20),
+        }
+    }
+}
+
+/// A controller with a handful of built-in micro policies, each of which can be toggled
+/// independently. With every policy disabled, this behaves like `AMove`.
+///
+/// Kiting and retreating require the armies to be spread out (see `Army::add_unit_at`). Units that
+/// share a position have no direction to move in.
+#[derive(Debug, Clone, Default)]
+pub struct Micro {
+    /// Units pick the lowest health enemy that isn't already going to die to their allies' pending
+    /// attacks, rather than a random one
+    pub focus_fire: bool,
+    /// Ranged units cancel their backswing and step back towards the edge of their range while
+    /// their weapon is on cooldown
+    pub kite: bool,
+    pub retreat: Option<Retreat>,
+    /// Queens transfuse the most damaged ally in range. If there's creep, queens also stay on it:
+    /// they walk back onto creep when they end up off of it, and only attack enemies in range
+    /// rather than chasing them
+    pub transfuse: bool,
+    retreating: Vec<bool>,
+    /// Targets of units that are currently kiting, since moving clears a unit's target
+    kiting: Vec<Option<u32>>,
+}
+
+impl Micro {
+    // the gap left between a kiting unit and the edge of its weapon's range, so that units don't
+    // end up just out of range due to rounding
+    const KITE_MARGIN: Real = const_real!(0.25);
+    // how far past the enemy's range retreating units go
+    const RETREAT_MARGIN: Real = const_real!(1);
+
+    pub fn new(focus_fire: bool, kite: bool, retreat: Option<Retreat>) -> Self {
+        Self {
+            focus_fire,
+            kite,
+            retreat,
+            ..Default::default()
+        }
+    }
+
+    fn current_target(&self, view: &View, handle: usize) -> Option<u32> {
+        view.army.units[handle]
+            .target
+            .or(self.kiting[handle])
+            .filter(|&t| view.can_acquire(handle, t as usize))
+    }
+
+    /// Expected damage that each enemy unit is about to take from its attackers' next volley
+    fn pending_damage(&self, view: &View) -> Vec<Real> {
+        let mut pending = vec![const_real!(0); view.enemy.units.len()];
+
+        for handle in 0..view.army.units.len() {
+            if !view.is_active(handle) {
+                continue;
+            }
+            if let Some(t) = self.current_target(view, handle) {
+                pending[t as usize] += volley(view, handle, t as usize);
+            }
+        }
+
+        pending
+    }
+
+    /// The lowest health enemy that won't die to pending damage, preferring targets that are
+    /// already in range
+    fn focus_target(view: &View, handle: usize, pending: &[Real]) -> Option<u32> {
+        let pos = attack_circle(&view.army.units[handle], &view.army.positions, handle);
+
+        (0..view.enemy.units.len())
+            .filter(|&t| view.can_acquire(handle, t))
+            .min_by_key(|&t| {
+                let target = &view.enemy.units[t];
+                let t_pos = view.enemy.positions[t];
+                let left = target.hull + target.shields - pending[t];
+                let in_range = view
+                    .weapon_against(handle, t)
+                    .is_some_and(|w| w.in_range(pos, t_pos));
+
+                (
+                    left <= 0,
+                    Reverse(view.enemy.unit_from_handle(t).target_priority),
+                    !in_range,
+                    left,
+                    pos.pos.dist_squared(t_pos.pos),
+                )
+            })
+            .map(|t| t as u32)
+    }
+
+    /// The ally missing the most life that the queen at `handle` can transfuse right now, ignoring
+    /// allies that are already being transfused or that would waste part of the instant heal
+    fn transfusion_target(view: &View, handle: usize, claimed: &[bool]) -> Option<u32> {
+        let queen = &view.army.units[handle];
+        if !queen.on_creep || queen.energy.is_none_or(|e| e < TRANSFUSION_ENERGY) {
+            return None;
+        }
+
+        (0..view.army.units.len())
+            .filter(|&t| {
+                let ally = &view.army.units[t];
+                t != handle
+                    && !claimed[t]
+                    && view.is_active(t)
+                    && ally.transfusion.is_none_or(|end| end <= view.time)
+                    && view.army.unit_from_handle(t).hull.max - ally.hull >= TRANSFUSION_HEAL
+                    && view.army.in_transfusion_range(handle, t)
+            })
+            .max_by_key(|&t| view.army.unit_from_handle(t).hull.max - view.army.units[t].hull)
+            .map(|t| t as u32)
+    }
+
+    /// Returns true if the unit has dropped below the retreat threshold and hasn't recovered yet
+    fn update_retreat(&mut self, view: &View, handle: usize) -> bool {
+        let Some(retreat) = self.retreat else {
+            return false;
+        };
+
+        let unit = &view.army.units[handle];
+        let base = view.army.unit_from_handle(handle);
+        let max = base.hull.max + base.shields.max;
+        if max == 0 || !unit.can_move() {
+            return false;
+        }
+
+        let frac = (unit.hull + unit.shields) / max;
+        if self.retreating[handle] {
+            self.retreating[handle] = frac < retreat.return_above;
+        } else {
+            self.retreating[handle] = frac < retreat.below;
+        }
+
+        self.retreating[handle]
+    }
+
+    /// Moves directly away from the closest enemy that can hit the unit, until it is out of range of
+    /// all of them
+    fn retreat_command(view: &View, handle: usize, leash: Real) -> Option<Command> {
+        let pos = view.army.positions[handle];
+
+        let mut closest: Option<(Real, Pos, Real)> = None;
+        for (t, enemy) in view.enemy.units.iter().enumerate() {
+            if enemy.is_dead() {
+                continue;
+            }
+            // the weapon the enemy would hit the unit with from where it is now
+            let t_pos = view.enemy.positions[t];
+            let props = view.army.filter_props(handle, true);
+            let Some(weapon) = view
+                .enemy
+                .unit_from_handle(t)
+                .weapon_against(props, t_pos, pos)
+            else {
+                continue;
+            };
+
+            let safe = weapon.range.end() + pos.r + t_pos.r + Self::RETREAT_MARGIN;
+            let dist = pos.pos.dist(t_pos.pos);
+            if dist < safe && closest.is_none_or(|(d, ..)| dist < d) {
+                closest = Some((dist, t_pos.pos, safe));
+            }
+        }
+
+        let unit = &view.army.units[handle];
+        let spawn = view.army.spawns[handle];
+        match closest {
+            Some((dist, from, safe)) if dist > 0 => {
+                let mut dest = Pos {
+                    x: from.x + (pos.pos.x - from.x) * safe / dist,
+                    y: from.y + (pos.pos.y - from.y) * safe / dist,
+                };
+
+                let from_spawn = spawn.dist(dest);
+                if from_spawn > leash {
+                    dest.x = spawn.x + (dest.x - spawn.x) * leash / from_spawn;
+                    dest.y = spawn.y + (dest.y - spawn.y) * leash / from_spawn;
+                }
+
+                Some(Command::Move(dest))
+            }
+            // either already safe, or there's no direction to run in
+            _ if unit.target.is_some() || unit.destination.is_some() => Some(Command::Hold),
+            _ => None,
+        }
+    }
+
+    /// If the unit should step back from its target, returns where to
+    fn kite_destination(view: &View, handle: usize, target: usize) -> Option<Pos> {
+        let unit = &view.army.units[handle];
+        let weapon = view.weapon_against(handle, target)?;
+
+        // the attack hasn't hit yet, or the weapon is about to be ready again. Moving during the
+        // backswing cancels it, which is the whole point of stutter stepping
+        if weapon.is_melee()
+            || !unit.can_move()
+            || matches!(unit.state, ActionState::DmgPoint(..))
+            || unit.attack_cd <= view.time + TICK
+        {
+            return None;
+        }
+
+        let pos = view.army.positions[handle];
+        let t_pos = view.enemy.positions[target];
+        let edge = weapon.range.end() + pos.r + t_pos.r - Self::KITE_MARGIN;
+        let dist = pos.pos.dist(t_pos.pos);
+
+        if dist == 0 || dist >= edge {
+            return None;
+        }
+
+        Some(Pos {
+            x: t_pos.pos.x + (pos.pos.x - t_pos.pos.x) * edge / dist,
+            y: t_pos.pos.y + (pos.pos.y - t_pos.pos.y) * edge / dist,
+        })
+    }
+}
+
+impl ArmyController for Micro {
+    fn commands(&mut self, view: &View, rng: &mut StdRng) -> Vec<(u32, Command)> {
+        let count = view.army.units.len();
+        self.retreating.resize(count, false);
+        self.kiting.resize(count, None);
+
+        let mut pending = if self.focus_fire {
+            self.pending_damage(view)
+        } else {
+            Vec::new()
+        };
+        let mut result = Vec::new();
+        let mut transfused = vec![false; count];
+
+        for handle in 0..count {
+            let unit = &view.army.units[handle];
+            // units walking to a transport were ordered to by something else
+            if !view.is_active(handle) || unit.boarding.is_some() {
+                self.kiting[handle] = None;
+                continue;
+            }
+
+            if self.update_retreat(view, handle) {
+                self.kiting[handle] = None;
+                let leash = self.retreat.map_or(const_real!(0), |r| r.leash);
+                if let Some(cmd) = Self::retreat_command(view, handle, leash) {
+                    result.push((handle as u32, cmd));
+                }
+                continue;
+            }
+
+            let stays_on_creep = self.transfuse && unit.base == Base::Queen && view.creep.is_some();
+            if self.transfuse && unit.base == Base::Queen {
+                if let Some(t) = Self::transfusion_target(view, handle, &transfused) {
+                    transfused[t as usize] = true;
+                    result.push((handle as u32, Command::Transfuse(t)));
+                }
+            }
+
+            if stays_on_creep && !unit.on_creep {
+                let dest = view
+                    .creep
+                    .and_then(|c| c.nearest(view.army.positions[handle].pos));
+                let moving_there =
+                    |d: Pos| unit.destination.is_some_and(|cur| cur.x == d.x && cur.y == d.y);
+                if let Some(dest) = dest.filter(|&d| !moving_there(d)) {
+                    self.kiting[handle] = None;
+                    result.push((handle as u32, Command::Move(dest)));
+                }
+                continue;
+            }
+
+            // committed to the attack
+            if matches!(unit.state, ActionState::DmgPoint(..)) {
+                continue;
+            }
+
+            let current = self.current_target(view, handle);
+            let target = if stays_on_creep {
+                // don't chase anything off the creep
+                current
+                    .filter(|&t| in_range(view, handle, t as usize))
+                    .or_else(|| closest_in_range(view, handle))
+            } else if self.focus_fire {
+                if let Some(t) = current {
+                    pending[t as usize] -= volley(view, handle, t as usize);
+                }
+
+                let t = match current {
+                    Some(t) if pending[t as usize] < remaining(&view.enemy.units[t as usize]) => {
+                        Some(t)
+                    }
+                    _ => Self::focus_target(view, handle, &pending),
+                };
+
+                if let Some(t) = t {
+                    pending[t as usize] += volley(view, handle, t as usize);
+                }
+                t
+            } else {
+                current.or_else(|| random_target(view, handle, rng))
+            };
+
+            let Some(target) = target else {
+                self.kiting[handle] = None;
+                if unit.target.is_some() {
+                    result.push((handle as u32, Command::Hold));
+                }
+                continue;
+            };
+
+            if self.kite && !stays_on_creep {
+                if let Some(dest) = Self::kite_destination(view, handle, target as usize) {
+                    self.kiting[handle] = Some(target);
+                    result.push((handle as u32, Command::Move(dest)));
+                    continue;
+                }
+            }
+
+            self.kiting[handle] = None;
+            if unit.target != Some(target) || unit.state == ActionState::Move {
+                result.push((handle as u32, Command::Attack(target)));
+            }
+        }
+
+        result
+    }
+}
+
+fn remaining(unit: &State) -> Real {
+    unit.hull + unit.shields
+}
+
+/// Expected damage of a single attack from the unit at `handle` against the enemy at `target`
+fn volley(view: &View, handle: usize, target: usize) -> Real {
+    let t_unit = view.enemy.unit_from_handle(target);
+    let Some(weapon) = view.weapon_against(handle, target) else {
+        return const_real!(0);
+    };
+
+    let dmg = if view.enemy.units[target].shields > 0 {
+        weapon.get_shield_damage(t_unit)
+    } else {
+        weapon.get_damage(t_unit)
+    };
+
+    MIN_DAMAGE.max(dmg) * weapon.multihit.multiplier()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{army::Army, unit::Unit};
+
+    #[test]
+    fn hydra_range() {
+        let mut hydras = Army::default();
+        hydras.add_unit_at(
+            Unit::HYDRALISK,
+            1,
+            Pos {
+                x: real!(0),
+                y: real!(0),
+            },
+        );
+        let mut zealots = Army::default();
+        zealots.add_unit(Unit::ZEALOT, 1);
+        // 4.5 apart edge to edge
+        let gap = hydras.positions[0].r + zealots.positions[0].r + const_real!(4.5);
+        zealots.positions[0].pos = Pos {
+            x: gap,
+            y: real!(0),
+        };
+
+        let view = View {
+            army: &hydras,
+            enemy: &zealots,
+            time: real!(0),
+            creep: None,
+        };
+        let weapon = view.weapon_against(0, 0).unwrap();
+        assert_eq!(weapon.range.end(), 5);
+        assert!(in_range(&view, 0, 0));
+
+        // between shots, the hydra backs off to the edge of its range
+        hydras.units[0].attack_cd = real!(1);
+        let view = View {
+            army: &hydras,
+            enemy: &zealots,
+            time: real!(0),
+            creep: None,
+        };
+        let dest = Micro::kite_destination(&view, 0, 0).unwrap();
+        assert!(dest.x < 0, "{dest:?}");
+    }
+}
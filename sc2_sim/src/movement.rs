@@ -0,0 +1,235 @@
+use crate::{
+    army::{ActionState, Army, State},
+    terrain::Terrain,
+    unit::Collision,
+    utils::{angle_diff, radians, turn_towards},
+    *,
+};
+
+impl Army {
+    /// Integrates each unit's movement for one tick.
+    ///
+    /// Units always move in the direction they're facing. They turn towards their destination at
+    /// their turn rate, which is further limited by their lateral acceleration at high speeds (i.e.
+    /// fast units take wide turns). Their speed ramps up by `accel` and down by `decel`, slowing for
+    /// sharp turns and braking so they stop at their destination. Units without a destination coast
+    /// to a stop.
+    ///
+    /// With terrain, ground units path around unpathable cells and slide along any walls they run
+    /// into.
+    pub(crate) fn move_units(&mut self, terrain: Option<&Terrain>) {
+        for (handle, unit) in self.units.iter_mut().enumerate() {
+            if unit.is_dead() {
+                continue;
+            }
+            if unit.is_movement_locked() {
+                unit.speed = const_real!(0);
+                continue;
+            }
+
+            let movement = &self.base_units[&unit.base].movement;
+            // units that can't accelerate (e.g. burrowed roaches) can't move at all
+            if movement.accel == 0 {
+                unit.speed = const_real!(0);
+                continue;
+            }
+
+            let circle = &mut self.positions[handle];
+            let terrain = terrain.filter(|_| circle.plane == Collision::Ground);
+            let pos = &mut circle.pos;
+
+            // `rate!` only converts one of the "per second"s in an acceleration
+            let accel = rate!(movement.accel);
+            // a deceleration of 0 means the unit brakes as fast as it accelerates
+            let decel = if movement.decel == 0 {
+                accel
+            } else {
+                rate!(movement.decel)
+            };
+
+            let mut desired = const_real!(0);
+            let mut dist = const_real!(0);
+
+            if let Some(dest) = unit.destination {
+                dist = pos.dist(dest);
+                if dist == 0 {
+                    unit.arrive();
+                    continue;
+                }
+
+                let steer = terrain.map_or(dest, |t| unit.steer(t, *pos, dest));
+                let heading = Real::atan2(steer.y - pos.y, steer.x - pos.x);
+                let mut turn = radians(movement.turn_rate) * TICK;
+                // a lateral acceleration of 0 means turning isn't limited by speed
+                if movement.lateral_accel > 0 && unit.speed > 0 {
+                    turn = turn.min(rate!(movement.lateral_accel) / unit.speed * TICK);
+                }
+                unit.facing = turn_towards(unit.facing, heading, turn);
+
+                // slow down to turn around, coming to a stop if the destination is behind us
+                let off = angle_diff(unit.facing, heading);
+                desired = unit.max_speed * real!(off.as_f64().cos()).max(const_real!(0));
+
+                // brake in time to stop at the destination
+                let braking_dist = unit.speed * unit.speed / (decel * 2);
+                if dist < braking_dist {
+                    desired = desired.min(Real::sqrt(decel * dist * 2));
+                }
+            }
+
+            unit.speed = if unit.speed < desired {
+                desired.min(unit.speed + accel * TICK)
+            } else {
+                desired.max(unit.speed - decel * TICK)
+            };
+
+            if unit.speed == 0 {
+                continue;
+            }
+
+            let step = unit.speed * TICK;
+            let next = match unit.destination {
+                Some(dest) if dist <= step => dest,
+                _ => {
+                    let facing = unit.facing.as_f64();
+                    Pos {
+                        x: pos.x + step * real!(facing.cos()),
+                        y: pos.y + step * real!(facing.sin()),
+                    }
+                }
+            };
+
+            let next = match terrain {
+                Some(t) if !t.is_pathable(next) => {
+                    // slide along the wall if possible
+                    let slides = [Pos { x: next.x, y: pos.y }, Pos { x: pos.x, y: next.y }];
+                    match slides.into_iter().find(|&p| t.is_pathable(p)) {
+                        Some(p) => p,
+                        None => {
+                            unit.speed = const_real!(0);
+                            continue;
+                        }
+                    }
+                }
+                _ => next,
+            };
+
+            *pos = next;
+            if unit.destination.is_some_and(|dest| dest.x == next.x && dest.y == next.y) {
+                unit.arrive();
+            }
+        }
+    }
+}
+
+impl State {
+    fn arrive(&mut self) {
+        self.destination = None;
+        self.path.clear();
+        self.path_goal = None;
+        if self.state == ActionState::Move {
+            self.state = ActionState::Wait;
+        }
+    }
+
+    /// The point a ground unit should head towards to reach `dest`, following (and updating) its
+    /// path around obstacles
+    fn steer(&mut self, terrain: &Terrain, pos: Pos, dest: Pos) -> Pos {
+        if terrain.line_walkable(pos, dest) {
+            self.path.clear();
+            self.path_goal = None;
+            return dest;
+        }
+
+        // only repath when the destination moves to a different cell
+        let goal = terrain.cell_of(dest);
+        if self.path_goal != goal {
+            self.path = terrain.path(pos, dest).unwrap_or_default();
+            self.path.reverse();
+            self.path_goal = goal;
+        }
+
+        // drop waypoints that have been reached, or that can be skipped
+        let reached = terrain.cell_size / 2;
+        while let [.., next, last] = self.path[..] {
+            if pos.dist_squared(last) > reached * reached && !terrain.line_walkable(pos, next) {
+                break;
+            }
+            self.path.pop();
+        }
+
+        self.path.last().copied().unwrap_or(dest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit::Unit;
+
+    fn moving(unit: Unit, speed: Real) -> Army {
+        let mut army = Army::default();
+        army.add_unit(unit, 1);
+        army.units[0].speed = speed;
+        army.units[0].destination = Some(Pos {
+            x: const_real!(0.5),
+            y: const_real!(0),
+        });
+        army
+    }
+
+    #[test]
+    fn zero_accel() {
+        let mut army = moving(Unit::ROACHBURROWED, const_real!(1));
+        for _ in 0..100 {
+            army.move_units(None);
+        }
+        assert_eq!(army.positions[0].pos.x, 0);
+        assert_eq!(army.units[0].speed, 0);
+    }
+
+    #[test]
+    fn arrives() {
+        let mut army = moving(Unit::ZEALOT, const_real!(0));
+        for _ in 0..100 {
+            army.move_units(None);
+        }
+        assert_eq!(army.positions[0].pos.x, 0.5);
+        assert!(army.units[0].destination.is_none());
+    }
+
+    #[test]
+    fn accel_decel() {
+        // overlords have a slow, distinct acceleration and deceleration
+        let movement = &Unit::OVERLORD.movement;
+        let mut army = moving(Unit::OVERLORD, const_real!(0));
+        army.units[0].destination = Some(Pos {
+            x: const_real!(100),
+            y: const_real!(0),
+        });
+
+        // the generated accelerations only have one of their "per second"s converted, see
+        // `move_units`
+        let per_sec = |x: Real| x.as_f64() * 1.4;
+        let top = movement.speed.as_f64();
+        let tick = TICK.as_f64();
+
+        let mut ticks = 0;
+        while army.units[0].speed < movement.speed {
+            army.move_units(None);
+            ticks += 1;
+        }
+        let expected = (top / per_sec(movement.accel) / tick).ceil();
+        assert_eq!(ticks as f64, expected);
+
+        // coast to a stop, covering v^2 / 2d give or take a tick of movement
+        army.units[0].destination = None;
+        let start = army.positions[0].pos.x;
+        while army.units[0].speed > 0 {
+            army.move_units(None);
+        }
+        let dist = (army.positions[0].pos.x - start).as_f64();
+        let expected = top * top / (2.0 * per_sec(movement.decel));
+        assert!((dist - expected).abs() < top * tick, "{dist} vs {expected}");
+    }
+}
@@ -0,0 +1,393 @@
+use rand::prelude::*;
+use strum::IntoEnumIterator;
+
+use crate::{
+    army::Army,
+    coordinator::{BatchResult, Coordinator, Team},
+    estimate::Law,
+    unit::{Base, Cost, Faction, Flag, Unit},
+    *,
+};
+
+/// Upper bounds on the resources and supply a composition may use
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Budget {
+    pub minerals: Real,
+    pub gas: Real,
+    pub supply: Real,
+}
+
+impl Budget {
+    pub fn new(minerals: u32, gas: u32, supply: u32) -> Self {
+        Self {
+            minerals: real!(minerals),
+            gas: real!(gas),
+            supply: real!(supply),
+        }
+    }
+
+    pub fn allows(&self, cost: Cost) -> bool {
+        cost.minerals <= self.minerals && cost.gas <= self.gas && cost.supply <= self.supply
+    }
+}
+
+/// The units the search is allowed to pick from
+#[derive(Debug, Clone)]
+pub enum UnitPool {
+    /// Every unit of the faction that can be built and can fight
+    Faction(Faction),
+    Units(Vec<Base>),
+}
+
+impl UnitPool {
+    // units that have a cost and a weapon, but can't be built directly or aren't worth considering
+    const EXCLUDED: [Base; 11] = [
+        Base::Probe,
+        Base::SCV,
+        Base::MULE,
+        Base::Drone,
+        Base::Interceptor,
+        Base::Broodling,
+        Base::Locust,
+        Base::LocustFlying,
+        Base::Changeling,
+        Base::Lurker,
+        Base::Baneling,
+    ];
+
+    // modes that units switch into after they're built. Armies can't switch modes mid-fight, so
+    // each unit is only offered in the form it's built in. Lurkers are the exception, they can
+    // only attack while burrowed
+    const ALT_MODES: [Base; 4] = [
+        Base::SiegeTankSieged,
+        Base::ThorAlt,
+        Base::VikingGround,
+        Base::LiberatorSieged,
+    ];
+
+    pub fn units(&self) -> Vec<Unit> {
+        match self {
+            UnitPool::Faction(faction) => Base::iter()
+                .filter(|b| !Self::EXCLUDED.contains(b) && !Self::ALT_MODES.contains(b))
+                .filter_map(Unit::from_base)
+                .filter(|u| {
+                    u.faction == *faction
+                        && !u.cost.is_free()
+                        // static defense can't move out with the army
+                        && !u.has_flag(Flag::Structure)
+                        // carriers fight via their interceptors
+                        && (u.has_weapons() || u.base == Base::Carrier)
+                })
+                .collect(),
+            UnitPool::Units(bases) => bases.iter().filter_map(|&b| Unit::from_base(b)).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Objective {
+    #[default]
+    WinRate,
+    /// Enemy resources lost divided by resources lost
+    CostEfficiency,
+}
+
+/// A set of units and how many of each to build
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Composition {
+    pub units: Vec<(Base, u32)>,
+}
+
+impl Composition {
+    pub fn cost(&self) -> Cost {
+        self.units
+            .iter()
+            .filter_map(|&(b, n)| Unit::from_base(b).map(|u| u.cost * n as i32))
+            .sum()
+    }
+
+    pub fn to_army(&self) -> Army {
+        let mut army = Army::default();
+        for &(base, count) in &self.units {
+            if count == 0 {
+                continue;
+            }
+            if let Some(unit) = Unit::from_base(base) {
+                army.add_unit(unit, count as usize);
+            }
+        }
+
+        army
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Candidate {
+    pub composition: Composition,
+    pub win_rate: f64,
+    pub cost_efficiency: f64,
+}
+
+impl Candidate {
+    pub fn score(&self, objective: Objective) -> f64 {
+        match objective {
+            // cost efficiency breaks ties between compositions that always win (or always lose)
+            Objective::WinRate => self.win_rate + self.cost_efficiency.min(100.0) * 1e-4,
+            Objective::CostEfficiency => self.cost_efficiency + self.win_rate * 1e-4,
+        }
+    }
+}
+
+/// Evolutionary search for compositions that beat a fixed enemy army.
+///
+/// Each generation, the best `survivors` candidates are kept and the rest of the population is
+/// refilled with mutated copies of them. Candidates are evaluated with `runs` simulations using the
+/// same seeds, so differences between candidates aren't just noise. Optionally, candidates that the
+/// Lanchester estimate says lose badly are discarded before being simulated.
+#[derive(Debug, Clone)]
+pub struct CounterSearch {
+    pub budget: Budget,
+    pub pool: UnitPool,
+    pub objective: Objective,
+    pub population: usize,
+    pub survivors: usize,
+    pub generations: usize,
+    /// Simulations per candidate
+    pub runs: usize,
+    /// Skip simulating candidates that the Lanchester estimate says lose while the enemy keeps more
+    /// than this fraction of its units. `None` disables pruning
+    pub prune_below: Option<f64>,
+    pub seed: u64,
+}
+
+impl Default for CounterSearch {
+    fn default() -> Self {
+        Self {
+            budget: Budget::default(),
+            pool: UnitPool::Faction(Faction::Protoss),
+            objective: Objective::WinRate,
+            population: 24,
+            survivors: 6,
+            generations: 10,
+            runs: 16,
+            prune_below: Some(0.5),
+            seed: 17313471783455232199,
+        }
+    }
+}
+
+impl CounterSearch {
+    pub fn new(budget: Budget, pool: UnitPool, objective: Objective) -> Self {
+        Self {
+            budget,
+            pool,
+            objective,
+            ..Default::default()
+        }
+    }
+
+    /// Returns every evaluated candidate, best first
+    pub fn run(&self, enemy: &Army) -> Vec<Candidate> {
+        let pool = self.pool.units();
+        if pool.is_empty() {
+            return Vec::new();
+        }
+
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let seeds: Vec<u64> = (0..self.runs).map(|_| rng.gen()).collect();
+
+        let mut population: Vec<Composition> = (0..self.population)
+            .map(|_| self.random_fill(&pool, vec![0; pool.len()], &mut rng))
+            .map(|counts| to_composition(&pool, &counts))
+            .collect();
+
+        let mut evaluated: Vec<Candidate> = Vec::new();
+
+        for _ in 0..self.generations {
+            for comp in population.drain(..) {
+                if comp.units.is_empty() || evaluated.iter().any(|c| c.composition == comp) {
+                    continue;
+                }
+                if let Some(candidate) = self.evaluate(comp, enemy, &seeds) {
+                    evaluated.push(candidate);
+                }
+            }
+
+            evaluated.sort_by(|a, b| {
+                b.score(self.objective)
+                    .total_cmp(&a.score(self.objective))
+            });
+
+            if evaluated.is_empty() {
+                break;
+            }
+
+            let parents = &evaluated[..self.survivors.clamp(1, evaluated.len())];
+            population = (0..self.population)
+                .map(|i| {
+                    let parent = &parents[i % parents.len()].composition;
+                    self.mutate(&pool, parent, &mut rng)
+                })
+                .collect();
+        }
+
+        evaluated
+    }
+
+    fn evaluate(&self, composition: Composition, enemy: &Army, seeds: &[u64]) -> Option<Candidate> {
+        let mut c = Coordinator {
+            a1: composition.to_army(),
+            a2: enemy.clone(),
+            ..Default::default()
+        };
+
+        if let Some(threshold) = self.prune_below {
+            let est = c.estimate(Law::Auto);
+            if est.winner == Some(Team::Team2) && est.remaining_frac > threshold {
+                return None;
+            }
+        }
+
+        let result: BatchResult = c.simulate_batch(seeds);
+
+        Some(Candidate {
+            composition,
+            win_rate: result.win_rate(Team::Team1),
+            cost_efficiency: result.cost_efficiency(Team::Team1),
+        })
+    }
+
+    /// Adds random units from the pool until nothing else fits in the budget
+    fn random_fill(&self, pool: &[Unit], mut counts: Vec<u32>, rng: &mut StdRng) -> Vec<u32> {
+        let mut spent = counts_cost(pool, &counts);
+
+        loop {
+            let options: Vec<usize> = (0..pool.len())
+                .filter(|&i| self.budget.allows(spent + pool[i].cost))
+                .collect();
+
+            let Some(&i) = options.choose(rng) else {
+                return counts;
+            };
+
+            counts[i] += 1;
+            spent = spent + pool[i].cost;
+        }
+    }
+
+    /// Removes a random portion of the units, then refills the budget randomly
+    fn mutate(&self, pool: &[Unit], parent: &Composition, rng: &mut StdRng) -> Composition {
+        let mut counts: Vec<u32> = pool
+            .iter()
+            .map(|u| {
+                parent
+                    .units
+                    .iter()
+                    .find(|(b, _)| *b == u.base)
+                    .map(|(_, n)| *n)
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        for count in counts.iter_mut() {
+            if *count > 0 && rng.gen_bool(0.5) {
+                *count -= rng.gen_range(1..=*count);
+            }
+        }
+
+        let counts = self.random_fill(pool, counts, rng);
+        to_composition(pool, &counts)
+    }
+}
+
+fn counts_cost(pool: &[Unit], counts: &[u32]) -> Cost {
+    pool.iter()
+        .zip(counts)
+        .map(|(u, &n)| u.cost * n as i32)
+        .sum()
+}
+
+fn to_composition(pool: &[Unit], counts: &[u32]) -> Composition {
+    Composition {
+        units: pool
+            .iter()
+            .zip(counts)
+            .filter(|(_, &n)| n > 0)
+            .map(|(u, &n)| (u.base, n))
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_form_per_unit() {
+        let terran: Vec<Base> = UnitPool::Faction(Faction::Terran)
+            .units()
+            .iter()
+            .map(|u| u.base)
+            .collect();
+
+        assert!(terran.contains(&Base::SiegeTank));
+        assert!(terran.contains(&Base::VikingAir));
+        for base in UnitPool::ALT_MODES {
+            assert!(!terran.contains(&base), "{base}");
+        }
+    }
+
+    fn search() -> (CounterSearch, Army) {
+        let search = CounterSearch {
+            population: 6,
+            survivors: 2,
+            generations: 3,
+            runs: 2,
+            prune_below: None,
+            ..CounterSearch::new(
+                Budget::new(1000, 400, 20),
+                UnitPool::Units(vec![Base::Zealot, Base::Stalker]),
+                Objective::WinRate,
+            )
+        };
+
+        let mut enemy = Army::default();
+        enemy.add_unit(Unit::MUTALISK, 6);
+
+        (search, enemy)
+    }
+
+    #[test]
+    fn within_budget() {
+        let (search, enemy) = search();
+        let candidates = search.run(&enemy);
+
+        assert!(!candidates.is_empty());
+        for c in &candidates {
+            assert!(search.budget.allows(c.composition.cost()), "{c:?}");
+        }
+    }
+
+    #[test]
+    fn beats_bad_start() {
+        let (search, enemy) = search();
+        let best = search.run(&enemy).remove(0);
+
+        // zealots can't hit mutalisks at all
+        let zealots = Composition {
+            units: vec![(Base::Zealot, 10)],
+        };
+        let seeds: Vec<u64> = (0..search.runs as u64).collect();
+        let bad = search.evaluate(zealots, &enemy, &seeds).unwrap();
+
+        assert!(
+            best.score(search.objective) > bad.score(search.objective),
+            "{best:?} vs {bad:?}"
+        );
+        assert!(best
+            .composition
+            .units
+            .iter()
+            .any(|&(b, _)| b == Base::Stalker));
+    }
+}
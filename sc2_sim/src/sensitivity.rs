@@ -0,0 +1,241 @@
+use crate::{
+    coordinator::{BatchResult, Coordinator, Team},
+    unit::{Base, Unit},
+    *,
+};
+
+/// A unit stat that can be perturbed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tweak {
+    /// `Health::max` of the unit's hull
+    HealthMax,
+    /// `Health::armor` of the unit's hull
+    HealthArmor,
+    ShieldsMax,
+    /// `Weapon::damage` of every weapon
+    WeaponDamage,
+    /// `Weapon::attack_speed` of every weapon. Remember that this is a period, so lower is faster
+    AttackSpeed,
+    /// The upper bound of `Weapon::range` of every weapon
+    Range,
+    /// `Movement::speed`
+    Speed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change {
+    Add(Real),
+    Scale(Real),
+}
+
+impl Change {
+    fn apply(&self, val: &mut Real) {
+        match *self {
+            Change::Add(x) => *val += x,
+            Change::Scale(x) => *val *= x,
+        }
+    }
+}
+
+/// A single stat change to every unit of type `base` on `team`
+#[derive(Debug, Clone, Copy)]
+pub struct Perturbation {
+    pub team: Team,
+    pub base: Base,
+    pub tweak: Tweak,
+    pub change: Change,
+}
+
+impl Perturbation {
+    pub fn new(team: Team, base: Base, tweak: Tweak, change: Change) -> Self {
+        Self {
+            team,
+            base,
+            tweak,
+            change,
+        }
+    }
+
+    pub fn apply(&self, c: &mut Coordinator) {
+        let army = match self.team {
+            Team::Team1 => &mut c.a1,
+            Team::Team2 => &mut c.a2,
+        };
+
+        if let Some(unit) = army.base_units.get_mut(&self.base) {
+            self.apply_to(unit);
+        }
+
+        // picks up any changes to the base unit
+        army.reset();
+    }
+
+    pub fn apply_to(&self, unit: &mut Unit) {
+        match self.tweak {
+            Tweak::HealthMax => self.change.apply(&mut unit.hull.max),
+            Tweak::HealthArmor => self.change.apply(&mut unit.hull.armor),
+            Tweak::ShieldsMax => self.change.apply(&mut unit.shields.max),
+            Tweak::Speed => self.change.apply(&mut unit.movement.speed),
+            Tweak::WeaponDamage | Tweak::AttackSpeed | Tweak::Range => {
+                for weapon in unit.weapons.to_mut() {
+                    match self.tweak {
+                        Tweak::WeaponDamage => self.change.apply(&mut weapon.damage),
+                        Tweak::AttackSpeed => self.change.apply(&mut weapon.attack_speed),
+                        _ => {
+                            let mut end = weapon.range.end();
+                            self.change.apply(&mut end);
+                            weapon.range = RangeInclusive::new(weapon.range.start(), end);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The change in a metric between the baseline scenario and a perturbed scenario
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Delta {
+    pub baseline: f64,
+    pub perturbed: f64,
+    /// Mean of the per-seed differences (perturbed - baseline)
+    pub mean: f64,
+    /// 95% confidence interval of `mean`
+    pub ci: (f64, f64),
+}
+
+impl Delta {
+    fn paired(baseline: &[f64], perturbed: &[f64]) -> Self {
+        let n = baseline.len().min(perturbed.len());
+        if n == 0 {
+            return Self::default();
+        }
+
+        let diffs: Vec<f64> = baseline
+            .iter()
+            .zip(perturbed)
+            .map(|(b, p)| p - b)
+            .collect();
+
+        let mean = diffs.iter().sum::<f64>() / n as f64;
+        let var = if n > 1 {
+            diffs.iter().map(|d| (d - mean) * (d - mean)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+        let margin = 1.96 * (var / n as f64).sqrt();
+
+        Self {
+            baseline: baseline.iter().sum::<f64>() / n as f64,
+            perturbed: perturbed.iter().sum::<f64>() / n as f64,
+            mean,
+            ci: (mean - margin, mean + margin),
+        }
+    }
+
+    /// True if the confidence interval doesn't contain 0
+    pub fn is_significant(&self) -> bool {
+        self.ci.0 > 0.0 || self.ci.1 < 0.0
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Sensitivity {
+    pub perturbation: Perturbation,
+    /// From team 1's perspective
+    pub win_rate: Delta,
+    /// From team 1's perspective
+    pub cost_efficiency: Delta,
+}
+
+/// Runs `runs` simulations of `scenario` as a baseline, then the same number of simulations with
+/// each perturbation applied (one at a time). Every batch uses the same seeds, so the differences
+/// between runs come from the perturbation rather than RNG.
+pub fn analyze(
+    scenario: &Coordinator,
+    perturbations: &[Perturbation],
+    runs: usize,
+) -> Vec<Sensitivity> {
+    let seeds: Vec<u64> = (0..runs as u64)
+        .map(|i| scenario.seed.wrapping_add(i))
+        .collect();
+
+    let mut c = scenario.clone();
+    let baseline = c.simulate_batch(&seeds);
+
+    perturbations
+        .iter()
+        .map(|p| {
+            let mut c = scenario.clone();
+            p.apply(&mut c);
+            let perturbed = c.simulate_batch(&seeds);
+
+            Sensitivity {
+                perturbation: *p,
+                win_rate: Delta::paired(&wins(&baseline), &wins(&perturbed)),
+                cost_efficiency: Delta::paired(&efficiency(&baseline), &efficiency(&perturbed)),
+            }
+        })
+        .collect()
+}
+
+fn wins(batch: &BatchResult) -> Vec<f64> {
+    batch
+        .runs
+        .iter()
+        .map(|r| if r.winner == Some(Team::Team1) { 1.0 } else { 0.0 })
+        .collect()
+}
+
+fn efficiency(batch: &BatchResult) -> Vec<f64> {
+    batch
+        .runs
+        .iter()
+        .map(|r| r.cost_efficiency(Team::Team1))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scenario() -> Coordinator {
+        let mut c = Coordinator::default();
+        c.a1.add_unit(Unit::MARINE, 8);
+        c.a2.add_unit(Unit::MARINE, 10);
+        c
+    }
+
+    #[test]
+    fn no_change() {
+        let p = Perturbation::new(
+            Team::Team1,
+            Base::Marine,
+            Tweak::HealthMax,
+            Change::Add(real!(0)),
+        );
+        let result = analyze(&scenario(), &[p], 8).remove(0);
+
+        // every seed plays out exactly the same as the baseline
+        for delta in [result.win_rate, result.cost_efficiency] {
+            assert_eq!(delta.mean, 0.0);
+            assert_eq!(delta.ci, (0.0, 0.0));
+            assert!(!delta.is_significant());
+        }
+    }
+
+    #[test]
+    fn health_buff() {
+        let p = Perturbation::new(
+            Team::Team1,
+            Base::Marine,
+            Tweak::HealthMax,
+            Change::Scale(real!(3)),
+        );
+        let result = analyze(&scenario(), &[p], 8).remove(0);
+
+        assert!(result.win_rate.mean > 0.0);
+        assert!(result.win_rate.is_significant(), "{:?}", result.win_rate);
+        assert!(result.cost_efficiency.mean > 0.0);
+    }
+}
@@ -0,0 +1,339 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Cell {
+    #[default]
+    Pathable,
+    /// Cliffs, rocks, water, etc. Ground units can't enter these cells
+    Unpathable,
+    /// Pathable, but ground units below can't see (and therefore can't target) units standing here
+    HighGround,
+}
+
+/// An optional arena for fights to take place in. Without one, fights happen on an infinite flat
+/// plane.
+///
+/// The arena is a grid of square cells. Ground units path around unpathable cells and can't leave
+/// the grid, air units (and the colossus) ignore it entirely.
+#[derive(Debug, Clone)]
+pub struct Terrain {
+    pub width: usize,
+    pub height: usize,
+    /// The length of a cell's side, in game units
+    pub cell_size: Real,
+    /// World position of the bottom left corner of cell (0, 0)
+    pub origin: Pos,
+    cells: Vec<Cell>,
+}
+
+impl Terrain {
+    // A* costs for orthogonal and diagonal steps, scaled so they can stay integers
+    const STRAIGHT_COST: u32 = 10;
+    const DIAGONAL_COST: u32 = 14;
+
+    /// Creates a fully pathable arena
+    pub fn new(width: usize, height: usize, cell_size: Real, origin: Pos) -> Self {
+        Self {
+            width,
+            height,
+            cell_size,
+            origin,
+            cells: vec![Cell::Pathable; width * height],
+        }
+    }
+
+    /// Builds an arena from rows of text, where `.` is pathable, `#` is unpathable and `^` is high
+    /// ground. The first row is the top of the arena (highest y). Rows shorter than the longest row
+    /// are padded with unpathable cells.
+    ///
+    /// ```ignore
+    /// // a ramp leading up to high ground, with a 2 cell wide choke
+    /// let terrain = Terrain::from_rows(
+    ///     &[
+    ///         "^^^^^^^^",
+    ///         "###^^###",
+    ///         "###..###",
+    ///         "........",
+    ///     ],
+    ///     real!(1),
+    ///     Pos::default(),
+    /// );
+    /// ```
+    pub fn from_rows(rows: &[&str], cell_size: Real, origin: Pos) -> Self {
+        let width = rows.iter().map(|r| r.chars().count()).max().unwrap_or(0);
+        let mut terrain = Self::new(width, rows.len(), cell_size, origin);
+
+        for (i, row) in rows.iter().enumerate() {
+            let y = rows.len() - 1 - i;
+            for x in 0..width {
+                let cell = match row.chars().nth(x) {
+                    Some('.') => Cell::Pathable,
+                    Some('^') => Cell::HighGround,
+                    _ => Cell::Unpathable,
+                };
+                terrain.set(x, y, cell);
+            }
+        }
+
+        terrain
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Cell {
+        self.cells[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, cell: Cell) {
+        self.cells[y * self.width + x] = cell;
+    }
+
+    /// Sets every cell in the inclusive rectangle between the two corners
+    pub fn fill(&mut self, corner_1: (usize, usize), corner_2: (usize, usize), cell: Cell) {
+        if self.width == 0 || self.height == 0 {
+            return;
+        }
+
+        for y in corner_1.1.min(corner_2.1)..=corner_1.1.max(corner_2.1).min(self.height - 1) {
+            for x in corner_1.0.min(corner_2.0)..=corner_1.0.max(corner_2.0).min(self.width - 1) {
+                self.set(x, y, cell);
+            }
+        }
+    }
+
+    /// The cell containing `pos`, or `None` if it's outside the arena
+    pub fn cell_of(&self, pos: Pos) -> Option<(usize, usize)> {
+        let x = ((pos.x - self.origin.x) / self.cell_size).floor();
+        let y = ((pos.y - self.origin.y) / self.cell_size).floor();
+
+        (x >= 0 && y >= 0 && x < real!(self.width) && y < real!(self.height))
+            .then(|| (x.as_usize(), y.as_usize()))
+    }
+
+    /// The world position of the center of a cell
+    pub fn center(&self, cell: (usize, usize)) -> Pos {
+        Pos {
+            x: self.origin.x + self.cell_size * real!(cell.0) + self.cell_size / 2,
+            y: self.origin.y + self.cell_size * real!(cell.1) + self.cell_size / 2,
+        }
+    }
+
+    /// The cell at `pos`. Anything outside of the arena is unpathable
+    pub fn cell_at(&self, pos: Pos) -> Cell {
+        self.cell_of(pos)
+            .map_or(Cell::Unpathable, |(x, y)| self.get(x, y))
+    }
+
+    pub fn is_pathable(&self, pos: Pos) -> bool {
+        self.cell_at(pos) != Cell::Unpathable
+    }
+
+    pub fn is_high_ground(&self, pos: Pos) -> bool {
+        self.cell_at(pos) == Cell::HighGround
+    }
+
+    /// True if a ground unit at `from` can see a unit at `to`. Units on low ground can't see up onto
+    /// high ground.
+    pub fn can_see(&self, from: Pos, to: Pos) -> bool {
+        !self.is_high_ground(to) || self.is_high_ground(from)
+    }
+
+    /// True if a ground unit can walk in a straight line between the two points
+    pub fn line_walkable(&self, from: Pos, to: Pos) -> bool {
+        let dist = from.dist(to);
+        let spacing: Real = self.cell_size / 2;
+        let samples = (dist / spacing).ceil().as_i32().max(1);
+
+        (0..=samples).all(|i| {
+            let t = real!(i) / samples;
+            self.is_pathable(Pos {
+                x: from.x + (to.x - from.x) * t,
+                y: from.y + (to.y - from.y) * t,
+            })
+        })
+    }
+
+    /// A* path for ground units from `from` to `to`. Returns the waypoints after `from`, ending with
+    /// `to`, or `None` if there is no path. Corners aren't cut, and waypoints that can be skipped by
+    /// walking in a straight line are removed.
+    pub fn path(&self, from: Pos, to: Pos) -> Option<Vec<Pos>> {
+        let start = self.cell_of(from)?;
+        let goal = self.cell_of(to)?;
+        if self.get(goal.0, goal.1) == Cell::Unpathable {
+            return None;
+        }
+
+        let idx = |(x, y): (usize, usize)| y * self.width + x;
+        let heuristic = |(x, y): (usize, usize)| {
+            let dx = x.abs_diff(goal.0) as u32;
+            let dy = y.abs_diff(goal.1) as u32;
+            Self::STRAIGHT_COST * dx.max(dy) + (Self::DIAGONAL_COST - Self::STRAIGHT_COST) * dx.min(dy)
+        };
+
+        let mut cost = vec![u32::MAX; self.cells.len()];
+        let mut came_from = vec![usize::MAX; self.cells.len()];
+        let mut open = BinaryHeap::new();
+
+        cost[idx(start)] = 0;
+        open.push(Reverse((heuristic(start), idx(start))));
+
+        while let Some(Reverse((_, current))) = open.pop() {
+            if current == idx(goal) {
+                break;
+            }
+
+            let (cx, cy) = (current % self.width, current / self.width);
+            for (dx, dy) in [
+                (-1, 0),
+                (1, 0),
+                (0, -1),
+                (0, 1),
+                (-1, -1),
+                (-1, 1),
+                (1, -1),
+                (1, 1),
+            ] {
+                let Some(next) = self.offset((cx, cy), dx, dy) else {
+                    continue;
+                };
+                if !self.pathable_cell(next) {
+                    continue;
+                }
+
+                let diagonal = dx != 0 && dy != 0;
+                // don't cut corners
+                if diagonal
+                    && (self.offset((cx, cy), dx, 0).is_none_or(|c| !self.pathable_cell(c))
+                        || self.offset((cx, cy), 0, dy).is_none_or(|c| !self.pathable_cell(c)))
+                {
+                    continue;
+                }
+
+                let step = if diagonal {
+                    Self::DIAGONAL_COST
+                } else {
+                    Self::STRAIGHT_COST
+                };
+                let new_cost = cost[current] + step;
+                if new_cost < cost[idx(next)] {
+                    cost[idx(next)] = new_cost;
+                    came_from[idx(next)] = current;
+                    open.push(Reverse((new_cost + heuristic(next), idx(next))));
+                }
+            }
+        }
+
+        if cost[idx(goal)] == u32::MAX {
+            return None;
+        }
+
+        let mut cells = Vec::new();
+        let mut current = came_from[idx(goal)];
+        while current != idx(start) && current != usize::MAX {
+            cells.push(self.center((current % self.width, current / self.width)));
+            current = came_from[current];
+        }
+        cells.reverse();
+        cells.push(to);
+
+        // string pulling: skip every waypoint that can be reached directly
+        let mut path = Vec::new();
+        let mut anchor = from;
+        let mut i = 0;
+        while i < cells.len() {
+            let mut furthest = i;
+            while furthest + 1 < cells.len() && self.line_walkable(anchor, cells[furthest + 1]) {
+                furthest += 1;
+            }
+            anchor = cells[furthest];
+            path.push(anchor);
+            i = furthest + 1;
+        }
+
+        Some(path)
+    }
+
+    fn offset(&self, (x, y): (usize, usize), dx: isize, dy: isize) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        (x < self.width && y < self.height).then_some((x, y))
+    }
+
+    fn pathable_cell(&self, (x, y): (usize, usize)) -> bool {
+        self.get(x, y) != Cell::Unpathable
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pos(x: f64, y: f64) -> Pos {
+        Pos {
+            x: real!(x),
+            y: real!(y),
+        }
+    }
+
+    #[test]
+    fn straight_path() {
+        let terrain = Terrain::new(8, 8, const_real!(1), Pos::default());
+        let path = terrain.path(pos(0.5, 0.5), pos(6.5, 4.5)).unwrap();
+
+        // nothing in the way, so string pulling leaves only the destination
+        assert_eq!(path.len(), 1);
+        assert_eq!(path[0].x, 6.5);
+        assert_eq!(path[0].y, 4.5);
+    }
+
+    #[test]
+    fn path_around_wall() {
+        let terrain = Terrain::from_rows(
+            &["........", "...#....", "...#....", "...#....", "...#...."],
+            const_real!(1),
+            Pos::default(),
+        );
+        let (from, to) = (pos(1.5, 1.5), pos(5.5, 1.5));
+        assert!(!terrain.line_walkable(from, to));
+
+        let path = terrain.path(from, to).unwrap();
+        assert!(path.len() > 1);
+        let last = path.last().unwrap();
+        assert_eq!((last.x, last.y), (to.x, to.y));
+
+        // every leg of the path can be walked, and it goes over the top of the wall
+        let mut prev = from;
+        for &p in &path {
+            assert!(terrain.line_walkable(prev, p));
+            prev = p;
+        }
+        assert!(path.iter().any(|p| p.y >= 4));
+    }
+
+    #[test]
+    fn no_path() {
+        let terrain =
+            Terrain::from_rows(&["..#..", "..#..", "..#.."], const_real!(1), Pos::default());
+        assert!(terrain.path(pos(0.5, 0.5), pos(4.5, 0.5)).is_none());
+        // unpathable or outside the arena
+        assert!(terrain.path(pos(0.5, 0.5), pos(2.5, 0.5)).is_none());
+        assert!(terrain.path(pos(0.5, 0.5), pos(9.5, 0.5)).is_none());
+    }
+
+    #[test]
+    fn fill() {
+        let mut terrain = Terrain::new(4, 4, const_real!(1), Pos::default());
+        terrain.fill((2, 3), (1, 1), Cell::Unpathable);
+        assert_eq!(terrain.get(1, 1), Cell::Unpathable);
+        assert_eq!(terrain.get(2, 3), Cell::Unpathable);
+        assert_eq!(terrain.get(0, 0), Cell::Pathable);
+        assert_eq!(terrain.get(3, 3), Cell::Pathable);
+
+        // corners outside the grid are clamped
+        terrain.fill((0, 0), (10, 0), Cell::HighGround);
+        assert_eq!(terrain.get(3, 0), Cell::HighGround);
+
+        let mut empty = Terrain::new(0, 0, const_real!(1), Pos::default());
+        empty.fill((0, 0), (1, 1), Cell::Unpathable);
+    }
+}
@@ -4,11 +4,15 @@ mod weapon_data;
 pub use weapon_data::*;
 mod upgrades;
 pub use upgrades::*;
+mod upgrade_data;
+mod abilities;
+pub use abilities::*;
+mod abil_data;
 mod weapon;
 pub use weapon::*;
 mod builder;
 
-use std::{default, ops::Range};
+use std::{borrow::Cow, default, ops::Range};
 use strum::{Display, EnumString, IntoStaticStr};
 
 use crate::*;
@@ -24,6 +28,7 @@ use crate::*;
     IntoStaticStr,
     Display,
     strum::VariantNames,
+    strum::EnumIter,
     PartialOrd,
     Ord,
 )]
@@ -105,6 +110,14 @@ pub enum Base {
     BroodLord,
     Broodling,
     Changeling,
+
+    PhotonCannon,
+    ShieldBattery,
+    Bunker,
+    MissileTurret,
+    PlanetaryFortress,
+    SpineCrawler,
+    SporeCrawler,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, IntoStaticStr, Display)]
@@ -151,7 +164,9 @@ impl Collision {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+/// Units prefer to attack enemies with the highest threat level they can hit. Comes from each unit's
+/// `AttackTargetPriority`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ThreatLevel {
     None = 0,
     Cocoon = 10,
@@ -323,6 +338,21 @@ impl std::ops::Mul<i32> for Cost {
     }
 }
 
+/// Where a unit comes from, as generated from the ability that makes it
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Production {
+    /// The unit or structure with the train/build/morph ability, e.g. "Gateway" or "Zergling".
+    /// Empty for units that aren't produced (e.g. larva, broodlings)
+    pub producer: &'static str,
+    /// Time spent in the producer. Same as the unit's `cost.build_time`
+    pub train_time: Real,
+    /// The warp gate's cooldown after warping the unit in, for units that can be warped in
+    pub warp_cooldown: Option<Real>,
+    pub morph_from: Option<Base>,
+    /// What morphing costs on top of the `morph_from` unit, e.g. 25/25 for a baneling
+    pub morph_cost: Cost,
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Health {
     pub max: Real,
@@ -340,6 +370,8 @@ pub struct Movement {
     pub decel: Real,
     pub turn_rate: Real,
     pub lateral_accel: Real,
+    /// Speed multiplier while on creep. Only Zerg units have one above 1
+    pub creep_speed: Real,
 }
 
 #[derive(Debug, Clone)]
@@ -353,13 +385,23 @@ pub struct Unit {
     pub shields: Health,
     pub movement: Movement,
     pub cost: Cost,
+    pub production: Production,
     pub size: Real,
     pub cargo_size: Real,
+    /// Total cargo space, if the unit is a transport (or a bunker)
+    pub cargo_capacity: Real,
+    /// The largest `cargo_size` the transport can load
+    pub max_cargo_size: Real,
     pub sight: Real,
-    pub weapons: [Option<Weapon>; 2],
+    /// In the order the engine prefers them, see `select_weapon`. Only one weapon fires per attack:
+    /// in the current data every real weapon links its cooldown to the unit's other weapons, and
+    /// the battlecruiser's ATA battery shares the ATS battery's cooldown, so no unit has weapons
+    /// that fire simultaneously
+    pub weapons: Cow<'static, [Weapon]>,
     pub push_priority: Real,
     pub energy_start: Real,
     pub energy_max: Real,
+    pub target_priority: ThreatLevel,
 }
 
 impl Unit {
@@ -427,10 +469,87 @@ impl Unit {
 
 
 
+    /// The weapon the unit approaches `target` with (see `select_weapon`), assuming it's visible
+    /// and targetable. See `Army::filter_props` for the version that accounts for the target's
+    /// state
     pub fn try_get_weapon(&self, target: &Unit) -> Option<&Weapon> {
-        self.weapons
-            .iter()
-            .find_map(|w| w.as_ref().filter(|x| x.can_hit(target.collision)))
+        let props = target.filter_props() | Filter::Visible as u32;
+        self.select_weapon(props, |_| false).map(|i| &self.weapons[i])
+    }
+
+    /// The first weapon whose target filters allow a target with the given `Filter` bitset
+    pub fn weapon_for(&self, props: u32) -> Option<&Weapon> {
+        self.weapons.iter().find(|w| w.can_hit(props))
+    }
+
+    /// The index of the weapon the unit attacks a target with, following the engine's rules:
+    /// weapons whose filters don't allow the target are skipped, and the earliest weapon that is
+    /// `in_range` wins. If none are in range, the result is the weapon to approach the target
+    /// with, which is the longest ranged one (e.g. the hydralisk's spines, not its melee attack).
+    ///
+    /// The unit attacks with this one weapon only, see `weapons`
+    pub fn select_weapon(&self, props: u32, in_range: impl Fn(&Weapon) -> bool) -> Option<usize> {
+        let usable = || {
+            self.weapons
+                .iter()
+                .enumerate()
+                .filter(|(_, w)| w.can_hit(props))
+        };
+        if let Some((i, _)) = usable().find(|(_, w)| in_range(w)) {
+            return Some(i);
+        }
+
+        usable()
+            .reduce(|a, b| if b.1.range.end() > a.1.range.end() { b } else { a })
+            .map(|(i, _)| i)
+    }
+
+    /// The weapon the unit would attack a target with `props` from `attacker` to `target`, see
+    /// `select_weapon`
+    pub fn weapon_against(
+        &self,
+        props: u32,
+        attacker: CollCircle,
+        target: CollCircle,
+    ) -> Option<&Weapon> {
+        self.select_weapon(props, |w| w.in_range(attacker, target))
+            .map(|i| &self.weapons[i])
+    }
+
+    pub fn has_weapons(&self) -> bool {
+        !self.weapons.is_empty()
+    }
+
+    /// The unit's plane and attributes as a bitset of `Filter`s. Colossi count as both ground and
+    /// air
+    pub fn filter_props(&self) -> u32 {
+        let mut props = match self.collision {
+            Collision::None => 0,
+            Collision::Ground => Filter::Ground as u32,
+            Collision::Flying => Filter::Air as u32,
+            Collision::Both => Filter::Ground as u32 | Filter::Air as u32,
+        };
+        for (flag, filter) in Filter::ATTRIBUTES {
+            if self.has_flag(flag) {
+                props |= filter as u32;
+            }
+        }
+
+        props
+    }
+
+    /// The radius in which the unit reveals cloaked and burrowed units, or 0 if it isn't a detector.
+    /// Detection comes from behaviors, which aren't parsed from the game data
+    pub fn detection(&self) -> Real {
+        match self.base {
+            Base::Observer
+            | Base::Overseer
+            | Base::Raven
+            | Base::PhotonCannon
+            | Base::MissileTurret
+            | Base::SporeCrawler => const_real!(11),
+            _ => const_real!(0),
+        }
     }
 
     pub fn has_flag(&self, flag: Flag) -> bool {
@@ -438,8 +557,8 @@ impl Unit {
     }
 
     pub fn ideal_dps(&self, weapon_idx: usize, with_bonus: bool) -> Range<Real> {
-        self.weapons[weapon_idx]
-            .as_ref()
+        self.weapons
+            .get(weapon_idx)
             .map(|x| x.dps(with_bonus))
             .unwrap_or(real!(0)..real!(0))
     }
@@ -0,0 +1,727 @@
+
+use crate::{const_real, duration, unit::*};
+
+impl Ability {
+    pub const LURKERHOLDFIRE: Ability = Ability {
+        name: "LurkerHoldFire",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const LURKERREMOVEHOLDFIRE: Ability = Ability {
+        name: "LurkerRemoveHoldFire",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const RAVAGERCORROSIVEBILE: Ability = Ability {
+        name: "RavagerCorrosiveBile",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(10),
+        range: const_real!(9),
+    };
+
+    pub const MULEREPAIR: Ability = Ability {
+        name: "MULERepair",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const SPRAYPROTOSS: Ability = Ability {
+        name: "SprayProtoss",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(1),
+    };
+
+    pub const PSISTORM: Ability = Ability {
+        name: "PsiStorm",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(2),
+        range: const_real!(9),
+    };
+
+    pub const FEEDBACK: Ability = Ability {
+        name: "Feedback",
+        energy_cost: const_real!(50),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(10),
+    };
+
+    pub const DARKTEMPLARBLINK: Ability = Ability {
+        name: "DarkTemplarBlink",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(20),
+        range: const_real!(500),
+    };
+
+    pub const GRAVITONBEAM: Ability = Ability {
+        name: "GravitonBeam",
+        energy_cost: const_real!(50),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(4),
+    };
+
+    pub const VOIDRAYSWARMDAMAGEBOOST: Ability = Ability {
+        name: "VoidRaySwarmDamageBoost",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(60),
+        range: const_real!(0),
+    };
+
+    pub const VOIDRAYSWARMDAMAGEBOOSTCANCEL: Ability = Ability {
+        name: "VoidRaySwarmDamageBoostCancel",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const BLINK: Ability = Ability {
+        name: "Blink",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(10),
+        range: const_real!(500),
+    };
+
+    pub const TEMPORALFIELD: Ability = Ability {
+        name: "TemporalField",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(84),
+        range: const_real!(9),
+    };
+
+    pub const MASSRECALL: Ability = Ability {
+        name: "MassRecall",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(125),
+        range: const_real!(500),
+    };
+
+    pub const MOTHERSHIPCLOAK: Ability = Ability {
+        name: "MothershipCloak",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(70),
+        range: const_real!(0),
+    };
+
+    pub const REPAIR: Ability = Ability {
+        name: "Repair",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const SPRAYTERRAN: Ability = Ability {
+        name: "SprayTerran",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(1),
+    };
+
+    pub const STIMPACK: Ability = Ability {
+        name: "Stimpack",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(10),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const KD8CHARGE: Ability = Ability {
+        name: "KD8Charge",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(20),
+        range: const_real!(5),
+    };
+
+    pub const CHANNELSNIPE: Ability = Ability {
+        name: "ChannelSnipe",
+        energy_cost: const_real!(50),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(10),
+    };
+
+    pub const TACNUKESTRIKE: Ability = Ability {
+        name: "TacNukeStrike",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(12),
+    };
+
+    pub const GHOSTHOLDFIRE: Ability = Ability {
+        name: "GhostHoldFire",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const EMP: Ability = Ability {
+        name: "EMP",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(10),
+    };
+
+    pub const GHOSTWEAPONSFREE: Ability = Ability {
+        name: "GhostWeaponsFree",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const MEDIVACHEAL: Ability = Ability {
+        name: "MedivacHeal",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(4),
+    };
+
+    pub const MEDIVACSPEEDBOOST: Ability = Ability {
+        name: "MedivacSpeedBoost",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(20),
+        range: const_real!(0),
+    };
+
+    pub const YAMATO: Ability = Ability {
+        name: "Yamato",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(100),
+        range: const_real!(10),
+    };
+
+    pub const HYPERJUMP: Ability = Ability {
+        name: "Hyperjump",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(100),
+        range: const_real!(500),
+    };
+
+    pub const RAVENSCRAMBLERMISSILE: Ability = Ability {
+        name: "RavenScramblerMissile",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(9),
+    };
+
+    pub const RAVENSHREDDERMISSILE: Ability = Ability {
+        name: "RavenShredderMissile",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(10),
+    };
+
+    pub const BUILDAUTOTURRET: Ability = Ability {
+        name: "BuildAutoTurret",
+        energy_cost: const_real!(50),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(2),
+    };
+
+    pub const SPRAYZERG: Ability = Ability {
+        name: "SprayZerg",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(1),
+    };
+
+    pub const SPAWNCHANGELING: Ability = Ability {
+        name: "SpawnChangeling",
+        energy_cost: const_real!(50),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const CONTAMINATE: Ability = Ability {
+        name: "Contaminate",
+        energy_cost: const_real!(125),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(3),
+    };
+
+    pub const ULTRALISKWEAPONCOOLDOWN: Ability = Ability {
+        name: "UltraliskWeaponCooldown",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(1),
+        range: const_real!(0),
+    };
+
+    pub const SAPSTRUCTURE: Ability = Ability {
+        name: "SapStructure",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0.25),
+    };
+
+    pub const EXPLODE: Ability = Ability {
+        name: "Explode",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const NEURALPARASITE: Ability = Ability {
+        name: "NeuralParasite",
+        energy_cost: const_real!(100),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(8),
+    };
+
+    pub const FUNGALGROWTH: Ability = Ability {
+        name: "FungalGrowth",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(10),
+    };
+
+    pub const INFESTORENSNARE: Ability = Ability {
+        name: "InfestorEnsnare",
+        energy_cost: const_real!(50),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(8),
+    };
+
+    pub const INFESTEDTERRANS: Ability = Ability {
+        name: "InfestedTerrans",
+        energy_cost: const_real!(50),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(8),
+    };
+
+    pub const AMORPHOUSARMORCLOUD: Ability = Ability {
+        name: "AmorphousArmorcloud",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(9),
+    };
+
+    pub const STIMPACKMARAUDER: Ability = Ability {
+        name: "StimpackMarauder",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(20),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const CAUSTICSPRAY: Ability = Ability {
+        name: "CausticSpray",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(45),
+        range: const_real!(6),
+    };
+
+    pub const FORCEFIELD: Ability = Ability {
+        name: "ForceField",
+        energy_cost: const_real!(50),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(9),
+    };
+
+    pub const GUARDIANSHIELD: Ability = Ability {
+        name: "GuardianShield",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(18),
+        range: const_real!(0),
+    };
+
+    pub const HALLUCINATIONARCHON: Ability = Ability {
+        name: "HallucinationArchon",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const HALLUCINATIONCOLOSSUS: Ability = Ability {
+        name: "HallucinationColossus",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const HALLUCINATIONHIGHTEMPLAR: Ability = Ability {
+        name: "HallucinationHighTemplar",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const HALLUCINATIONIMMORTAL: Ability = Ability {
+        name: "HallucinationImmortal",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const HALLUCINATIONPHOENIX: Ability = Ability {
+        name: "HallucinationPhoenix",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const HALLUCINATIONPROBE: Ability = Ability {
+        name: "HallucinationProbe",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const HALLUCINATIONSTALKER: Ability = Ability {
+        name: "HallucinationStalker",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const HALLUCINATIONVOIDRAY: Ability = Ability {
+        name: "HallucinationVoidRay",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const HALLUCINATIONWARPPRISM: Ability = Ability {
+        name: "HallucinationWarpPrism",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const HALLUCINATIONZEALOT: Ability = Ability {
+        name: "HallucinationZealot",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const HALLUCINATIONORACLE: Ability = Ability {
+        name: "HallucinationOracle",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const HALLUCINATIONDISRUPTOR: Ability = Ability {
+        name: "HallucinationDisruptor",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const HALLUCINATIONADEPT: Ability = Ability {
+        name: "HallucinationAdept",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const SPAWNLARVA: Ability = Ability {
+        name: "SpawnLarva",
+        energy_cost: const_real!(25),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0.1),
+    };
+
+    pub const TRANSFUSION: Ability = Ability {
+        name: "Transfusion",
+        energy_cost: const_real!(50),
+        life_cost: const_real!(0),
+        cooldown: duration!(1),
+        range: const_real!(7),
+    };
+
+    pub const ORACLEREVELATION: Ability = Ability {
+        name: "OracleRevelation",
+        energy_cost: const_real!(25),
+        life_cost: const_real!(0),
+        cooldown: duration!(14),
+        range: const_real!(12),
+    };
+
+    pub const SPAWNLOCUSTSTARGETED: Ability = Ability {
+        name: "SpawnLocustsTargeted",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(60),
+        range: const_real!(500),
+    };
+
+    pub const LIGHTNINGBOMB: Ability = Ability {
+        name: "LightningBomb",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(90),
+        range: const_real!(9),
+    };
+
+    pub const VIPERCONSUMESTRUCTURE: Ability = Ability {
+        name: "ViperConsumeStructure",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(1),
+        range: const_real!(7),
+    };
+
+    pub const BLINDINGCLOUD: Ability = Ability {
+        name: "BlindingCloud",
+        energy_cost: const_real!(100),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(10),
+    };
+
+    pub const YOINK: Ability = Ability {
+        name: "Yoink",
+        energy_cost: const_real!(75),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(9),
+    };
+
+    pub const PARASITICBOMB: Ability = Ability {
+        name: "ParasiticBomb",
+        energy_cost: const_real!(125),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(8),
+    };
+
+    pub const WIDOWMINEATTACK: Ability = Ability {
+        name: "WidowMineAttack",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(40),
+        range: const_real!(5),
+    };
+
+    pub const LOCKONCANCEL: Ability = Ability {
+        name: "LockOnCancel",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const LOCKON: Ability = Ability {
+        name: "LockOn",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(4),
+        range: const_real!(6),
+    };
+
+    pub const LOCUSTMPFLYINGSWOOP: Ability = Ability {
+        name: "LocustMPFlyingSwoop",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(6),
+    };
+
+    pub const LOCUSTMPFLYINGSWOOPATTACK: Ability = Ability {
+        name: "LocustMPFlyingSwoopAttack",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(6),
+    };
+
+    pub const PURIFICATIONNOVATARGETED: Ability = Ability {
+        name: "PurificationNovaTargeted",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(30),
+        range: const_real!(500),
+    };
+
+    pub const ADEPTPHASESHIFT: Ability = Ability {
+        name: "AdeptPhaseShift",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(16),
+        range: const_real!(500),
+    };
+
+    pub const ADEPTPHASESHIFTCANCEL: Ability = Ability {
+        name: "AdeptPhaseShiftCancel",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const LIBERATORAGTARGET: Ability = Ability {
+        name: "LiberatorAGTarget",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(5),
+    };
+
+    pub const LIBERATORAATARGET: Ability = Ability {
+        name: "LiberatorAATarget",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const SHIELDBATTERYRECHARGEEX5: Ability = Ability {
+        name: "ShieldBatteryRechargeEx5",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(6),
+    };
+
+    pub const SALVAGEBUNKERREFUND: Ability = Ability {
+        name: "SalvageBunkerRefund",
+        energy_cost: const_real!(0),
+        life_cost: const_real!(0),
+        cooldown: duration!(0),
+        range: const_real!(0),
+    };
+
+    pub const ALL: &[Ability] = &[
+        Self::LURKERHOLDFIRE,
+        Self::LURKERREMOVEHOLDFIRE,
+        Self::RAVAGERCORROSIVEBILE,
+        Self::MULEREPAIR,
+        Self::SPRAYPROTOSS,
+        Self::PSISTORM,
+        Self::FEEDBACK,
+        Self::DARKTEMPLARBLINK,
+        Self::GRAVITONBEAM,
+        Self::VOIDRAYSWARMDAMAGEBOOST,
+        Self::VOIDRAYSWARMDAMAGEBOOSTCANCEL,
+        Self::BLINK,
+        Self::TEMPORALFIELD,
+        Self::MASSRECALL,
+        Self::MOTHERSHIPCLOAK,
+        Self::REPAIR,
+        Self::SPRAYTERRAN,
+        Self::STIMPACK,
+        Self::KD8CHARGE,
+        Self::CHANNELSNIPE,
+        Self::TACNUKESTRIKE,
+        Self::GHOSTHOLDFIRE,
+        Self::EMP,
+        Self::GHOSTWEAPONSFREE,
+        Self::MEDIVACHEAL,
+        Self::MEDIVACSPEEDBOOST,
+        Self::YAMATO,
+        Self::HYPERJUMP,
+        Self::RAVENSCRAMBLERMISSILE,
+        Self::RAVENSHREDDERMISSILE,
+        Self::BUILDAUTOTURRET,
+        Self::SPRAYZERG,
+        Self::SPAWNCHANGELING,
+        Self::CONTAMINATE,
+        Self::ULTRALISKWEAPONCOOLDOWN,
+        Self::SAPSTRUCTURE,
+        Self::EXPLODE,
+        Self::NEURALPARASITE,
+        Self::FUNGALGROWTH,
+        Self::INFESTORENSNARE,
+        Self::INFESTEDTERRANS,
+        Self::AMORPHOUSARMORCLOUD,
+        Self::STIMPACKMARAUDER,
+        Self::CAUSTICSPRAY,
+        Self::FORCEFIELD,
+        Self::GUARDIANSHIELD,
+        Self::HALLUCINATIONARCHON,
+        Self::HALLUCINATIONCOLOSSUS,
+        Self::HALLUCINATIONHIGHTEMPLAR,
+        Self::HALLUCINATIONIMMORTAL,
+        Self::HALLUCINATIONPHOENIX,
+        Self::HALLUCINATIONPROBE,
+        Self::HALLUCINATIONSTALKER,
+        Self::HALLUCINATIONVOIDRAY,
+        Self::HALLUCINATIONWARPPRISM,
+        Self::HALLUCINATIONZEALOT,
+        Self::HALLUCINATIONORACLE,
+        Self::HALLUCINATIONDISRUPTOR,
+        Self::HALLUCINATIONADEPT,
+        Self::SPAWNLARVA,
+        Self::TRANSFUSION,
+        Self::ORACLEREVELATION,
+        Self::SPAWNLOCUSTSTARGETED,
+        Self::LIGHTNINGBOMB,
+        Self::VIPERCONSUMESTRUCTURE,
+        Self::BLINDINGCLOUD,
+        Self::YOINK,
+        Self::PARASITICBOMB,
+        Self::WIDOWMINEATTACK,
+        Self::LOCKONCANCEL,
+        Self::LOCKON,
+        Self::LOCUSTMPFLYINGSWOOP,
+        Self::LOCUSTMPFLYINGSWOOPATTACK,
+        Self::PURIFICATIONNOVATARGETED,
+        Self::ADEPTPHASESHIFT,
+        Self::ADEPTPHASESHIFTCANCEL,
+        Self::LIBERATORAGTARGET,
+        Self::LIBERATORAATARGET,
+        Self::SHIELDBATTERYRECHARGEEX5,
+        Self::SALVAGEBUNKERREFUND,
+    ];
+}
@@ -0,0 +1,35 @@
+use crate::Real;
+
+/// A spell's costs and range, as generated from the ability catalog
+#[derive(Debug, Clone, Copy)]
+pub struct Ability {
+    /// The catalog id, e.g. "GuardianShield"
+    pub name: &'static str,
+    pub energy_cost: Real,
+    pub life_cost: Real,
+    pub cooldown: Real,
+    /// 0 for abilities without a target
+    pub range: Real,
+}
+
+impl Ability {
+    pub fn by_name(name: &str) -> Option<&'static Ability> {
+        Self::ALL.iter().find(|x| x.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn costs() {
+        assert_eq!(Ability::TRANSFUSION.energy_cost, 50);
+        assert_eq!(Ability::TRANSFUSION.range, 7);
+        assert_eq!(Ability::GUARDIANSHIELD.energy_cost, 75);
+
+        let stim = Ability::by_name("StimpackMarauder").unwrap();
+        assert_eq!(stim.life_cost, 20);
+        assert_eq!(stim.energy_cost, 0);
+    }
+}
@@ -38,47 +38,41 @@ impl Unit {
     builder_fn! {collision, Collision}
     builder_fn! {flags, Flags}
     builder_fn! {hull, Health}
-    pub const fn with_weapon(mut self, weapon: Option<Weapon>, idx: usize) -> Self {
-        self.weapons[idx] = weapon;
+    /// Replaces the weapon at `idx`, or removes it if `weapon` is `None`. Indices past the end
+    /// add the weapon after the unit's existing ones
+    pub fn with_weapon(mut self, weapon: Option<Weapon>, idx: usize) -> Self {
+        let weapons = self.weapons.to_mut();
+        match weapon {
+            Some(w) if idx < weapons.len() => weapons[idx] = w,
+            Some(w) => weapons.push(w),
+            None if idx < weapons.len() => {
+                weapons.remove(idx);
+            }
+            None => (),
+        }
         self
     }
     builder_fn! {cost, build_time, Real}
     builder_fn! {shields, Health}
 
-    // rust please...
-    const ZEALOT_GW: Real = duration!(38.0);
-    const SENTRY_BOTH: Real = duration!(32.0);
-    const STALKER_GW: Real = duration!(42.0);
-    const TEMPLAR_GW: Real = duration!(55.0);
-
-    // WG at the start because WG and GW is a recipe for disaster
-    const WG_ZEALOT: Real = duration!(28);
-    const WG_STALKER: Real = Self::SENTRY_BOTH;
-    const WG_TEMPLAR: Real = duration!(45.0);
-
+    /// Uses the time spent in the unit's producer (e.g. a gateway rather than a warp gate)
     pub const fn via_gateway(self) -> Self {
-        match self.base {
-            Base::Zealot => self.with_build_time(Self::ZEALOT_GW),
-            Base::Adept | Base::Stalker => self.with_build_time(Self::STALKER_GW),
-            Base::Sentry => self.with_build_time(Self::SENTRY_BOTH),
-            Base::HighTemplar | Base::DarkTemplar => self.with_build_time(Self::TEMPLAR_GW),
-            _ => self,
-        }
+        let t = self.production.train_time;
+        self.with_build_time(t)
     }
 
+    /// Uses the warp gate's cooldown for units that can be warped in
     pub const fn via_warpgate(self) -> Self {
-        match self.base {
-            Base::Adept | Base::Zealot => self.with_build_time(Self::WG_ZEALOT),
-            Base::Stalker | Base::Sentry => self.with_build_time(Self::WG_STALKER),
-            Base::HighTemplar | Base::DarkTemplar => self.with_build_time(Self::WG_TEMPLAR),
-            _ => self,
+        match self.production.warp_cooldown {
+            Some(t) => self.with_build_time(t),
+            None => self,
         }
     }
 
     // TODO
-    const FAST_WARPIN: Real = const_real!(5.0);
+    pub(crate) const FAST_WARPIN: Real = const_real!(5.0);
     // TODO
-    const SLOW_WARPIN: Real = const_real!(16.0);
+    pub(crate) const SLOW_WARPIN: Real = const_real!(16.0);
 
     /// Modifies build time to include warpgate (if applicable) + fast warpin duration
     pub const fn with_fast_warpin(mut self) -> Self {
@@ -99,28 +93,58 @@ impl Unit {
         self.with_build_time(val)
     }
 
-    pub const fn with_concussive_shell(mut self, weapon_idx: usize) -> Self {
-        self.weapons[0] = match self.weapons[0].as_ref() {
-            Some(x) => {
-                let wep = Weapon {
-                    effect: Some(Effect::CONCUSSIVE),
-                    ..*x
-                };
-                Some(wep)
-            },
+    pub fn with_concussive_shell(mut self, weapon_idx: usize) -> Self {
+        match self.weapons.to_mut().get_mut(weapon_idx) {
+            Some(x) => x.effect = Some(Effect::CONCUSSIVE),
             None => panic!("Cannot give concussive shell to unit without weapon"),
-        };
+        }
 
         self
     }
 
-    pub fn with_glaives(mut self) -> Self {
-        self.weapons[0].as_mut().unwrap().attack_speed /= const_real!(1.45);
-        self
+    pub fn with_glaives(self) -> Self {
+        self.with_upgrade(&Upgrade::ADEPTPIERCINGATTACK)
     }
 
-    pub fn with_combat_shields(mut self) -> Self {
-        self.hull.max = const_real!(55);
-        self
+    pub fn with_combat_shields(self) -> Self {
+        self.with_upgrade(&Upgrade::SHIELDWALL)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gateway_times() {
+        // (unit, gateway train time, warp gate cooldown), in normal speed seconds
+        let times = [
+            (Unit::ZEALOT, duration!(38), duration!(28)),
+            (Unit::ADEPT, duration!(42), duration!(28)),
+            (Unit::STALKER, duration!(42), duration!(32)),
+            (Unit::SENTRY, duration!(32), duration!(32)),
+            (Unit::HIGHTEMPLAR, duration!(55), duration!(45)),
+        ];
+
+        for (unit, train, warp) in times {
+            assert_eq!(unit.production.producer, "Gateway");
+            assert_eq!(unit.production.train_time, train, "{}", unit.base);
+            assert_eq!(unit.production.warp_cooldown, Some(warp), "{}", unit.base);
+            assert_eq!(unit.clone().via_gateway().cost.build_time, train);
+            assert_eq!(unit.via_warpgate().cost.build_time, warp);
+        }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn morphs() {
+        let bane = Unit::BANELING.production;
+        assert_eq!(bane.producer, "Zergling");
+        assert_eq!(bane.morph_from, Some(Base::Zergling));
+        assert_eq!(bane.morph_cost.minerals, 25);
+        assert_eq!(bane.morph_cost.gas, 25);
+        assert_eq!(bane.warp_cooldown, None);
+
+        let marine = Unit::MARINE.production;
+        assert_eq!(marine.producer, "Barracks");
+        assert_eq!(marine.morph_from, None);
+    }
+}
@@ -1,3 +1,6 @@
+
+use std::borrow::Cow;
+
 use crate::{const_real, duration, rate, unit::*};
 
 impl Unit {
@@ -24,20 +27,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1.3),
         },
         cost: Cost {
             minerals: const_real!(150),
             gas: const_real!(150),
             supply: const_real!(3),
-            build_time: duration!(0.0),
+            build_time: duration!(25.25),
+        },
+        production: Production {
+            producer: "Hydralisk",
+            train_time: duration!(25.25),
+            warp_cooldown: None,
+            morph_from: Some(Base::Hydralisk),
+            morph_cost: Cost {
+                minerals: const_real!(50),
+                gas: const_real!(100),
+                supply: const_real!(1),
+                build_time: duration!(25.25),
+            },
         },
         size: const_real!(0.9375),
         cargo_size: const_real!(4),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const LURKERBURROWED: Unit = Unit {
@@ -63,20 +82,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(150),
             gas: const_real!(150),
             supply: const_real!(3),
-            build_time: duration!(0.0),
+            build_time: duration!(25.25),
+        },
+        production: Production {
+            producer: "Hydralisk",
+            train_time: duration!(25.25),
+            warp_cooldown: None,
+            morph_from: Some(Base::Hydralisk),
+            morph_cost: Cost {
+                minerals: const_real!(50),
+                gas: const_real!(100),
+                supply: const_real!(1),
+                build_time: duration!(25.25),
+            },
         },
         size: const_real!(0.75),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::LURKERBURROWED_LURKERMP), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::LURKERBURROWED_LURKERMP]),
     };
 
     pub const LURKEREGG: Unit = Unit {
@@ -102,6 +137,7 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(719.4726),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(0),
@@ -109,13 +145,28 @@ impl Unit {
             supply: const_real!(0),
             build_time: duration!(0),
         },
+        production: Production {
+            producer: "",
+            train_time: duration!(0),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
+        },
         size: const_real!(0.5),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(5),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Cocoon,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const RAVAGER: Unit = Unit {
@@ -141,20 +192,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1.3),
         },
         cost: Cost {
             minerals: const_real!(100),
             gas: const_real!(100),
             supply: const_real!(3),
-            build_time: duration!(0.0),
+            build_time: duration!(17),
+        },
+        production: Production {
+            producer: "Roach",
+            train_time: duration!(17),
+            warp_cooldown: None,
+            morph_from: Some(Base::Roach),
+            morph_cost: Cost {
+                minerals: const_real!(25),
+                gas: const_real!(75),
+                supply: const_real!(1),
+                build_time: duration!(17),
+            },
         },
         size: const_real!(0.75),
         cargo_size: const_real!(4),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(9),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::RAVAGER_RAVAGERWEAPON), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::RAVAGER_RAVAGERWEAPON]),
     };
 
     pub const RAVAGERCOCOON: Unit = Unit {
@@ -180,6 +247,7 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(0),
@@ -187,13 +255,28 @@ impl Unit {
             supply: const_real!(0),
             build_time: duration!(0),
         },
+        production: Production {
+            producer: "",
+            train_time: duration!(0),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
+        },
         size: const_real!(0.75),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(5),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Cocoon,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const MULE: Unit = Unit {
@@ -219,20 +302,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(50),
             gas: const_real!(0),
             supply: const_real!(0),
-            build_time: duration!(0.0),
+            build_time: duration!(0),
+        },
+        production: Production {
+            producer: "",
+            train_time: duration!(0),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.375),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(8),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const PROBE: Unit = Unit {
@@ -258,20 +357,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(50),
             gas: const_real!(0),
             supply: const_real!(1),
-            build_time: duration!(0.0),
+            build_time: duration!(17),
+        },
+        production: Production {
+            producer: "Nexus",
+            train_time: duration!(17),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.375),
         cargo_size: const_real!(1),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(8),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::PROBE_PARTICLEBEAM), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::PROBE_PARTICLEBEAM]),
     };
 
     pub const ZEALOT: Unit = Unit {
@@ -297,20 +412,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(100),
             gas: const_real!(0),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(38),
+        },
+        production: Production {
+            producer: "Gateway",
+            train_time: duration!(38),
+            warp_cooldown: Some(duration!(28)),
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.5),
         cargo_size: const_real!(2),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(9),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::ZEALOT_PSIBLADES), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::ZEALOT_PSIBLADES]),
     };
 
     pub const HIGHTEMPLAR: Unit = Unit {
@@ -336,20 +467,36 @@ impl Unit {
             decel: rate!(1000),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(50),
             gas: const_real!(150),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(55),
+        },
+        production: Production {
+            producer: "Gateway",
+            train_time: duration!(55),
+            warp_cooldown: Some(duration!(45)),
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.375),
         cargo_size: const_real!(2),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(50),
         energy_max: const_real!(200),
-        weapons: [Some(Weapon::HIGHTEMPLAR_HIGHTEMPLARWEAPON), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::HIGHTEMPLAR_HIGHTEMPLARWEAPON]),
     };
 
     pub const DARKTEMPLAR: Unit = Unit {
@@ -375,20 +522,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(125),
             gas: const_real!(125),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(55),
+        },
+        production: Production {
+            producer: "Gateway",
+            train_time: duration!(55),
+            warp_cooldown: Some(duration!(45)),
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.375),
         cargo_size: const_real!(2),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(8),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::DARKTEMPLAR_WARPBLADES), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::DARKTEMPLAR_WARPBLADES]),
     };
 
     pub const OBSERVER: Unit = Unit {
@@ -414,20 +577,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(25),
             gas: const_real!(75),
             supply: const_real!(1),
-            build_time: duration!(0.0),
+            build_time: duration!(25),
+        },
+        production: Production {
+            producer: "RoboticsFacility",
+            train_time: duration!(25),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.5),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(11),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const CARRIER: Unit = Unit {
@@ -453,20 +632,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(350),
             gas: const_real!(250),
             supply: const_real!(6),
-            build_time: duration!(0.0),
+            build_time: duration!(90),
+        },
+        production: Production {
+            producer: "Stargate",
+            train_time: duration!(90),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(1.25),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(12),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const INTERCEPTOR: Unit = Unit {
@@ -492,20 +687,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(15),
             gas: const_real!(0),
             supply: const_real!(0),
-            build_time: duration!(0.0),
+            build_time: duration!(0),
+        },
+        production: Production {
+            producer: "",
+            train_time: duration!(0),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.25),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(7),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::INTERCEPTOR_INTERCEPTORBEAM), None],
+        target_priority: ThreatLevel::Low,
+        weapons: Cow::Borrowed(&[Weapon::INTERCEPTOR_INTERCEPTORBEAM]),
     };
 
     pub const ARCHON: Unit = Unit {
@@ -531,20 +742,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(175),
             gas: const_real!(275),
             supply: const_real!(4),
-            build_time: duration!(0.0),
+            build_time: duration!(12),
+        },
+        production: Production {
+            producer: "HighTemplar",
+            train_time: duration!(12),
+            warp_cooldown: None,
+            morph_from: Some(Base::HighTemplar),
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(12),
+            },
         },
         size: const_real!(1),
         cargo_size: const_real!(4),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(9),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::ARCHON_PSIONICSHOCKWAVE), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::ARCHON_PSIONICSHOCKWAVE]),
     };
 
     pub const PHOENIX: Unit = Unit {
@@ -570,20 +797,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(1499.9414),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(150),
             gas: const_real!(100),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(35),
+        },
+        production: Production {
+            producer: "Stargate",
+            train_time: duration!(35),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.75),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(50),
         energy_max: const_real!(200),
-        weapons: [Some(Weapon::PHOENIX_IONCANNONS), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::PHOENIX_IONCANNONS]),
     };
 
     pub const VOIDRAY: Unit = Unit {
@@ -609,20 +852,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(250),
             gas: const_real!(150),
             supply: const_real!(4),
-            build_time: duration!(0.0),
+            build_time: duration!(60.2),
+        },
+        production: Production {
+            producer: "Stargate",
+            train_time: duration!(60.2),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(1),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::VOIDRAY_VOIDRAYSWARM), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::VOIDRAY_VOIDRAYSWARM]),
     };
 
     pub const WARPPRISM: Unit = Unit {
@@ -648,20 +907,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(57),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(250),
             gas: const_real!(0),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(50),
+        },
+        production: Production {
+            producer: "RoboticsFacility",
+            train_time: duration!(50),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.875),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(8),
+        max_cargo_size: const_real!(8),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const STALKER: Unit = Unit {
@@ -687,20 +962,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(125),
             gas: const_real!(50),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(42),
+        },
+        production: Production {
+            producer: "Gateway",
+            train_time: duration!(42),
+            warp_cooldown: Some(duration!(32)),
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.625),
         cargo_size: const_real!(2),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::STALKER_PARTICLEDISRUPTORS), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::STALKER_PARTICLEDISRUPTORS]),
     };
 
     pub const COLOSSUS: Unit = Unit {
@@ -726,20 +1017,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(300),
             gas: const_real!(200),
             supply: const_real!(6),
-            build_time: duration!(0.0),
+            build_time: duration!(75),
+        },
+        production: Production {
+            producer: "RoboticsFacility",
+            train_time: duration!(75),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(1),
         cargo_size: const_real!(8),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::COLOSSUS_THERMALLANCES), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::COLOSSUS_THERMALLANCES]),
     };
 
     pub const MOTHERSHIP: Unit = Unit {
@@ -765,20 +1072,36 @@ impl Unit {
             decel: rate!(1),
             turn_rate: rate!(720),
             lateral_accel: rate!(2.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(300),
             gas: const_real!(300),
             supply: const_real!(6),
-            build_time: duration!(0.0),
+            build_time: duration!(125),
+        },
+        production: Production {
+            producer: "Nexus",
+            train_time: duration!(125),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(1.25),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(14),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::MOTHERSHIP_MOTHERSHIPBEAM), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::MOTHERSHIP_MOTHERSHIPBEAM]),
     };
 
     pub const SCV: Unit = Unit {
@@ -804,20 +1127,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(50),
             gas: const_real!(0),
             supply: const_real!(1),
-            build_time: duration!(0.0),
+            build_time: duration!(17),
+        },
+        production: Production {
+            producer: "PlanetaryFortress",
+            train_time: duration!(17),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.375),
         cargo_size: const_real!(1),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(8),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::SCV_FUSIONCUTTER), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::SCV_FUSIONCUTTER]),
     };
 
     pub const MARINE: Unit = Unit {
@@ -843,20 +1182,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(50),
             gas: const_real!(0),
             supply: const_real!(1),
-            build_time: duration!(0.0),
+            build_time: duration!(25),
+        },
+        production: Production {
+            producer: "Barracks",
+            train_time: duration!(25),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.375),
         cargo_size: const_real!(1),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(9),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::MARINE_GUASSRIFLE), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::MARINE_GUASSRIFLE]),
     };
 
     pub const REAPER: Unit = Unit {
@@ -882,20 +1237,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(50),
             gas: const_real!(50),
             supply: const_real!(1),
-            build_time: duration!(0.0),
+            build_time: duration!(45),
+        },
+        production: Production {
+            producer: "Barracks",
+            train_time: duration!(45),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.375),
         cargo_size: const_real!(1),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(9),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::REAPER_P38SCYTHEGUASSPISTOL), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::REAPER_P38SCYTHEGUASSPISTOL]),
     };
 
     pub const GHOST: Unit = Unit {
@@ -921,20 +1292,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(150),
             gas: const_real!(125),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(40),
+        },
+        production: Production {
+            producer: "Barracks",
+            train_time: duration!(40),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.375),
         cargo_size: const_real!(2),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(11),
         push_priority: const_real!(10),
         energy_start: const_real!(75),
         energy_max: const_real!(200),
-        weapons: [Some(Weapon::GHOST_C10CANISTERRIFLE), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::GHOST_C10CANISTERRIFLE]),
     };
 
     pub const SIEGETANK: Unit = Unit {
@@ -960,20 +1347,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(360),
             lateral_accel: rate!(64),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(150),
             gas: const_real!(125),
             supply: const_real!(3),
-            build_time: duration!(0.0),
+            build_time: duration!(45),
+        },
+        production: Production {
+            producer: "Factory",
+            train_time: duration!(45),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.875),
         cargo_size: const_real!(4),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(11),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::SIEGETANK_90MMCANNONS), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::SIEGETANK_90MMCANNONS]),
     };
 
     pub const SIEGETANKSIEGED: Unit = Unit {
@@ -999,20 +1402,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(360),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(150),
             gas: const_real!(125),
             supply: const_real!(3),
-            build_time: duration!(0.0),
+            build_time: duration!(45),
+        },
+        production: Production {
+            producer: "Factory",
+            train_time: duration!(45),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.875),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(11),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::SIEGETANKSIEGED_CRUCIOSHOCKCANNON), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::SIEGETANKSIEGED_CRUCIOSHOCKCANNON]),
     };
 
     pub const THOR: Unit = Unit {
@@ -1038,23 +1457,39 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(360),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(300),
             gas: const_real!(200),
             supply: const_real!(6),
-            build_time: duration!(0.0),
+            build_time: duration!(60),
+        },
+        production: Production {
+            producer: "Factory",
+            train_time: duration!(60),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(1),
         cargo_size: const_real!(8),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(11),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [
-            Some(Weapon::THOR_JAVELINMISSILELAUNCHERS),
-            Some(Weapon::THOR_THORSHAMMER),
-        ],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[
+            Weapon::THOR_JAVELINMISSILELAUNCHERS,
+            Weapon::THOR_THORSHAMMER,
+        ]),
     };
 
     pub const THORALT: Unit = Unit {
@@ -1080,23 +1515,39 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(360),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(300),
             gas: const_real!(200),
             supply: const_real!(6),
-            build_time: duration!(0.0),
+            build_time: duration!(60),
+        },
+        production: Production {
+            producer: "Factory",
+            train_time: duration!(60),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(1),
         cargo_size: const_real!(8),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(11),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [
-            Some(Weapon::THORALT_LANCEMISSILELAUNCHERS),
-            Some(Weapon::THORALT_THORSHAMMER),
-        ],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[
+            Weapon::THORALT_LANCEMISSILELAUNCHERS,
+            Weapon::THORALT_THORSHAMMER,
+        ]),
     };
 
     pub const BANSHEE: Unit = Unit {
@@ -1122,20 +1573,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(1499.9414),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(150),
             gas: const_real!(100),
             supply: const_real!(3),
-            build_time: duration!(0.0),
+            build_time: duration!(60),
+        },
+        production: Production {
+            producer: "Starport",
+            train_time: duration!(60),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.75),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(50),
         energy_max: const_real!(200),
-        weapons: [Some(Weapon::BANSHEE_BACKLASHROCKETS), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::BANSHEE_BACKLASHROCKETS]),
     };
 
     pub const MEDIVAC: Unit = Unit {
@@ -1161,20 +1628,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(1000),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(100),
             gas: const_real!(100),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(42),
+        },
+        production: Production {
+            producer: "Starport",
+            train_time: duration!(42),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.75),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(8),
+        max_cargo_size: const_real!(8),
         sight: const_real!(11),
         push_priority: const_real!(10),
         energy_start: const_real!(50),
         energy_max: const_real!(200),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const BATTLECRUISER: Unit = Unit {
@@ -1200,23 +1683,39 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(400),
             gas: const_real!(300),
             supply: const_real!(6),
-            build_time: duration!(0.0),
+            build_time: duration!(90),
+        },
+        production: Production {
+            producer: "Starport",
+            train_time: duration!(90),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(1.25),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(12),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [
-            Some(Weapon::BATTLECRUISER_BATTLECRUISERWEAPONSWITCH),
-            Some(Weapon::BATTLECRUISER_ATALASERBATTERY),
-        ],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[
+            Weapon::BATTLECRUISER_ATSLASERBATTERY,
+            Weapon::BATTLECRUISER_ATALASERBATTERY,
+        ]),
     };
 
     pub const RAVEN: Unit = Unit {
@@ -1242,20 +1741,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(100),
             gas: const_real!(150),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(48),
+        },
+        production: Production {
+            producer: "Starport",
+            train_time: duration!(48),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.625),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(11),
         push_priority: const_real!(10),
         energy_start: const_real!(50),
         energy_max: const_real!(200),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const VIKINGGROUND: Unit = Unit {
@@ -1281,20 +1796,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(150),
             gas: const_real!(75),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(42),
+        },
+        production: Production {
+            producer: "Starport",
+            train_time: duration!(42),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.75),
         cargo_size: const_real!(2),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::VIKINGGROUND_TWINGATLINGCANNON), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::VIKINGGROUND_TWINGATLINGCANNON]),
     };
 
     pub const VIKINGAIR: Unit = Unit {
@@ -1320,20 +1851,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(150),
             gas: const_real!(75),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(42),
+        },
+        production: Production {
+            producer: "Starport",
+            train_time: duration!(42),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.75),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::VIKINGAIR_LANZERTORPEDOES), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::VIKINGAIR_LANZERTORPEDOES]),
     };
 
     pub const LARVA: Unit = Unit {
@@ -1359,6 +1906,7 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(0),
@@ -1366,13 +1914,28 @@ impl Unit {
             supply: const_real!(0),
             build_time: duration!(0),
         },
+        production: Production {
+            producer: "",
+            train_time: duration!(0),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
+        },
         size: const_real!(0.125),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(5),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Cocoon,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const DRONE: Unit = Unit {
@@ -1398,20 +1961,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(50),
             gas: const_real!(0),
             supply: const_real!(1),
-            build_time: duration!(0.0),
+            build_time: duration!(17),
+        },
+        production: Production {
+            producer: "Larva",
+            train_time: duration!(17),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.375),
         cargo_size: const_real!(1),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(8),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::DRONE_SPINES), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::DRONE_SPINES]),
     };
 
     pub const ROACH: Unit = Unit {
@@ -1437,23 +2016,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1.3),
         },
         cost: Cost {
             minerals: const_real!(75),
             gas: const_real!(25),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(27),
+        },
+        production: Production {
+            producer: "Larva",
+            train_time: duration!(27),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.5),
         cargo_size: const_real!(2),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(9),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [
-            Some(Weapon::ROACH_ROACHMELEE),
-            Some(Weapon::ROACH_ACIDSALIVA),
-        ],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::ROACH_ROACHMELEE, Weapon::ROACH_ACIDSALIVA]),
     };
 
     pub const ROACHBURROWED: Unit = Unit {
@@ -1479,20 +2071,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1.3),
         },
         cost: Cost {
             minerals: const_real!(75),
             gas: const_real!(25),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(27),
+        },
+        production: Production {
+            producer: "Larva",
+            train_time: duration!(27),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.5),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(5),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const OVERLORD: Unit = Unit {
@@ -1518,20 +2126,36 @@ impl Unit {
             decel: rate!(1.625),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(100),
             gas: const_real!(0),
             supply: const_real!(0),
-            build_time: duration!(0.0),
+            build_time: duration!(25),
+        },
+        production: Production {
+            producer: "Larva",
+            train_time: duration!(25),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(1),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(8),
+        max_cargo_size: const_real!(8),
         sight: const_real!(11),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const OVERSEER: Unit = Unit {
@@ -1557,20 +2181,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(150),
             gas: const_real!(50),
             supply: const_real!(0),
-            build_time: duration!(0.0),
+            build_time: duration!(16.6665),
+        },
+        production: Production {
+            producer: "Overlord",
+            train_time: duration!(16.6665),
+            warp_cooldown: None,
+            morph_from: Some(Base::Overlord),
+            morph_cost: Cost {
+                minerals: const_real!(50),
+                gas: const_real!(50),
+                supply: const_real!(0),
+                build_time: duration!(16.6665),
+            },
         },
         size: const_real!(1),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(11),
         push_priority: const_real!(10),
         energy_start: const_real!(50),
         energy_max: const_real!(200),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const ZERGLING: Unit = Unit {
@@ -1596,20 +2236,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1.3),
         },
         cost: Cost {
             minerals: const_real!(25),
             gas: const_real!(0),
             supply: const_real!(0.5),
-            build_time: duration!(0.0),
+            build_time: duration!(24),
+        },
+        production: Production {
+            producer: "Larva",
+            train_time: duration!(24),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.375),
         cargo_size: const_real!(1),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(8),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::ZERGLING_CLAWS), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::ZERGLING_CLAWS]),
     };
 
     pub const HYDRALISK: Unit = Unit {
@@ -1635,23 +2291,39 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46),
+            creep_speed: const_real!(1.3),
         },
         cost: Cost {
             minerals: const_real!(100),
             gas: const_real!(50),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(33),
+        },
+        production: Production {
+            producer: "Larva",
+            train_time: duration!(33),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.625),
         cargo_size: const_real!(2),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(9),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [
-            Some(Weapon::HYDRALISK_HYDRALISKMELEE),
-            Some(Weapon::HYDRALISK_NEEDLESPINES),
-        ],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[
+            Weapon::HYDRALISK_HYDRALISKMELEE,
+            Weapon::HYDRALISK_NEEDLESPINES,
+        ]),
     };
 
     pub const MUTALISK: Unit = Unit {
@@ -1677,20 +2349,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(1499.9414),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(100),
             gas: const_real!(100),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(33),
+        },
+        production: Production {
+            producer: "Larva",
+            train_time: duration!(33),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.5),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(11),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::MUTALISK_GLAIVEWURM), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::MUTALISK_GLAIVEWURM]),
     };
 
     pub const ULTRALISK: Unit = Unit {
@@ -1716,20 +2404,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(360),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1.3),
         },
         cost: Cost {
             minerals: const_real!(275),
             gas: const_real!(200),
             supply: const_real!(6),
-            build_time: duration!(0.0),
+            build_time: duration!(55),
+        },
+        production: Production {
+            producer: "Larva",
+            train_time: duration!(55),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.875),
         cargo_size: const_real!(8),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(9),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::ULTRALISK_KAISERBLADES), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::ULTRALISK_KAISERBLADES]),
     };
 
     pub const BANELING: Unit = Unit {
@@ -1755,23 +2459,39 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1.3),
         },
         cost: Cost {
             minerals: const_real!(50),
             gas: const_real!(25),
             supply: const_real!(0.5),
-            build_time: duration!(0.0),
+            build_time: duration!(20),
+        },
+        production: Production {
+            producer: "Zergling",
+            train_time: duration!(20),
+            warp_cooldown: None,
+            morph_from: Some(Base::Zergling),
+            morph_cost: Cost {
+                minerals: const_real!(25),
+                gas: const_real!(25),
+                supply: const_real!(0),
+                build_time: duration!(20),
+            },
         },
         size: const_real!(0.375),
         cargo_size: const_real!(2),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(8),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [
-            Some(Weapon::BANELING_VOLATILEBURST),
-            Some(Weapon::BANELING_VOLATILEBURSTBUILDING),
-        ],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[
+            Weapon::BANELING_VOLATILEBURST,
+            Weapon::BANELING_VOLATILEBURSTBUILDING,
+        ]),
     };
 
     pub const INFESTOR: Unit = Unit {
@@ -1797,20 +2517,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46),
+            creep_speed: const_real!(1.3),
         },
         cost: Cost {
             minerals: const_real!(100),
             gas: const_real!(150),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(50),
+        },
+        production: Production {
+            producer: "Larva",
+            train_time: duration!(50),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.625),
         cargo_size: const_real!(2),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(75),
         energy_max: const_real!(200),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const INFESTORBURROWED: Unit = Unit {
@@ -1836,20 +2572,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46),
+            creep_speed: const_real!(1.3),
         },
         cost: Cost {
             minerals: const_real!(100),
             gas: const_real!(150),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(50),
+        },
+        production: Production {
+            producer: "Larva",
+            train_time: duration!(50),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.625),
         cargo_size: const_real!(2),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(8),
         push_priority: const_real!(10),
         energy_start: const_real!(75),
         energy_max: const_real!(200),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const IMMORTAL: Unit = Unit {
@@ -1875,20 +2627,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(275),
             gas: const_real!(100),
             supply: const_real!(4),
-            build_time: duration!(0.0),
+            build_time: duration!(55),
+        },
+        production: Production {
+            producer: "RoboticsFacility",
+            train_time: duration!(55),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.75),
         cargo_size: const_real!(4),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(9),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::IMMORTAL_PHASEDISRUPTORS), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::IMMORTAL_PHASEDISRUPTORS]),
     };
 
     pub const MARAUDER: Unit = Unit {
@@ -1914,20 +2682,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(69.125),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(100),
             gas: const_real!(25),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(30),
+        },
+        production: Production {
+            producer: "Barracks",
+            train_time: duration!(30),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.5625),
         cargo_size: const_real!(2),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::MARAUDER_PUNISHERGRENADES), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::MARAUDER_PUNISHERGRENADES]),
     };
 
     pub const BROODLORD: Unit = Unit {
@@ -1953,20 +2737,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(300),
             gas: const_real!(250),
             supply: const_real!(4),
-            build_time: duration!(0.0),
+            build_time: duration!(33.8332),
+        },
+        production: Production {
+            producer: "Corruptor",
+            train_time: duration!(33.8332),
+            warp_cooldown: None,
+            morph_from: Some(Base::Corruptor),
+            morph_cost: Cost {
+                minerals: const_real!(150),
+                gas: const_real!(150),
+                supply: const_real!(2),
+                build_time: duration!(33.8332),
+            },
         },
         size: const_real!(1),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(12),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::BROODLORD_BROODLINGSTRIKE), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::BROODLORD_BROODLINGSTRIKE]),
     };
 
     pub const BROODLING: Unit = Unit {
@@ -1992,6 +2792,7 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(0),
@@ -1999,13 +2800,28 @@ impl Unit {
             supply: const_real!(0),
             build_time: duration!(0),
         },
+        production: Production {
+            producer: "",
+            train_time: duration!(0),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
+        },
         size: const_real!(0.375),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(7),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::BROODLING_NEEDLECLAWS]),
     };
 
     pub const CORRUPTOR: Unit = Unit {
@@ -2031,20 +2847,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(150),
             gas: const_real!(100),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(40),
+        },
+        production: Production {
+            producer: "Larva",
+            train_time: duration!(40),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.625),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::CORRUPTOR_PARASITESPORE), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::CORRUPTOR_PARASITESPORE]),
     };
 
     pub const SENTRY: Unit = Unit {
@@ -2070,20 +2902,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(50),
             gas: const_real!(100),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(32),
+        },
+        production: Production {
+            producer: "Gateway",
+            train_time: duration!(32),
+            warp_cooldown: Some(duration!(32)),
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.5),
         cargo_size: const_real!(2),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(50),
         energy_max: const_real!(200),
-        weapons: [Some(Weapon::SENTRY_DISRUPTIONBEAM), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::SENTRY_DISRUPTIONBEAM]),
     };
 
     pub const QUEEN: Unit = Unit {
@@ -2109,23 +2957,40 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46),
+            creep_speed: const_real!(2.6665),
         },
         cost: Cost {
             minerals: const_real!(150),
             gas: const_real!(0),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(50),
+        },
+        production: Production {
+            producer: "Hatchery",
+            train_time: duration!(50),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.875),
         cargo_size: const_real!(2),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(9),
         push_priority: const_real!(10),
         energy_start: const_real!(25),
         energy_max: const_real!(200),
-        weapons: [
-            Some(Weapon::QUEEN_ACIDSPINES),
-            Some(Weapon::QUEEN_TALONSMISSILE),
-        ],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[
+            Weapon::QUEEN_ACIDSPINES,
+            Weapon::QUEEN_TALONS,
+            Weapon::QUEEN_TALONSMISSILE,
+        ]),
     };
 
     pub const HELLION: Unit = Unit {
@@ -2151,20 +3016,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(46),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(100),
             gas: const_real!(0),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(30),
+        },
+        production: Production {
+            producer: "Factory",
+            train_time: duration!(30),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.625),
         cargo_size: const_real!(2),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::HELLION_INFERNALFLAMETHROWER), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::HELLION_INFERNALFLAMETHROWER]),
     };
 
     pub const CHANGELING: Unit = Unit {
@@ -2190,6 +3071,7 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(0),
@@ -2197,13 +3079,28 @@ impl Unit {
             supply: const_real!(0),
             build_time: duration!(0),
         },
+        production: Production {
+            producer: "",
+            train_time: duration!(0),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
+        },
         size: const_real!(0.375),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(8),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const ORACLE: Unit = Unit {
@@ -2229,20 +3126,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(150),
             gas: const_real!(150),
             supply: const_real!(3),
-            build_time: duration!(0.0),
+            build_time: duration!(52),
+        },
+        production: Production {
+            producer: "Stargate",
+            train_time: duration!(52),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.75),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(50),
         energy_max: const_real!(200),
-        weapons: [Some(Weapon::ORACLE_ORACLE), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::ORACLE_ORACLE]),
     };
 
     pub const HELLBAT: Unit = Unit {
@@ -2268,20 +3181,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(46),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(100),
             gas: const_real!(0),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(30),
+        },
+        production: Production {
+            producer: "Factory",
+            train_time: duration!(30),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.625),
         cargo_size: const_real!(4),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::HELLBAT_HELLIONTANK), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::HELLBAT_HELLIONTANK]),
     };
 
     pub const SWARMHOST: Unit = Unit {
@@ -2307,20 +3236,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(360),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1.3),
         },
         cost: Cost {
             minerals: const_real!(100),
             gas: const_real!(75),
             supply: const_real!(3),
-            build_time: duration!(0.0),
+            build_time: duration!(40),
+        },
+        production: Production {
+            producer: "Larva",
+            train_time: duration!(40),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.8125),
         cargo_size: const_real!(4),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const LOCUST: Unit = Unit {
@@ -2346,6 +3291,7 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1.4),
         },
         cost: Cost {
             minerals: const_real!(0),
@@ -2353,16 +3299,28 @@ impl Unit {
             supply: const_real!(0),
             build_time: duration!(0),
         },
+        production: Production {
+            producer: "",
+            train_time: duration!(0),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
+        },
         size: const_real!(0.375),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(6),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [
-            Some(Weapon::LOCUST_LOCUSTMPMELEE),
-            Some(Weapon::LOCUST_LOCUSTMP),
-        ],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::LOCUST_LOCUSTMPMELEE, Weapon::LOCUST_LOCUSTMP]),
     };
 
     pub const TEMPEST: Unit = Unit {
@@ -2388,23 +3346,36 @@ impl Unit {
             decel: rate!(3),
             turn_rate: rate!(720),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(250),
             gas: const_real!(175),
             supply: const_real!(5),
-            build_time: duration!(0.0),
+            build_time: duration!(60),
+        },
+        production: Production {
+            producer: "Stargate",
+            train_time: duration!(60),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(1.125),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(12),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [
-            Some(Weapon::TEMPEST_TEMPEST),
-            Some(Weapon::TEMPEST_TEMPESTGROUND),
-        ],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::TEMPEST_TEMPEST, Weapon::TEMPEST_TEMPESTGROUND]),
     };
 
     pub const VIPER: Unit = Unit {
@@ -2430,20 +3401,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(100),
             gas: const_real!(200),
             supply: const_real!(3),
-            build_time: duration!(0.0),
+            build_time: duration!(40),
+        },
+        production: Production {
+            producer: "Larva",
+            train_time: duration!(40),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(1),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(11),
         push_priority: const_real!(10),
         energy_start: const_real!(50),
         energy_max: const_real!(200),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const WIDOWMINE: Unit = Unit {
@@ -2469,20 +3456,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(75),
             gas: const_real!(25),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(30),
+        },
+        production: Production {
+            producer: "Factory",
+            train_time: duration!(30),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.5),
         cargo_size: const_real!(2),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(7),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Low,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const WIDOWMINEBURROWED: Unit = Unit {
@@ -2508,20 +3511,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(75),
             gas: const_real!(25),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(30),
+        },
+        production: Production {
+            producer: "Factory",
+            train_time: duration!(30),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.5),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(7),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const CYCLONE: Unit = Unit {
@@ -2547,23 +3566,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(360),
             lateral_accel: rate!(64),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(125),
             gas: const_real!(50),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(45),
+        },
+        production: Production {
+            producer: "Factory",
+            train_time: duration!(45),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.75),
         cargo_size: const_real!(4),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(11),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [
-            Some(Weapon::CYCLONE_TYPHOONMISSILEPOD),
-            Some(Weapon::CYCLONE_CYCLONEFAKEWEAPON),
-        ],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::CYCLONE_TYPHOONMISSILEPOD]),
     };
 
     pub const LOCUSTFLYING: Unit = Unit {
@@ -2589,6 +3621,7 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(720),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(0),
@@ -2596,13 +3629,28 @@ impl Unit {
             supply: const_real!(0),
             build_time: duration!(0),
         },
+        production: Production {
+            producer: "",
+            train_time: duration!(0),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
+        },
         size: const_real!(0.375),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(6),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::LOCUSTFLYING_LOCUSTMPFLYINGSWOOPWEAPON), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::LOCUSTFLYING_LOCUSTMPFLYINGSWOOPWEAPON]),
     };
 
     pub const DISRUPTOR: Unit = Unit {
@@ -2628,20 +3676,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(150),
             gas: const_real!(150),
             supply: const_real!(4),
-            build_time: duration!(0.0),
+            build_time: duration!(50),
+        },
+        production: Production {
+            producer: "RoboticsFacility",
+            train_time: duration!(50),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.625),
         cargo_size: const_real!(4),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(9),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [None, None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[]),
     };
 
     pub const ADEPT: Unit = Unit {
@@ -2667,20 +3731,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(999.8437),
             lateral_accel: rate!(46.0625),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(100),
             gas: const_real!(25),
             supply: const_real!(2),
-            build_time: duration!(0.0),
+            build_time: duration!(42),
+        },
+        production: Production {
+            producer: "Gateway",
+            train_time: duration!(42),
+            warp_cooldown: Some(duration!(28)),
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.5),
         cargo_size: const_real!(2),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(9),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::ADEPT_ADEPT), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::ADEPT_ADEPT]),
     };
 
     pub const LIBERATOR: Unit = Unit {
@@ -2706,20 +3786,36 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(1499.9414),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(150),
             gas: const_real!(125),
             supply: const_real!(3),
-            build_time: duration!(0.0),
+            build_time: duration!(60),
+        },
+        production: Production {
+            producer: "Starport",
+            train_time: duration!(60),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.75),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::LIBERATOR_LIBERATORMISSILELAUNCHERS), None],
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::LIBERATOR_LIBERATORMISSILELAUNCHERS]),
     };
 
     pub const LIBERATORSIEGED: Unit = Unit {
@@ -2745,19 +3841,505 @@ impl Unit {
             decel: rate!(0),
             turn_rate: rate!(1499.9414),
             lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
         },
         cost: Cost {
             minerals: const_real!(150),
             gas: const_real!(125),
             supply: const_real!(3),
-            build_time: duration!(0.0),
+            build_time: duration!(60),
+        },
+        production: Production {
+            producer: "Starport",
+            train_time: duration!(60),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
         },
         size: const_real!(0.75),
         cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
+        sight: const_real!(10),
+        push_priority: const_real!(10),
+        energy_start: const_real!(0),
+        energy_max: const_real!(0),
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::LIBERATORSIEGED_LIBERATORAGWEAPON]),
+    };
+
+    pub const PHOTONCANNON: Unit = Unit {
+        base: Base::PhotonCannon,
+        faction: Faction::Protoss,
+        collision: Collision::Ground,
+        flags: Flags::new(false, true, false, false, false, false, true, false, false),
+        hull: Health {
+            max: const_real!(150),
+            regen: rate!(0),
+            delay: duration!(0),
+            armor: const_real!(1),
+        },
+        shields: Health {
+            max: const_real!(150),
+            regen: rate!(2),
+            delay: duration!(10),
+            armor: const_real!(0),
+        },
+        movement: Movement {
+            speed: rate!(0),
+            accel: rate!(1000),
+            decel: rate!(0),
+            turn_rate: rate!(720),
+            lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
+        },
+        cost: Cost {
+            minerals: const_real!(150),
+            gas: const_real!(0),
+            supply: const_real!(0),
+            build_time: duration!(40),
+        },
+        production: Production {
+            producer: "Probe",
+            train_time: duration!(40),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
+        },
+        size: const_real!(1),
+        cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
+        sight: const_real!(11),
+        push_priority: const_real!(10),
+        energy_start: const_real!(0),
+        energy_max: const_real!(0),
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::PHOTONCANNON_PHOTONCANNON]),
+    };
+
+    pub const SHIELDBATTERY: Unit = Unit {
+        base: Base::ShieldBattery,
+        faction: Faction::Protoss,
+        collision: Collision::Ground,
+        flags: Flags::new(false, true, false, false, false, false, true, false, false),
+        hull: Health {
+            max: const_real!(150),
+            regen: rate!(0),
+            delay: duration!(0),
+            armor: const_real!(1),
+        },
+        shields: Health {
+            max: const_real!(150),
+            regen: rate!(2),
+            delay: duration!(10),
+            armor: const_real!(0),
+        },
+        movement: Movement {
+            speed: rate!(0),
+            accel: rate!(1000),
+            decel: rate!(0),
+            turn_rate: rate!(720),
+            lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
+        },
+        cost: Cost {
+            minerals: const_real!(100),
+            gas: const_real!(0),
+            supply: const_real!(0),
+            build_time: duration!(40),
+        },
+        production: Production {
+            producer: "Probe",
+            train_time: duration!(40),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
+        },
+        size: const_real!(1),
+        cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
+        sight: const_real!(9),
+        push_priority: const_real!(10),
+        energy_start: const_real!(50),
+        energy_max: const_real!(100),
+        target_priority: ThreatLevel::Building,
+        weapons: Cow::Borrowed(&[]),
+    };
+
+    pub const BUNKER: Unit = Unit {
+        base: Base::Bunker,
+        faction: Faction::Terran,
+        collision: Collision::Ground,
+        flags: Flags::new(false, true, true, false, false, false, true, false, false),
+        hull: Health {
+            max: const_real!(400),
+            regen: rate!(0),
+            delay: duration!(0),
+            armor: const_real!(1),
+        },
+        shields: Health {
+            max: const_real!(0),
+            regen: rate!(0),
+            delay: duration!(0),
+            armor: const_real!(0),
+        },
+        movement: Movement {
+            speed: rate!(0),
+            accel: rate!(1000),
+            decel: rate!(0),
+            turn_rate: rate!(720),
+            lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
+        },
+        cost: Cost {
+            minerals: const_real!(100),
+            gas: const_real!(0),
+            supply: const_real!(0),
+            build_time: duration!(40),
+        },
+        production: Production {
+            producer: "SCV",
+            train_time: duration!(40),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
+        },
+        size: const_real!(1.25),
+        cargo_size: const_real!(0),
+        cargo_capacity: const_real!(4),
+        max_cargo_size: const_real!(2),
         sight: const_real!(10),
         push_priority: const_real!(10),
         energy_start: const_real!(0),
         energy_max: const_real!(0),
-        weapons: [Some(Weapon::LIBERATORSIEGED_LIBERATORAGWEAPON), None],
+        target_priority: ThreatLevel::Low,
+        weapons: Cow::Borrowed(&[]),
     };
+
+    pub const MISSILETURRET: Unit = Unit {
+        base: Base::MissileTurret,
+        faction: Faction::Terran,
+        collision: Collision::Ground,
+        flags: Flags::new(false, true, true, false, false, false, true, false, false),
+        hull: Health {
+            max: const_real!(250),
+            regen: rate!(0),
+            delay: duration!(0),
+            armor: const_real!(0),
+        },
+        shields: Health {
+            max: const_real!(0),
+            regen: rate!(0),
+            delay: duration!(0),
+            armor: const_real!(0),
+        },
+        movement: Movement {
+            speed: rate!(0),
+            accel: rate!(1000),
+            decel: rate!(0),
+            turn_rate: rate!(720),
+            lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
+        },
+        cost: Cost {
+            minerals: const_real!(100),
+            gas: const_real!(0),
+            supply: const_real!(0),
+            build_time: duration!(25),
+        },
+        production: Production {
+            producer: "SCV",
+            train_time: duration!(25),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
+        },
+        size: const_real!(0.75),
+        cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
+        sight: const_real!(11),
+        push_priority: const_real!(10),
+        energy_start: const_real!(0),
+        energy_max: const_real!(0),
+        target_priority: ThreatLevel::Low,
+        weapons: Cow::Borrowed(&[Weapon::MISSILETURRET_LONGBOLTMISSILE]),
+    };
+
+    pub const PLANETARYFORTRESS: Unit = Unit {
+        base: Base::PlanetaryFortress,
+        faction: Faction::Terran,
+        collision: Collision::Ground,
+        flags: Flags::new(false, true, true, false, false, false, true, false, false),
+        hull: Health {
+            max: const_real!(1500),
+            regen: rate!(0),
+            delay: duration!(0),
+            armor: const_real!(3),
+        },
+        shields: Health {
+            max: const_real!(0),
+            regen: rate!(0),
+            delay: duration!(0),
+            armor: const_real!(0),
+        },
+        movement: Movement {
+            speed: rate!(0),
+            accel: rate!(1000),
+            decel: rate!(0),
+            turn_rate: rate!(720),
+            lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
+        },
+        cost: Cost {
+            minerals: const_real!(550),
+            gas: const_real!(150),
+            supply: const_real!(0),
+            build_time: duration!(50),
+        },
+        production: Production {
+            producer: "CommandCenter",
+            train_time: duration!(50),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(150),
+                gas: const_real!(150),
+                supply: const_real!(0),
+                build_time: duration!(50),
+            },
+        },
+        size: const_real!(2.5),
+        cargo_size: const_real!(0),
+        cargo_capacity: const_real!(5),
+        max_cargo_size: const_real!(1),
+        sight: const_real!(11),
+        push_priority: const_real!(10),
+        energy_start: const_real!(0),
+        energy_max: const_real!(0),
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::PLANETARYFORTRESS_TWINIBIKSCANNON]),
+    };
+
+    pub const SPINECRAWLER: Unit = Unit {
+        base: Base::SpineCrawler,
+        faction: Faction::Zerg,
+        collision: Collision::Ground,
+        flags: Flags::new(false, true, false, true, false, false, true, false, false),
+        hull: Health {
+            max: const_real!(300),
+            regen: rate!(0.2734),
+            delay: duration!(0),
+            armor: const_real!(2),
+        },
+        shields: Health {
+            max: const_real!(0),
+            regen: rate!(0),
+            delay: duration!(0),
+            armor: const_real!(0),
+        },
+        movement: Movement {
+            speed: rate!(0),
+            accel: rate!(1000),
+            decel: rate!(0),
+            turn_rate: rate!(719.4726),
+            lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
+        },
+        cost: Cost {
+            minerals: const_real!(150),
+            gas: const_real!(0),
+            supply: const_real!(0),
+            build_time: duration!(50),
+        },
+        production: Production {
+            producer: "Drone",
+            train_time: duration!(50),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
+        },
+        size: const_real!(0.875),
+        cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
+        sight: const_real!(11),
+        push_priority: const_real!(10),
+        energy_start: const_real!(0),
+        energy_max: const_real!(0),
+        target_priority: ThreatLevel::Normal,
+        weapons: Cow::Borrowed(&[Weapon::SPINECRAWLER_IMPALERTENTACLE]),
+    };
+
+    pub const SPORECRAWLER: Unit = Unit {
+        base: Base::SporeCrawler,
+        faction: Faction::Zerg,
+        collision: Collision::Ground,
+        flags: Flags::new(false, true, false, true, false, false, true, false, false),
+        hull: Health {
+            max: const_real!(400),
+            regen: rate!(0.2734),
+            delay: duration!(0),
+            armor: const_real!(1),
+        },
+        shields: Health {
+            max: const_real!(0),
+            regen: rate!(0),
+            delay: duration!(0),
+            armor: const_real!(0),
+        },
+        movement: Movement {
+            speed: rate!(0),
+            accel: rate!(1000),
+            decel: rate!(0),
+            turn_rate: rate!(719.4726),
+            lateral_accel: rate!(0),
+            creep_speed: const_real!(1),
+        },
+        cost: Cost {
+            minerals: const_real!(125),
+            gas: const_real!(0),
+            supply: const_real!(0),
+            build_time: duration!(30),
+        },
+        production: Production {
+            producer: "Drone",
+            train_time: duration!(30),
+            warp_cooldown: None,
+            morph_from: None,
+            morph_cost: Cost {
+                minerals: const_real!(0),
+                gas: const_real!(0),
+                supply: const_real!(0),
+                build_time: duration!(0),
+            },
+        },
+        size: const_real!(0.875),
+        cargo_size: const_real!(0),
+        cargo_capacity: const_real!(0),
+        max_cargo_size: const_real!(0),
+        sight: const_real!(11),
+        push_priority: const_real!(10),
+        energy_start: const_real!(0),
+        energy_max: const_real!(0),
+        target_priority: ThreatLevel::Low,
+        weapons: Cow::Borrowed(&[Weapon::SPORECRAWLER_ACIDSPEW]),
+    };
+
+    /// Returns the default stats for `base`, or `None` if there's no generated data for it (e.g.
+    /// `Base::Custom`)
+    pub fn from_base(base: Base) -> Option<Self> {
+        Some(match base {
+            Base::Lurker => Self::LURKER,
+            Base::LurkerBurrowed => Self::LURKERBURROWED,
+            Base::LurkerEgg => Self::LURKEREGG,
+            Base::Ravager => Self::RAVAGER,
+            Base::RavagerCocoon => Self::RAVAGERCOCOON,
+            Base::MULE => Self::MULE,
+            Base::Probe => Self::PROBE,
+            Base::Zealot => Self::ZEALOT,
+            Base::HighTemplar => Self::HIGHTEMPLAR,
+            Base::DarkTemplar => Self::DARKTEMPLAR,
+            Base::Observer => Self::OBSERVER,
+            Base::Carrier => Self::CARRIER,
+            Base::Interceptor => Self::INTERCEPTOR,
+            Base::Archon => Self::ARCHON,
+            Base::Phoenix => Self::PHOENIX,
+            Base::VoidRay => Self::VOIDRAY,
+            Base::WarpPrism => Self::WARPPRISM,
+            Base::Stalker => Self::STALKER,
+            Base::Colossus => Self::COLOSSUS,
+            Base::Mothership => Self::MOTHERSHIP,
+            Base::SCV => Self::SCV,
+            Base::Marine => Self::MARINE,
+            Base::Reaper => Self::REAPER,
+            Base::Ghost => Self::GHOST,
+            Base::SiegeTank => Self::SIEGETANK,
+            Base::SiegeTankSieged => Self::SIEGETANKSIEGED,
+            Base::Thor => Self::THOR,
+            Base::ThorAlt => Self::THORALT,
+            Base::Banshee => Self::BANSHEE,
+            Base::Medivac => Self::MEDIVAC,
+            Base::Battlecruiser => Self::BATTLECRUISER,
+            Base::Raven => Self::RAVEN,
+            Base::VikingGround => Self::VIKINGGROUND,
+            Base::VikingAir => Self::VIKINGAIR,
+            Base::Larva => Self::LARVA,
+            Base::Drone => Self::DRONE,
+            Base::Roach => Self::ROACH,
+            Base::RoachBurrowed => Self::ROACHBURROWED,
+            Base::Overlord => Self::OVERLORD,
+            Base::Overseer => Self::OVERSEER,
+            Base::Zergling => Self::ZERGLING,
+            Base::Hydralisk => Self::HYDRALISK,
+            Base::Mutalisk => Self::MUTALISK,
+            Base::Ultralisk => Self::ULTRALISK,
+            Base::Baneling => Self::BANELING,
+            Base::Infestor => Self::INFESTOR,
+            Base::InfestorBurrowed => Self::INFESTORBURROWED,
+            Base::Immortal => Self::IMMORTAL,
+            Base::Marauder => Self::MARAUDER,
+            Base::BroodLord => Self::BROODLORD,
+            Base::Broodling => Self::BROODLING,
+            Base::Corruptor => Self::CORRUPTOR,
+            Base::Sentry => Self::SENTRY,
+            Base::Queen => Self::QUEEN,
+            Base::Hellion => Self::HELLION,
+            Base::Changeling => Self::CHANGELING,
+            Base::Oracle => Self::ORACLE,
+            Base::Hellbat => Self::HELLBAT,
+            Base::SwarmHost => Self::SWARMHOST,
+            Base::Locust => Self::LOCUST,
+            Base::Tempest => Self::TEMPEST,
+            Base::Viper => Self::VIPER,
+            Base::WidowMine => Self::WIDOWMINE,
+            Base::WidowMineBurrowed => Self::WIDOWMINEBURROWED,
+            Base::Cyclone => Self::CYCLONE,
+            Base::LocustFlying => Self::LOCUSTFLYING,
+            Base::Disruptor => Self::DISRUPTOR,
+            Base::Adept => Self::ADEPT,
+            Base::Liberator => Self::LIBERATOR,
+            Base::LiberatorSieged => Self::LIBERATORSIEGED,
+            Base::PhotonCannon => Self::PHOTONCANNON,
+            Base::ShieldBattery => Self::SHIELDBATTERY,
+            Base::Bunker => Self::BUNKER,
+            Base::MissileTurret => Self::MISSILETURRET,
+            Base::PlanetaryFortress => Self::PLANETARYFORTRESS,
+            Base::SpineCrawler => Self::SPINECRAWLER,
+            Base::SporeCrawler => Self::SPORECRAWLER,
+            _ => return None,
+        })
+    }
 }
@@ -0,0 +1,4667 @@
+
+use crate::{const_real, rate, unit::*};
+
+impl Upgrade {
+    pub const BATTLECRUISERBEHEMOTHREACTOR: Upgrade = Upgrade {
+        name: "BattlecruiserBehemothReactor",
+        faction: Faction::Terran,
+        effects: &[UpgradeEffect {
+            unit: Base::Battlecruiser,
+            field: UpgradeField::EnergyStart,
+            op: UpgradeOp::Add,
+            value: const_real!(25),
+        }],
+    };
+
+    pub const ANABOLICSYNTHESIS: Upgrade = Upgrade {
+        name: "AnabolicSynthesis",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Ultralisk,
+                field: UpgradeField::CreepSpeed,
+                op: UpgradeOp::Add,
+                value: const_real!(-0.2165),
+            },
+            UpgradeEffect {
+                unit: Base::Ultralisk,
+                field: UpgradeField::Speed,
+                op: UpgradeOp::Add,
+                value: rate!(0.5898),
+            },
+        ],
+    };
+
+    pub const GHOSTMOEBIUSREACTOR: Upgrade = Upgrade {
+        name: "GhostMoebiusReactor",
+        faction: Faction::Terran,
+        effects: &[UpgradeEffect {
+            unit: Base::Ghost,
+            field: UpgradeField::EnergyStart,
+            op: UpgradeOp::Add,
+            value: const_real!(25),
+        }],
+    };
+
+    pub const TUNNELINGCLAWS: Upgrade = Upgrade {
+        name: "TunnelingClaws",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::RoachBurrowed,
+                field: UpgradeField::Speed,
+                op: UpgradeOp::Add,
+                value: rate!(1.4062),
+            },
+            UpgradeEffect {
+                unit: Base::RoachBurrowed,
+                field: UpgradeField::HullRegen,
+                op: UpgradeOp::Add,
+                value: rate!(0),
+            },
+            UpgradeEffect {
+                unit: Base::RoachBurrowed,
+                field: UpgradeField::Accel,
+                op: UpgradeOp::Add,
+                value: rate!(1000),
+            },
+        ],
+    };
+
+    pub const HIGHTEMPLARKHAYDARINAMULET: Upgrade = Upgrade {
+        name: "HighTemplarKhaydarinAmulet",
+        faction: Faction::Protoss,
+        effects: &[UpgradeEffect {
+            unit: Base::HighTemplar,
+            field: UpgradeField::EnergyStart,
+            op: UpgradeOp::Add,
+            value: const_real!(25),
+        }],
+    };
+
+    pub const INFESTORENERGYUPGRADE: Upgrade = Upgrade {
+        name: "InfestorEnergyUpgrade",
+        faction: Faction::Zerg,
+        effects: &[UpgradeEffect {
+            unit: Base::Infestor,
+            field: UpgradeField::EnergyStart,
+            op: UpgradeOp::Add,
+            value: const_real!(25),
+        }],
+    };
+
+    pub const RAVENCORVIDREACTOR: Upgrade = Upgrade {
+        name: "RavenCorvidReactor",
+        faction: Faction::Terran,
+        effects: &[UpgradeEffect {
+            unit: Base::Raven,
+            field: UpgradeField::EnergyStart,
+            op: UpgradeOp::Add,
+            value: const_real!(25),
+        }],
+    };
+
+    pub const REAPERSPEED: Upgrade = Upgrade {
+        name: "ReaperSpeed",
+        faction: Faction::Terran,
+        effects: &[UpgradeEffect {
+            unit: Base::Reaper,
+            field: UpgradeField::Speed,
+            op: UpgradeOp::Add,
+            value: rate!(0.875),
+        }],
+    };
+
+    pub const TERRANBUILDINGARMOR: Upgrade = Upgrade {
+        name: "TerranBuildingArmor",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::PlanetaryFortress,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::MissileTurret,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Bunker,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+        ],
+    };
+
+    pub const CHITINOUSPLATING: Upgrade = Upgrade {
+        name: "ChitinousPlating",
+        faction: Faction::Zerg,
+        effects: &[UpgradeEffect {
+            unit: Base::Ultralisk,
+            field: UpgradeField::HullArmor,
+            op: UpgradeOp::Add,
+            value: const_real!(2),
+        }],
+    };
+
+    pub const VIKINGJOTUNBOOSTERS: Upgrade = Upgrade {
+        name: "VikingJotunBoosters",
+        faction: Faction::Terran,
+        effects: &[UpgradeEffect {
+            unit: Base::VikingAir,
+            field: UpgradeField::Range(0),
+            op: UpgradeOp::Add,
+            value: const_real!(2),
+        }],
+    };
+
+    pub const VOIDRAYSPEEDUPGRADE: Upgrade = Upgrade {
+        name: "VoidRaySpeedUpgrade",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::VoidRay,
+                field: UpgradeField::Speed,
+                op: UpgradeOp::Set,
+                value: rate!(3.3203),
+            },
+            UpgradeEffect {
+                unit: Base::VoidRay,
+                field: UpgradeField::Accel,
+                op: UpgradeOp::Set,
+                value: rate!(2.6875),
+            },
+        ],
+    };
+
+    pub const CENTRIFICALHOOKS: Upgrade = Upgrade {
+        name: "CentrificalHooks",
+        faction: Faction::Zerg,
+        effects: &[UpgradeEffect {
+            unit: Base::Baneling,
+            field: UpgradeField::Speed,
+            op: UpgradeOp::Add,
+            value: rate!(0.4531),
+        }],
+    };
+
+    pub const EXTENDEDTHERMALLANCE: Upgrade = Upgrade {
+        name: "ExtendedThermalLance",
+        faction: Faction::Protoss,
+        effects: &[UpgradeEffect {
+            unit: Base::Colossus,
+            field: UpgradeField::Range(0),
+            op: UpgradeOp::Add,
+            value: const_real!(2),
+        }],
+    };
+
+    pub const HIGHCAPACITYBARRELS: Upgrade = Upgrade {
+        name: "HighCapacityBarrels",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(5),
+            },
+            UpgradeEffect {
+                unit: Base::Hellbat,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(17),
+            },
+        ],
+    };
+
+    pub const HISECAUTOTRACKING: Upgrade = Upgrade {
+        name: "HiSecAutoTracking",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::MissileTurret,
+                field: UpgradeField::Range(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::PlanetaryFortress,
+                field: UpgradeField::Range(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANINFANTRYWEAPONSLEVEL1: Upgrade = Upgrade {
+        name: "TerranInfantryWeaponsLevel1",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Marine,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Reaper,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Marauder,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Marauder,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ghost,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ghost,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANINFANTRYWEAPONSLEVEL2: Upgrade = Upgrade {
+        name: "TerranInfantryWeaponsLevel2",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Marine,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Reaper,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Marauder,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Marauder,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ghost,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ghost,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANINFANTRYWEAPONSLEVEL3: Upgrade = Upgrade {
+        name: "TerranInfantryWeaponsLevel3",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Marine,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Reaper,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Marauder,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Marauder,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ghost,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ghost,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANINFANTRYARMORSLEVEL1: Upgrade = Upgrade {
+        name: "TerranInfantryArmorsLevel1",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::SCV,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Marine,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Reaper,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Marauder,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ghost,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::MULE,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANINFANTRYARMORSLEVEL2: Upgrade = Upgrade {
+        name: "TerranInfantryArmorsLevel2",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::SCV,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Marine,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Reaper,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Marauder,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ghost,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::MULE,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANINFANTRYARMORSLEVEL3: Upgrade = Upgrade {
+        name: "TerranInfantryArmorsLevel3",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::SCV,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Marine,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Reaper,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Marauder,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ghost,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::MULE,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANVEHICLEWEAPONSLEVEL1: Upgrade = Upgrade {
+        name: "TerranVehicleWeaponsLevel1",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTank,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTankSieged,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(4),
+            },
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTank,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTankSieged,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellbat,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::Cyclone,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::Bonus(0, Flag::Massive),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANVEHICLEWEAPONSLEVEL2: Upgrade = Upgrade {
+        name: "TerranVehicleWeaponsLevel2",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTank,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTankSieged,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(4),
+            },
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTank,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTankSieged,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellbat,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::Cyclone,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::Bonus(0, Flag::Massive),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANVEHICLEWEAPONSLEVEL3: Upgrade = Upgrade {
+        name: "TerranVehicleWeaponsLevel3",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTank,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTankSieged,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(4),
+            },
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTank,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTankSieged,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellbat,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::Cyclone,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::Bonus(0, Flag::Massive),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANVEHICLEARMORSLEVEL1: Upgrade = Upgrade {
+        name: "TerranVehicleArmorsLevel1",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTank,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTankSieged,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellbat,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::WidowMine,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::WidowMineBurrowed,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Cyclone,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANVEHICLEARMORSLEVEL2: Upgrade = Upgrade {
+        name: "TerranVehicleArmorsLevel2",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTank,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTankSieged,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellbat,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::WidowMine,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::WidowMineBurrowed,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Cyclone,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANVEHICLEARMORSLEVEL3: Upgrade = Upgrade {
+        name: "TerranVehicleArmorsLevel3",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTank,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTankSieged,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellbat,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::WidowMine,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::WidowMineBurrowed,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Cyclone,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANSHIPWEAPONSLEVEL1: Upgrade = Upgrade {
+        name: "TerranShipWeaponsLevel1",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Banshee,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Battlecruiser,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Battlecruiser,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingAir,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingGround,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Liberator,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LiberatorSieged,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(5),
+            },
+            UpgradeEffect {
+                unit: Base::VikingGround,
+                field: UpgradeField::Bonus(0, Flag::Mechanical),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANSHIPWEAPONSLEVEL2: Upgrade = Upgrade {
+        name: "TerranShipWeaponsLevel2",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Banshee,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Battlecruiser,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Battlecruiser,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingAir,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingGround,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Liberator,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LiberatorSieged,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(5),
+            },
+            UpgradeEffect {
+                unit: Base::VikingGround,
+                field: UpgradeField::Bonus(0, Flag::Mechanical),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANSHIPWEAPONSLEVEL3: Upgrade = Upgrade {
+        name: "TerranShipWeaponsLevel3",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Banshee,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Battlecruiser,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Battlecruiser,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingAir,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingGround,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Liberator,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LiberatorSieged,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(5),
+            },
+            UpgradeEffect {
+                unit: Base::VikingGround,
+                field: UpgradeField::Bonus(0, Flag::Mechanical),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANSHIPARMORSLEVEL1: Upgrade = Upgrade {
+        name: "TerranShipArmorsLevel1",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Banshee,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Battlecruiser,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Medivac,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Raven,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingGround,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingAir,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Liberator,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LiberatorSieged,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANSHIPARMORSLEVEL2: Upgrade = Upgrade {
+        name: "TerranShipArmorsLevel2",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Banshee,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Battlecruiser,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Medivac,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Raven,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingGround,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingAir,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Liberator,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LiberatorSieged,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANSHIPARMORSLEVEL3: Upgrade = Upgrade {
+        name: "TerranShipArmorsLevel3",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Banshee,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Battlecruiser,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Medivac,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Raven,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingGround,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingAir,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Liberator,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LiberatorSieged,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const PROTOSSGROUNDARMORSLEVEL1: Upgrade = Upgrade {
+        name: "ProtossGroundArmorsLevel1",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Probe,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Zealot,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Sentry,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Stalker,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Immortal,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::HighTemplar,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::DarkTemplar,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Archon,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Colossus,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Adept,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Disruptor,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const PROTOSSGROUNDARMORSLEVEL2: Upgrade = Upgrade {
+        name: "ProtossGroundArmorsLevel2",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Probe,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Zealot,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Sentry,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Stalker,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Immortal,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::HighTemplar,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::DarkTemplar,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Archon,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Colossus,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Adept,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Disruptor,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const PROTOSSGROUNDARMORSLEVEL3: Upgrade = Upgrade {
+        name: "ProtossGroundArmorsLevel3",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Probe,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Zealot,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Sentry,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Stalker,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Immortal,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::HighTemplar,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::DarkTemplar,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Archon,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Colossus,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Adept,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Disruptor,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const PROTOSSGROUNDWEAPONSLEVEL1: Upgrade = Upgrade {
+        name: "ProtossGroundWeaponsLevel1",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Zealot,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Sentry,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Stalker,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Immortal,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Immortal,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::DarkTemplar,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(5),
+            },
+            UpgradeEffect {
+                unit: Base::Archon,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::Colossus,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Archon,
+                field: UpgradeField::Bonus(0, Flag::Biological),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Adept,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Adept,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Colossus,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::HighTemplar,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Stalker,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const PROTOSSGROUNDWEAPONSLEVEL2: Upgrade = Upgrade {
+        name: "ProtossGroundWeaponsLevel2",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Zealot,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Sentry,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Stalker,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Immortal,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Immortal,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::DarkTemplar,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(5),
+            },
+            UpgradeEffect {
+                unit: Base::Archon,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::Colossus,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Archon,
+                field: UpgradeField::Bonus(0, Flag::Biological),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Adept,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Adept,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Colossus,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::HighTemplar,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Stalker,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const PROTOSSGROUNDWEAPONSLEVEL3: Upgrade = Upgrade {
+        name: "ProtossGroundWeaponsLevel3",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Zealot,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Sentry,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Stalker,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Immortal,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Immortal,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::DarkTemplar,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(5),
+            },
+            UpgradeEffect {
+                unit: Base::Archon,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::Colossus,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Archon,
+                field: UpgradeField::Bonus(0, Flag::Biological),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Adept,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Adept,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Colossus,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::HighTemplar,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Stalker,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const PROTOSSSHIELDSLEVEL1: Upgrade = Upgrade {
+        name: "ProtossShieldsLevel1",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Probe,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Zealot,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Sentry,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Stalker,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Immortal,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::HighTemplar,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::DarkTemplar,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Archon,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Observer,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::WarpPrism,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Colossus,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Phoenix,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VoidRay,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Carrier,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Interceptor,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Mothership,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::PhotonCannon,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Oracle,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Tempest,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Adept,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Disruptor,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::ShieldBattery,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const PROTOSSSHIELDSLEVEL2: Upgrade = Upgrade {
+        name: "ProtossShieldsLevel2",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Probe,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Zealot,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Sentry,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Stalker,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Immortal,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::HighTemplar,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::DarkTemplar,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Archon,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Observer,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::WarpPrism,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Colossus,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Phoenix,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VoidRay,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Carrier,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Interceptor,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Mothership,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::PhotonCannon,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Oracle,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Tempest,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Adept,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Disruptor,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::ShieldBattery,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const PROTOSSSHIELDSLEVEL3: Upgrade = Upgrade {
+        name: "ProtossShieldsLevel3",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Probe,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Zealot,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Sentry,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Stalker,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Immortal,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::HighTemplar,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::DarkTemplar,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Archon,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Observer,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::WarpPrism,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Colossus,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Phoenix,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VoidRay,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Carrier,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Interceptor,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Mothership,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::PhotonCannon,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Oracle,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Tempest,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Adept,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Disruptor,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::ShieldBattery,
+                field: UpgradeField::ShieldArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const PROTOSSAIRARMORSLEVEL1: Upgrade = Upgrade {
+        name: "ProtossAirArmorsLevel1",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Observer,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::WarpPrism,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Phoenix,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VoidRay,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Carrier,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Interceptor,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Mothership,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Tempest,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Oracle,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const PROTOSSAIRARMORSLEVEL2: Upgrade = Upgrade {
+        name: "ProtossAirArmorsLevel2",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Observer,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::WarpPrism,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Phoenix,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VoidRay,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Carrier,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Interceptor,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Mothership,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Tempest,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Oracle,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const PROTOSSAIRARMORSLEVEL3: Upgrade = Upgrade {
+        name: "ProtossAirArmorsLevel3",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Observer,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::WarpPrism,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Phoenix,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VoidRay,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Carrier,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Interceptor,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Mothership,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Tempest,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Oracle,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const PROTOSSAIRWEAPONSLEVEL1: Upgrade = Upgrade {
+        name: "ProtossAirWeaponsLevel1",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Phoenix,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Interceptor,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Mothership,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Tempest,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::VoidRay,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Tempest,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(4),
+            },
+            UpgradeEffect {
+                unit: Base::Tempest,
+                field: UpgradeField::Bonus(0, Flag::Massive),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+        ],
+    };
+
+    pub const PROTOSSAIRWEAPONSLEVEL2: Upgrade = Upgrade {
+        name: "ProtossAirWeaponsLevel2",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Phoenix,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Interceptor,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Mothership,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Tempest,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::VoidRay,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Tempest,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(4),
+            },
+            UpgradeEffect {
+                unit: Base::Tempest,
+                field: UpgradeField::Bonus(0, Flag::Massive),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+        ],
+    };
+
+    pub const PROTOSSAIRWEAPONSLEVEL3: Upgrade = Upgrade {
+        name: "ProtossAirWeaponsLevel3",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Phoenix,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Interceptor,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Mothership,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Tempest,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::VoidRay,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Tempest,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(4),
+            },
+            UpgradeEffect {
+                unit: Base::Tempest,
+                field: UpgradeField::Bonus(0, Flag::Massive),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+        ],
+    };
+
+    pub const ZERGGROUNDARMORSLEVEL1: Upgrade = Upgrade {
+        name: "ZergGroundArmorsLevel1",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Drone,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Zergling,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hydralisk,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Baneling,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Roach,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::RoachBurrowed,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ultralisk,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Queen,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Infestor,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::InfestorBurrowed,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Locust,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SwarmHost,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Lurker,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LurkerBurrowed,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ravager,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LocustFlying,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const ZERGGROUNDARMORSLEVEL2: Upgrade = Upgrade {
+        name: "ZergGroundArmorsLevel2",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Drone,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Zergling,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hydralisk,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Baneling,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Roach,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::RoachBurrowed,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ultralisk,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Queen,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Infestor,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::InfestorBurrowed,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Locust,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SwarmHost,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Lurker,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LurkerBurrowed,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ravager,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LocustFlying,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const ZERGGROUNDARMORSLEVEL3: Upgrade = Upgrade {
+        name: "ZergGroundArmorsLevel3",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Drone,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Zergling,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hydralisk,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Baneling,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Roach,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::RoachBurrowed,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ultralisk,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Queen,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Infestor,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::InfestorBurrowed,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Locust,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SwarmHost,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Lurker,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LurkerBurrowed,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ravager,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LocustFlying,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const ZERGFLYERARMORSLEVEL1: Upgrade = Upgrade {
+        name: "ZergFlyerArmorsLevel1",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Mutalisk,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Overlord,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Overseer,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Corruptor,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::BroodLord,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Viper,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const ZERGFLYERARMORSLEVEL2: Upgrade = Upgrade {
+        name: "ZergFlyerArmorsLevel2",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Mutalisk,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Overlord,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Overseer,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Corruptor,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::BroodLord,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Viper,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const ZERGFLYERARMORSLEVEL3: Upgrade = Upgrade {
+        name: "ZergFlyerArmorsLevel3",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Mutalisk,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Overlord,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Overseer,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Corruptor,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::BroodLord,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Viper,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const ZERGFLYERWEAPONSLEVEL1: Upgrade = Upgrade {
+        name: "ZergFlyerWeaponsLevel1",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Mutalisk,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::BroodLord,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Corruptor,
+                field: UpgradeField::Bonus(0, Flag::Massive),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Corruptor,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const ZERGFLYERWEAPONSLEVEL2: Upgrade = Upgrade {
+        name: "ZergFlyerWeaponsLevel2",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Mutalisk,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::BroodLord,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Corruptor,
+                field: UpgradeField::Bonus(0, Flag::Massive),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Corruptor,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const ZERGFLYERWEAPONSLEVEL3: Upgrade = Upgrade {
+        name: "ZergFlyerWeaponsLevel3",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Mutalisk,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::BroodLord,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Corruptor,
+                field: UpgradeField::Bonus(0, Flag::Massive),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Corruptor,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const ZERGMELEEWEAPONSLEVEL1: Upgrade = Upgrade {
+        name: "ZergMeleeWeaponsLevel1",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Zergling,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ultralisk,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::Broodling,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Baneling,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Baneling,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+        ],
+    };
+
+    pub const ZERGMELEEWEAPONSLEVEL2: Upgrade = Upgrade {
+        name: "ZergMeleeWeaponsLevel2",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Zergling,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ultralisk,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::Broodling,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Baneling,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Baneling,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+        ],
+    };
+
+    pub const ZERGMELEEWEAPONSLEVEL3: Upgrade = Upgrade {
+        name: "ZergMeleeWeaponsLevel3",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Zergling,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ultralisk,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::Broodling,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Baneling,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Baneling,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+        ],
+    };
+
+    pub const ZERGMISSILEWEAPONSLEVEL1: Upgrade = Upgrade {
+        name: "ZergMissileWeaponsLevel1",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Hydralisk,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Queen,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Queen,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Roach,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Hydralisk,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Roach,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Locust,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LocustFlying,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Locust,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Queen,
+                field: UpgradeField::Damage(2),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ravager,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::LurkerBurrowed,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::LurkerBurrowed,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const ZERGMISSILEWEAPONSLEVEL2: Upgrade = Upgrade {
+        name: "ZergMissileWeaponsLevel2",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Hydralisk,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Queen,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Queen,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Roach,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Hydralisk,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Roach,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Locust,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LocustFlying,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Locust,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Queen,
+                field: UpgradeField::Damage(2),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ravager,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::LurkerBurrowed,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::LurkerBurrowed,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const ZERGMISSILEWEAPONSLEVEL3: Upgrade = Upgrade {
+        name: "ZergMissileWeaponsLevel3",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Hydralisk,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Queen,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Queen,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Roach,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Hydralisk,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Roach,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Locust,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LocustFlying,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Locust,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Queen,
+                field: UpgradeField::Damage(2),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Ravager,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::LurkerBurrowed,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::LurkerBurrowed,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const ORGANICCARAPACE: Upgrade = Upgrade {
+        name: "OrganicCarapace",
+        faction: Faction::Zerg,
+        effects: &[UpgradeEffect {
+            unit: Base::RoachBurrowed,
+            field: UpgradeField::HullRegen,
+            op: UpgradeOp::Add,
+            value: rate!(10),
+        }],
+    };
+
+    pub const OBSERVERGRAVITICBOOSTER: Upgrade = Upgrade {
+        name: "ObserverGraviticBooster",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Observer,
+                field: UpgradeField::Speed,
+                op: UpgradeOp::Add,
+                value: rate!(1.0078),
+            },
+            UpgradeEffect {
+                unit: Base::Observer,
+                field: UpgradeField::Accel,
+                op: UpgradeOp::Add,
+                value: rate!(1.0625),
+            },
+        ],
+    };
+
+    pub const GRAVITICDRIVE: Upgrade = Upgrade {
+        name: "GraviticDrive",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::WarpPrism,
+                field: UpgradeField::Speed,
+                op: UpgradeOp::Add,
+                value: rate!(0.875),
+            },
+            UpgradeEffect {
+                unit: Base::WarpPrism,
+                field: UpgradeField::Accel,
+                op: UpgradeOp::Add,
+                value: rate!(1.125),
+            },
+        ],
+    };
+
+    pub const CHARGE: Upgrade = Upgrade {
+        name: "Charge",
+        faction: Faction::Protoss,
+        effects: &[UpgradeEffect {
+            unit: Base::Zealot,
+            field: UpgradeField::Speed,
+            op: UpgradeOp::Add,
+            value: rate!(1.125),
+        }],
+    };
+
+    pub const ZERGLINGATTACKSPEED: Upgrade = Upgrade {
+        name: "zerglingattackspeed",
+        faction: Faction::Zerg,
+        effects: &[UpgradeEffect {
+            unit: Base::Zergling,
+            field: UpgradeField::AttackRate(0),
+            op: UpgradeOp::Add,
+            value: const_real!(0.4),
+        }],
+    };
+
+    pub const ZERGLINGMOVEMENTSPEED: Upgrade = Upgrade {
+        name: "zerglingmovementspeed",
+        faction: Faction::Zerg,
+        effects: &[UpgradeEffect {
+            unit: Base::Zergling,
+            field: UpgradeField::Speed,
+            op: UpgradeOp::Add,
+            value: rate!(1.746),
+        }],
+    };
+
+    pub const HYDRALISKSPEED: Upgrade = Upgrade {
+        name: "hydraliskspeed",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Hydralisk,
+                field: UpgradeField::Range(1),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hydralisk,
+                field: UpgradeField::Speed,
+                op: UpgradeOp::Set,
+                value: rate!(2.8125),
+            },
+        ],
+    };
+
+    pub const OVERLORDSPEED: Upgrade = Upgrade {
+        name: "overlordspeed",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Overseer,
+                field: UpgradeField::Speed,
+                op: UpgradeOp::Add,
+                value: rate!(1.5),
+            },
+            UpgradeEffect {
+                unit: Base::Overlord,
+                field: UpgradeField::Speed,
+                op: UpgradeOp::Set,
+                value: rate!(1.879),
+            },
+        ],
+    };
+
+    pub const INFESTORPERISTALSIS: Upgrade = Upgrade {
+        name: "InfestorPeristalsis",
+        faction: Faction::Zerg,
+        effects: &[UpgradeEffect {
+            unit: Base::InfestorBurrowed,
+            field: UpgradeField::Speed,
+            op: UpgradeOp::Add,
+            value: rate!(1),
+        }],
+    };
+
+    pub const GLIALRECONSTITUTION: Upgrade = Upgrade {
+        name: "GlialReconstitution",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Roach,
+                field: UpgradeField::Speed,
+                op: UpgradeOp::Add,
+                value: rate!(0.75),
+            },
+            UpgradeEffect {
+                unit: Base::RoachBurrowed,
+                field: UpgradeField::Speed,
+                op: UpgradeOp::Add,
+                value: rate!(0.84),
+            },
+        ],
+    };
+
+    pub const SHIELDWALL: Upgrade = Upgrade {
+        name: "ShieldWall",
+        faction: Faction::Terran,
+        effects: &[UpgradeEffect {
+            unit: Base::Marine,
+            field: UpgradeField::HullMax,
+            op: UpgradeOp::Add,
+            value: const_real!(10),
+        }],
+    };
+
+    pub const ORACLEENERGYUPGRADE: Upgrade = Upgrade {
+        name: "OracleEnergyUpgrade",
+        faction: Faction::Protoss,
+        effects: &[UpgradeEffect {
+            unit: Base::Oracle,
+            field: UpgradeField::EnergyStart,
+            op: UpgradeOp::Add,
+            value: const_real!(25),
+        }],
+    };
+
+    pub const RESTORESHIELDS: Upgrade = Upgrade {
+        name: "RestoreShields",
+        faction: Faction::Protoss,
+        effects: &[UpgradeEffect {
+            unit: Base::Oracle,
+            field: UpgradeField::EnergyStart,
+            op: UpgradeOp::Add,
+            value: const_real!(25),
+        }],
+    };
+
+    pub const PHOENIXRANGEUPGRADE: Upgrade = Upgrade {
+        name: "PhoenixRangeUpgrade",
+        faction: Faction::Protoss,
+        effects: &[UpgradeEffect {
+            unit: Base::Phoenix,
+            field: UpgradeField::Range(0),
+            op: UpgradeOp::Add,
+            value: const_real!(2),
+        }],
+    };
+
+    pub const HYDRALISKSPEEDUPGRADE: Upgrade = Upgrade {
+        name: "HydraliskSpeedUpgrade",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Hydralisk,
+                field: UpgradeField::Speed,
+                op: UpgradeOp::Set,
+                value: rate!(2.8125),
+            },
+            UpgradeEffect {
+                unit: Base::Hydralisk,
+                field: UpgradeField::CreepSpeed,
+                op: UpgradeOp::Set,
+                value: const_real!(1.2),
+            },
+        ],
+    };
+
+    pub const INCREASEDRANGE: Upgrade = Upgrade {
+        name: "IncreasedRange",
+        faction: Faction::Protoss,
+        effects: &[UpgradeEffect {
+            unit: Base::Immortal,
+            field: UpgradeField::Range(0),
+            op: UpgradeOp::Add,
+            value: const_real!(2),
+        }],
+    };
+
+    pub const ZERGBURROWMOVE: Upgrade = Upgrade {
+        name: "ZergBurrowMove",
+        faction: Faction::Zerg,
+        effects: &[UpgradeEffect {
+            unit: Base::RoachBurrowed,
+            field: UpgradeField::Speed,
+            op: UpgradeOp::Set,
+            value: rate!(1.3984),
+        }],
+    };
+
+    pub const TEMPESTRANGEUPGRADE: Upgrade = Upgrade {
+        name: "TempestRangeUpgrade",
+        faction: Faction::Protoss,
+        effects: &[UpgradeEffect {
+            unit: Base::Tempest,
+            field: UpgradeField::Bonus(0, Flag::Massive),
+            op: UpgradeOp::Set,
+            value: const_real!(35),
+        }],
+    };
+
+    pub const ANIONPULSECRYSTALS: Upgrade = Upgrade {
+        name: "AnionPulseCrystals",
+        faction: Faction::Protoss,
+        effects: &[UpgradeEffect {
+            unit: Base::Phoenix,
+            field: UpgradeField::Range(0),
+            op: UpgradeOp::Add,
+            value: const_real!(4),
+        }],
+    };
+
+    pub const TERRANVEHICLEANDSHIPWEAPONSLEVEL1: Upgrade = Upgrade {
+        name: "TerranVehicleAndShipWeaponsLevel1",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTank,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTankSieged,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTankSieged,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTank,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Banshee,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Battlecruiser,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Battlecruiser,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingAir,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingGround,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellbat,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Hellbat,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Cyclone,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Liberator,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LiberatorSieged,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(5),
+            },
+        ],
+    };
+
+    pub const TERRANVEHICLEANDSHIPWEAPONSLEVEL2: Upgrade = Upgrade {
+        name: "TerranVehicleAndShipWeaponsLevel2",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTank,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTankSieged,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTankSieged,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTank,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Banshee,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Battlecruiser,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Battlecruiser,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingAir,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingGround,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellbat,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Hellbat,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Cyclone,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Liberator,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LiberatorSieged,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(5),
+            },
+        ],
+    };
+
+    pub const TERRANVEHICLEANDSHIPWEAPONSLEVEL3: Upgrade = Upgrade {
+        name: "TerranVehicleAndShipWeaponsLevel3",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTank,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTankSieged,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(3),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTankSieged,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTank,
+                field: UpgradeField::Bonus(0, Flag::Armored),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Banshee,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Battlecruiser,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Battlecruiser,
+                field: UpgradeField::Damage(1),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingAir,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingGround,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellbat,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Hellbat,
+                field: UpgradeField::Bonus(0, Flag::Light),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Cyclone,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(2),
+            },
+            UpgradeEffect {
+                unit: Base::Liberator,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LiberatorSieged,
+                field: UpgradeField::Damage(0),
+                op: UpgradeOp::Add,
+                value: const_real!(5),
+            },
+        ],
+    };
+
+    pub const TERRANVEHICLEANDSHIPARMORSLEVEL1: Upgrade = Upgrade {
+        name: "TerranVehicleAndShipArmorsLevel1",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTank,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTankSieged,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Banshee,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Battlecruiser,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Medivac,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Raven,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingGround,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingAir,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellbat,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::WidowMine,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::WidowMineBurrowed,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Cyclone,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Liberator,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LiberatorSieged,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANVEHICLEANDSHIPARMORSLEVEL2: Upgrade = Upgrade {
+        name: "TerranVehicleAndShipArmorsLevel2",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTank,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTankSieged,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Banshee,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Battlecruiser,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Medivac,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Raven,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingGround,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingAir,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellbat,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::WidowMine,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::WidowMineBurrowed,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Cyclone,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Liberator,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LiberatorSieged,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const TERRANVEHICLEANDSHIPARMORSLEVEL3: Upgrade = Upgrade {
+        name: "TerranVehicleAndShipArmorsLevel3",
+        faction: Faction::Terran,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Thor,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::ThorAlt,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTank,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::SiegeTankSieged,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellion,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Banshee,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Battlecruiser,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Medivac,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Raven,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingGround,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::VikingAir,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Hellbat,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::WidowMine,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::WidowMineBurrowed,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Cyclone,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::Liberator,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+            UpgradeEffect {
+                unit: Base::LiberatorSieged,
+                field: UpgradeField::HullArmor,
+                op: UpgradeOp::Add,
+                value: const_real!(1),
+            },
+        ],
+    };
+
+    pub const ADEPTPIERCINGATTACK: Upgrade = Upgrade {
+        name: "AdeptPiercingAttack",
+        faction: Faction::Protoss,
+        effects: &[UpgradeEffect {
+            unit: Base::Adept,
+            field: UpgradeField::AttackRate(0),
+            op: UpgradeOp::Add,
+            value: const_real!(0.45),
+        }],
+    };
+
+    pub const ADEPTSHIELDUPGRADE: Upgrade = Upgrade {
+        name: "AdeptShieldUpgrade",
+        faction: Faction::Protoss,
+        effects: &[UpgradeEffect {
+            unit: Base::Adept,
+            field: UpgradeField::ShieldsMax,
+            op: UpgradeOp::Add,
+            value: const_real!(50),
+        }],
+    };
+
+    pub const TEMPESTGROUNDATTACKUPGRADE: Upgrade = Upgrade {
+        name: "TempestGroundAttackUpgrade",
+        faction: Faction::Protoss,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Tempest,
+                field: UpgradeField::Bonus(1, Flag::Structure),
+                op: UpgradeOp::Add,
+                value: const_real!(40),
+            },
+            UpgradeEffect {
+                unit: Base::Tempest,
+                field: UpgradeField::Bonus(0, Flag::Structure),
+                op: UpgradeOp::Add,
+                value: const_real!(40),
+            },
+        ],
+    };
+
+    pub const AMPLIFIEDSHIELDING: Upgrade = Upgrade {
+        name: "AmplifiedShielding",
+        faction: Faction::Protoss,
+        effects: &[UpgradeEffect {
+            unit: Base::Adept,
+            field: UpgradeField::ShieldsMax,
+            op: UpgradeOp::Add,
+            value: const_real!(20),
+        }],
+    };
+
+    pub const PSIONICAMPLIFIERS: Upgrade = Upgrade {
+        name: "PsionicAmplifiers",
+        faction: Faction::Protoss,
+        effects: &[UpgradeEffect {
+            unit: Base::Adept,
+            field: UpgradeField::Range(0),
+            op: UpgradeOp::Add,
+            value: const_real!(1),
+        }],
+    };
+
+    pub const BANSHEESPEED: Upgrade = Upgrade {
+        name: "BansheeSpeed",
+        faction: Faction::Terran,
+        effects: &[UpgradeEffect {
+            unit: Base::Banshee,
+            field: UpgradeField::Speed,
+            op: UpgradeOp::Add,
+            value: rate!(1),
+        }],
+    };
+
+    pub const LIBERATORAGRANGEUPGRADE: Upgrade = Upgrade {
+        name: "LiberatorAGRangeUpgrade",
+        faction: Faction::Terran,
+        effects: &[UpgradeEffect {
+            unit: Base::LiberatorSieged,
+            field: UpgradeField::Range(0),
+            op: UpgradeOp::Add,
+            value: const_real!(2),
+        }],
+    };
+
+    pub const LURKERRANGE: Upgrade = Upgrade {
+        name: "LurkerRange",
+        faction: Faction::Zerg,
+        effects: &[UpgradeEffect {
+            unit: Base::LurkerBurrowed,
+            field: UpgradeField::Range(0),
+            op: UpgradeOp::Add,
+            value: const_real!(2),
+        }],
+    };
+
+    pub const MEDIVACINCREASESPEEDBOOST: Upgrade = Upgrade {
+        name: "MedivacIncreaseSpeedBoost",
+        faction: Faction::Terran,
+        effects: &[UpgradeEffect {
+            unit: Base::Medivac,
+            field: UpgradeField::Speed,
+            op: UpgradeOp::Set,
+            value: rate!(2.9492),
+        }],
+    };
+
+    pub const EVOLVEMUSCULARAUGMENTS: Upgrade = Upgrade {
+        name: "EvolveMuscularAugments",
+        faction: Faction::Zerg,
+        effects: &[
+            UpgradeEffect {
+                unit: Base::Hydralisk,
+                field: UpgradeField::Speed,
+                op: UpgradeOp::Add,
+                value: rate!(0.7),
+            },
+            UpgradeEffect {
+                unit: Base::Hydralisk,
+                field: UpgradeField::CreepSpeed,
+                op: UpgradeOp::Set,
+                value: const_real!(1.2391),
+            },
+        ],
+    };
+
+    pub const EVOLVEGROOVEDSPINES: Upgrade = Upgrade {
+        name: "EvolveGroovedSpines",
+        faction: Faction::Zerg,
+        effects: &[UpgradeEffect {
+            unit: Base::Hydralisk,
+            field: UpgradeField::Range(1),
+            op: UpgradeOp::Add,
+            value: const_real!(1),
+        }],
+    };
+
+    pub const MAGFIELDLAUNCHERS: Upgrade = Upgrade {
+        name: "MagFieldLaunchers",
+        faction: Faction::Terran,
+        effects: &[UpgradeEffect {
+            unit: Base::Cyclone,
+            field: UpgradeField::Range(0),
+            op: UpgradeOp::Add,
+            value: const_real!(2),
+        }],
+    };
+
+    pub const HURRICANETHRUSTERS: Upgrade = Upgrade {
+        name: "HurricaneThrusters",
+        faction: Faction::Terran,
+        effects: &[UpgradeEffect {
+            unit: Base::Cyclone,
+            field: UpgradeField::Speed,
+            op: UpgradeOp::Set,
+            value: rate!(3.375),
+        }],
+    };
+
+    pub const ALL: &[Upgrade] = &[
+        Self::BATTLECRUISERBEHEMOTHREACTOR,
+        Self::ANABOLICSYNTHESIS,
+        Self::GHOSTMOEBIUSREACTOR,
+        Self::TUNNELINGCLAWS,
+        Self::HIGHTEMPLARKHAYDARINAMULET,
+        Self::INFESTORENERGYUPGRADE,
+        Self::RAVENCORVIDREACTOR,
+        Self::REAPERSPEED,
+        Self::TERRANBUILDINGARMOR,
+        Self::CHITINOUSPLATING,
+        Self::VIKINGJOTUNBOOSTERS,
+        Self::VOIDRAYSPEEDUPGRADE,
+        Self::CENTRIFICALHOOKS,
+        Self::EXTENDEDTHERMALLANCE,
+        Self::HIGHCAPACITYBARRELS,
+        Self::HISECAUTOTRACKING,
+        Self::TERRANINFANTRYWEAPONSLEVEL1,
+        Self::TERRANINFANTRYWEAPONSLEVEL2,
+        Self::TERRANINFANTRYWEAPONSLEVEL3,
+        Self::TERRANINFANTRYARMORSLEVEL1,
+        Self::TERRANINFANTRYARMORSLEVEL2,
+        Self::TERRANINFANTRYARMORSLEVEL3,
+        Self::TERRANVEHICLEWEAPONSLEVEL1,
+        Self::TERRANVEHICLEWEAPONSLEVEL2,
+        Self::TERRANVEHICLEWEAPONSLEVEL3,
+        Self::TERRANVEHICLEARMORSLEVEL1,
+        Self::TERRANVEHICLEARMORSLEVEL2,
+        Self::TERRANVEHICLEARMORSLEVEL3,
+        Self::TERRANSHIPWEAPONSLEVEL1,
+        Self::TERRANSHIPWEAPONSLEVEL2,
+        Self::TERRANSHIPWEAPONSLEVEL3,
+        Self::TERRANSHIPARMORSLEVEL1,
+        Self::TERRANSHIPARMORSLEVEL2,
+        Self::TERRANSHIPARMORSLEVEL3,
+        Self::PROTOSSGROUNDARMORSLEVEL1,
+        Self::PROTOSSGROUNDARMORSLEVEL2,
+        Self::PROTOSSGROUNDARMORSLEVEL3,
+        Self::PROTOSSGROUNDWEAPONSLEVEL1,
+        Self::PROTOSSGROUNDWEAPONSLEVEL2,
+        Self::PROTOSSGROUNDWEAPONSLEVEL3,
+        Self::PROTOSSSHIELDSLEVEL1,
+        Self::PROTOSSSHIELDSLEVEL2,
+        Self::PROTOSSSHIELDSLEVEL3,
+        Self::PROTOSSAIRARMORSLEVEL1,
+        Self::PROTOSSAIRARMORSLEVEL2,
+        Self::PROTOSSAIRARMORSLEVEL3,
+        Self::PROTOSSAIRWEAPONSLEVEL1,
+        Self::PROTOSSAIRWEAPONSLEVEL2,
+        Self::PROTOSSAIRWEAPONSLEVEL3,
+        Self::ZERGGROUNDARMORSLEVEL1,
+        Self::ZERGGROUNDARMORSLEVEL2,
+        Self::ZERGGROUNDARMORSLEVEL3,
+        Self::ZERGFLYERARMORSLEVEL1,
+        Self::ZERGFLYERARMORSLEVEL2,
+        Self::ZERGFLYERARMORSLEVEL3,
+        Self::ZERGFLYERWEAPONSLEVEL1,
+        Self::ZERGFLYERWEAPONSLEVEL2,
+        Self::ZERGFLYERWEAPONSLEVEL3,
+        Self::ZERGMELEEWEAPONSLEVEL1,
+        Self::ZERGMELEEWEAPONSLEVEL2,
+        Self::ZERGMELEEWEAPONSLEVEL3,
+        Self::ZERGMISSILEWEAPONSLEVEL1,
+        Self::ZERGMISSILEWEAPONSLEVEL2,
+        Self::ZERGMISSILEWEAPONSLEVEL3,
+        Self::ORGANICCARAPACE,
+        Self::OBSERVERGRAVITICBOOSTER,
+        Self::GRAVITICDRIVE,
+        Self::CHARGE,
+        Self::ZERGLINGATTACKSPEED,
+        Self::ZERGLINGMOVEMENTSPEED,
+        Self::HYDRALISKSPEED,
+        Self::OVERLORDSPEED,
+        Self::INFESTORPERISTALSIS,
+        Self::GLIALRECONSTITUTION,
+        Self::SHIELDWALL,
+        Self::ORACLEENERGYUPGRADE,
+        Self::RESTORESHIELDS,
+        Self::PHOENIXRANGEUPGRADE,
+        Self::HYDRALISKSPEEDUPGRADE,
+        Self::INCREASEDRANGE,
+        Self::ZERGBURROWMOVE,
+        Self::TEMPESTRANGEUPGRADE,
+        Self::ANIONPULSECRYSTALS,
+        Self::TERRANVEHICLEANDSHIPWEAPONSLEVEL1,
+        Self::TERRANVEHICLEANDSHIPWEAPONSLEVEL2,
+        Self::TERRANVEHICLEANDSHIPWEAPONSLEVEL3,
+        Self::TERRANVEHICLEANDSHIPARMORSLEVEL1,
+        Self::TERRANVEHICLEANDSHIPARMORSLEVEL2,
+        Self::TERRANVEHICLEANDSHIPARMORSLEVEL3,
+        Self::ADEPTPIERCINGATTACK,
+        Self::ADEPTSHIELDUPGRADE,
+        Self::TEMPESTGROUNDATTACKUPGRADE,
+        Self::AMPLIFIEDSHIELDING,
+        Self::PSIONICAMPLIFIERS,
+        Self::BANSHEESPEED,
+        Self::LIBERATORAGRANGEUPGRADE,
+        Self::LURKERRANGE,
+        Self::MEDIVACINCREASESPEEDBOOST,
+        Self::EVOLVEMUSCULARAUGMENTS,
+        Self::EVOLVEGROOVEDSPINES,
+        Self::MAGFIELDLAUNCHERS,
+        Self::HURRICANETHRUSTERS,
+    ];
+}
@@ -1,4 +1,9 @@
-use crate::{const_real, unit::Unit, Real};
+use crate::{
+    const_real,
+    unit::{Base, Bonus, Faction, Flag, Unit},
+    utils::RangeInclusive,
+    Real,
+};
 
 #[derive(Debug, Clone)]
 pub enum BasicUpgrades {
@@ -8,12 +13,201 @@ pub enum BasicUpgrades {
     Zerg { melee_weapons: u8, ranged_weapons: u8, ground_armor: u8, air_weapons: u8, air_armor: u8 },
 }
 
+/// Researched upgrades and alternate passives that aren't covered by `BasicUpgrades`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Research {
+    /// Ultralisks get +2 armor
+    pub chitinous_plating: bool,
+    /// Immortals use Hardened Shields (capping each hit to 10 damage while they have shields)
+    /// instead of Barrier
+    pub hardened_shields: bool,
+}
+
 impl Default for BasicUpgrades {
     fn default() -> Self {
         Self::Generic { weapons: 0, armor: 0 }
     }
 }
 
+/// A research or armor/weapon/shield level, as generated from the upgrade catalog
+#[derive(Debug, Clone, Copy)]
+pub struct Upgrade {
+    /// The catalog id, e.g. "TerranInfantryWeaponsLevel1"
+    pub name: &'static str,
+    pub faction: Faction,
+    pub effects: &'static [UpgradeEffect],
+}
+
+/// One entry of an upgrade's `EffectArray`, resolved to the unit (and weapon) it changes
+#[derive(Debug, Clone, Copy)]
+pub struct UpgradeEffect {
+    pub unit: Base,
+    pub field: UpgradeField,
+    pub op: UpgradeOp,
+    pub value: Real,
+}
+
+/// The `Unit`/`Weapon` field an upgrade changes. Weapon fields hold the weapon's index in
+/// `Unit::weapons`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpgradeField {
+    HullMax,
+    HullArmor,
+    HullRegen,
+    ShieldsMax,
+    ShieldArmor,
+    EnergyStart,
+    EnergyMax,
+    Speed,
+    Accel,
+    CreepSpeed,
+    Damage(usize),
+    Bonus(usize, Flag),
+    Range(usize),
+    /// The weapon's `RateMultiplier`, which divides its attack speed
+    AttackRate(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpgradeOp {
+    Add,
+    Multiply,
+    Set,
+}
+
+impl UpgradeOp {
+    fn apply(self, x: Real, value: Real) -> Real {
+        match self {
+            UpgradeOp::Add => x + value,
+            UpgradeOp::Multiply => x * value,
+            UpgradeOp::Set => value,
+        }
+    }
+}
+
+impl Upgrade {
+    pub fn by_name(name: &str) -> Option<&'static Upgrade> {
+        Self::ALL.iter().find(|x| x.name == name)
+    }
+}
+
 impl Unit {
+    /// Applies the effects of `upgrade` that change this unit. Weapon effects are skipped if the
+    /// weapon was removed
+    pub fn with_upgrade(mut self, upgrade: &Upgrade) -> Self {
+        for effect in upgrade.effects.iter().filter(|x| x.unit == self.base) {
+            let (op, value) = (effect.op, effect.value);
+            let weapon = match effect.field {
+                UpgradeField::Damage(i)
+                | UpgradeField::Bonus(i, _)
+                | UpgradeField::Range(i)
+                | UpgradeField::AttackRate(i) => match self.weapons.to_mut().get_mut(i) {
+                    Some(x) => Some(x),
+                    None => continue,
+                },
+                _ => None,
+            };
+
+            match (effect.field, weapon) {
+                (UpgradeField::HullMax, _) => self.hull.max = op.apply(self.hull.max, value),
+                (UpgradeField::HullArmor, _) => self.hull.armor = op.apply(self.hull.armor, value),
+                (UpgradeField::HullRegen, _) => self.hull.regen = op.apply(self.hull.regen, value),
+                (UpgradeField::ShieldsMax, _) => {
+                    self.shields.max = op.apply(self.shields.max, value)
+                }
+                (UpgradeField::ShieldArmor, _) => {
+                    self.shields.armor = op.apply(self.shields.armor, value)
+                }
+                (UpgradeField::EnergyStart, _) => {
+                    self.energy_start = op.apply(self.energy_start, value)
+                }
+                (UpgradeField::EnergyMax, _) => self.energy_max = op.apply(self.energy_max, value),
+                (UpgradeField::Speed, _) => {
+                    self.movement.speed = op.apply(self.movement.speed, value)
+                }
+                (UpgradeField::Accel, _) => {
+                    self.movement.accel = op.apply(self.movement.accel, value)
+                }
+                (UpgradeField::CreepSpeed, _) => {
+                    self.movement.creep_speed = op.apply(self.movement.creep_speed, value)
+                }
+                (UpgradeField::Damage(_), Some(w)) => w.damage = op.apply(w.damage, value),
+                (UpgradeField::Bonus(_, flag), Some(w)) => {
+                    match w.bonuses.iter_mut().flatten().find(|x| x.vs == flag) {
+                        Some(b) => b.damage = op.apply(b.damage, value),
+                        None => {
+                            if let Some(slot) = w.bonuses.iter_mut().find(|x| x.is_none()) {
+                                *slot = Some(Bonus {
+                                    vs: flag,
+                                    damage: op.apply(const_real!(0), value),
+                                });
+                            }
+                        }
+                    }
+                }
+                (UpgradeField::Range(_), Some(w)) => {
+                    w.range = RangeInclusive::new(w.range.start(), op.apply(w.range.end(), value))
+                }
+                // the multiplier starts at 1, and the generator sums up an upgrade's additions to it
+                (UpgradeField::AttackRate(_), Some(w)) => {
+                    w.attack_speed /= op.apply(const_real!(1), value)
+                }
+                _ => unreachable!(),
+            }
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::unit::Weapon;
+
+    #[test]
+    fn combat_shields() {
+        let marine = Unit::MARINE.with_upgrade(&Upgrade::SHIELDWALL);
+        assert_eq!(Unit::MARINE.hull.max, 45);
+        assert_eq!(marine.hull.max, 55);
+    }
 
+    #[test]
+    fn glaives() {
+        let adept = Unit::ADEPT.with_upgrade(&Upgrade::ADEPTPIERCINGATTACK);
+        assert_eq!(
+            adept.weapons[0].attack_speed,
+            Unit::ADEPT.weapons[0].attack_speed / const_real!(1.45)
+        );
+    }
+
+    #[test]
+    fn weapon_level() {
+        let base = &Unit::MARAUDER.weapons[0];
+        let marauder = Unit::MARAUDER.with_upgrade(&Upgrade::TERRANINFANTRYWEAPONSLEVEL1);
+        let weapon = &marauder.weapons[0];
+        assert_eq!(weapon.damage, base.damage + 1);
+
+        let bonus = |w: &Weapon| {
+            w.bonuses
+                .iter()
+                .flatten()
+                .find(|x| x.vs == Flag::Armored)
+                .unwrap()
+                .damage
+        };
+        assert_eq!(bonus(weapon), bonus(base) + 1);
+
+        // other units' weapons aren't touched
+        let stalker = Unit::STALKER.with_upgrade(&Upgrade::TERRANINFANTRYWEAPONSLEVEL1);
+        assert_eq!(stalker.weapons[0].damage, Unit::STALKER.weapons[0].damage);
+    }
+
+    #[test]
+    fn range() {
+        let colossus = Unit::COLOSSUS.with_upgrade(&Upgrade::EXTENDEDTHERMALLANCE);
+        assert_eq!(
+            colossus.weapons[0].range.end(),
+            Unit::COLOSSUS.weapons[0].range.end() + 2
+        );
+    }
 }
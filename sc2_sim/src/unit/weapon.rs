@@ -2,6 +2,7 @@
 
 use effect::Effect;
 use rand::prelude::{Rng, StdRng};
+use strum::{Display, EnumString, IntoStaticStr};
 use unit::{Collision, Flag, Unit};
 
 use crate::*;
@@ -22,8 +23,14 @@ pub enum SearchType {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Multihit {
     Single,
+    /// Several hits that land together
     Instant(Real),
+    /// One hit per entry. Each entry is the delay after the previous hit, or after the damage point
+    /// for the first one
     TimeOffset(&'static [Real]),
+    /// One search per entry, at that distance in front of the attacker (e.g. the lurker's spines).
+    /// The offsets are carried over from the data but not simulated yet: like other splash, only
+    /// the target is hit, once
     PosOffset(&'static [Real]),
 }
 
@@ -33,7 +40,7 @@ impl Multihit {
             Multihit::Single => const_real!(1),
             Multihit::Instant(x) => x,
             Multihit::TimeOffset(x) => real!(x.len()),
-            Multihit::PosOffset(x) => real!(x.len()),
+            Multihit::PosOffset(_) => const_real!(1),
         }
     }
 }
@@ -63,6 +70,88 @@ pub enum Priority {
     LeastDist,
 }
 
+/// Extra damage against targets with the given attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bonus {
+    pub vs: Flag,
+    pub damage: Real,
+}
+
+/// The most attribute bonuses a single weapon can have
+pub const MAX_BONUSES: usize = 3;
+
+/// A property that a weapon's `TargetFilters` can require or exclude. The names match the ones used
+/// in the game data. The attributes mirror `Flag`, the rest are the unit's plane and its state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString, IntoStaticStr, Display)]
+#[repr(u32)]
+pub enum Filter {
+    Ground = 1 << 0,
+    Air = 1 << 1,
+    Light = 1 << 2,
+    Armored = 1 << 3,
+    Biological = 1 << 4,
+    Mechanical = 1 << 5,
+    Massive = 1 << 6,
+    Psionic = 1 << 7,
+    Structure = 1 << 8,
+    Heroic = 1 << 9,
+    /// Visible to the attacker, i.e. not cloaked, burrowed, or under the fog of war
+    Visible = 1 << 10,
+    Stasis = 1 << 11,
+    Invulnerable = 1 << 12,
+    /// Inside a transport or bunker
+    Hidden = 1 << 13,
+    Dead = 1 << 14,
+}
+
+impl Filter {
+    pub const ATTRIBUTES: [(Flag, Filter); 8] = [
+        (Flag::Light, Filter::Light),
+        (Flag::Armored, Filter::Armored),
+        (Flag::Biological, Filter::Biological),
+        (Flag::Mechanical, Filter::Mechanical),
+        (Flag::Massive, Filter::Massive),
+        (Flag::Psionic, Filter::Psionic),
+        (Flag::Structure, Filter::Structure),
+        (Flag::Heroic, Filter::Heroic),
+    ];
+}
+
+/// Which targets a weapon can attack. Targets must have every `require` property and none of the
+/// `exclude` ones. Both are bitsets of `Filter`s
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TargetFilters {
+    pub require: u32,
+    pub exclude: u32,
+}
+
+impl TargetFilters {
+    pub const fn new(require: &[Filter], exclude: &[Filter]) -> Self {
+        let mut result = Self {
+            require: 0,
+            exclude: 0,
+        };
+
+        let mut i = 0;
+        while i < require.len() {
+            result.require |= require[i] as u32;
+            i += 1;
+        }
+        let mut i = 0;
+        while i < exclude.len() {
+            result.exclude |= exclude[i] as u32;
+            i += 1;
+        }
+
+        result
+    }
+
+    /// `props` is a bitset of the target's `Filter`s, see `Unit::filter_props`
+    pub fn allows(&self, props: u32) -> bool {
+        props & self.require == self.require && props & self.exclude == 0
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Weapon {
     pub kind: WeaponKind,
@@ -70,19 +159,15 @@ pub struct Weapon {
     pub multihit: Multihit,
     pub search: SearchType,
     pub attack_speed: Real,
-    pub bonus_damage: Real,
-    pub bonus_vs: Option<Flag>,
+    pub bonuses: [Option<Bonus>; MAX_BONUSES],
     pub can_target: Collision,
-    /// The amount that units damage increases with each upgrade, in the form [damage_up, bonus_up].
-    /// If the unit does not deal bonus damage, bonus_up should always be 0.0
-    pub upgrade_inc: [Real; 2],
+    pub filters: TargetFilters,
     pub range: RangeInclusive<Real>,
     /// Once a unit is already attacking, this field determines how far the target unit must move
     /// before they are considered "out of range".
     pub range_slop: Real,
-    /// If the unit's orientation relative to their target is less than or equal to this amount
-    /// the unit is allowed to shoot. Typically set to 0 so the unit must look directly at their
-    /// target
+    /// The width (in degrees) of the cone centered on the unit's facing that the target must be in
+    /// for the unit to shoot. Typically set to 0 so the unit must look directly at their target
     pub arc: Real,
     /// Similar to range slop. Once a unit is already attacking, this field determines how far the
     /// target unit must move before the unit is no longer considered "looking at" the target
@@ -104,10 +189,9 @@ impl Weapon {
         kind: WeaponKind,
         multihit: Multihit,
         attack_speed: Real,
-        bonus_damage: u32,
-        bonus_vs: Option<Flag>,
+        bonuses: [Option<Bonus>; MAX_BONUSES],
         can_target: Collision,
-        upgrade_inc: [u32; 2],
+        filters: TargetFilters,
         range: RangeInclusive<Real>,
         backswing: Real,
         damage_point: Real,
@@ -117,13 +201,9 @@ impl Weapon {
             kind,
             multihit,
             attack_speed,
-            bonus_damage: Real::from_i32(bonus_damage as i32),
-            bonus_vs,
+            bonuses,
             can_target,
-            upgrade_inc: [
-                Real::from_i32(upgrade_inc[0] as i32),
-                Real::from_i32(upgrade_inc[1] as i32),
-            ],
+            filters,
             range,
             range_slop: DEFAULT_RANGE_SLOP,
             arc: const_real!(0),
@@ -141,17 +221,46 @@ impl Weapon {
         self.range.end().const_add(const_real!(0.5))
     }
 
-    pub fn can_hit(&self, target: Collision) -> bool {
-        match self.can_target {
-            Collision::None => false,
-            Collision::Both => true,
-            _ => self.can_target == target,
-        }
+    /// Range is measured from the edge of the attacker to the edge of the target.
+    ///
+    /// Minimum range is ignored for now, since armies are stacked on top of each other by default
+    /// and units like sieged tanks would never be able to fire.
+    pub fn in_range(&self, attacker: CollCircle, target: CollCircle) -> bool {
+        let max = self.range.end() + attacker.r + target.r;
+
+        attacker.pos.dist_squared(target.pos) <= max * max
+    }
+
+    /// Like `in_range`, but with `range_slop` added, for attacks that have already started
+    pub fn in_slop_range(&self, attacker: CollCircle, target: CollCircle) -> bool {
+        let max = self.range.end() + self.range_slop + attacker.r + target.r;
+
+        attacker.pos.dist_squared(target.pos) <= max * max
+    }
+
+    /// `props` is a bitset of the target's `Filter`s, see `Unit::filter_props`
+    pub fn can_hit(&self, props: u32) -> bool {
+        self.can_target != Collision::None && self.filters.allows(props)
+    }
+
+    /// The total bonus damage against `target`, from every attribute it has
+    pub fn bonus_against(&self, target: &Unit) -> Real {
+        self.bonuses
+            .iter()
+            .flatten()
+            .filter(|b| target.has_flag(b.vs))
+            .map(|b| b.damage)
+            .sum()
     }
 
-    pub fn set_upgrade(&mut self, level: Real) {
-        self.damage += self.upgrade_inc[0] * level;
-        self.bonus_damage += self.upgrade_inc[1] * level;
+    /// The largest bonus the weapon can deal against a single target
+    pub fn max_bonus(&self) -> Real {
+        self.bonuses
+            .iter()
+            .flatten()
+            .map(|b| b.damage)
+            .max()
+            .unwrap_or_default()
     }
 
     pub fn is_melee(&self) -> bool {
@@ -159,10 +268,7 @@ impl Weapon {
     }
 
     pub fn get_damage(&self, target: &Unit) -> Real {
-        let mut dmg = self.damage;
-        if self.bonus_vs.is_some_and(|b| target.has_flag(b)) {
-            dmg += self.bonus_damage;
-        }
+        let mut dmg = self.damage + self.bonus_against(target);
 
         dmg -= target.hull.armor;
 
@@ -173,6 +279,10 @@ impl Weapon {
     ///
     /// see also: `get_cooldown()`
     pub fn get_delay(&self, rng: &mut StdRng) -> Real {
+        // some weapons (e.g. the battlecruiser's) have no random delay at all
+        if self.random_delay.start() >= self.random_delay.end() {
+            return self.random_delay.start();
+        }
         Real::from_bits(
             rng.gen_range(self.random_delay.start().as_bits()..self.random_delay.end().as_bits()),
         )
@@ -184,10 +294,7 @@ impl Weapon {
     }
 
     pub fn get_shield_damage(&self, target: &Unit) -> Real {
-        let mut dmg = self.damage;
-        if self.bonus_vs.is_some_and(|b| target.has_flag(b)) {
-            dmg += self.bonus_damage;
-        }
+        let mut dmg = self.damage + self.bonus_against(target);
 
         dmg -= target.shields.armor;
 
@@ -196,7 +303,7 @@ impl Weapon {
 
     pub fn dps(&self, with_bonus: bool) -> std::ops::Range<Real> {
         let dmg = if with_bonus {
-            self.damage + self.bonus_damage
+            self.damage + self.max_bonus()
         } else {
             self.damage
         };
@@ -211,14 +318,17 @@ pub struct Projectile {
     pub timer: Real,
     pub source: u32,
     pub target: u32,
+    /// Index into the source unit's `weapons`
+    pub weapon: u8,
 }
 
 impl Projectile {
-    pub fn new(source: usize, target: usize, range: Real, time: Real) -> Self {
+    pub fn new(source: usize, target: usize, weapon: usize, range: Real, time: Real) -> Self {
         Self {
             timer: (range / DEFAULT_PROJECTILE_SPEED) + time,
             source: source as u32,
             target: target as u32,
+            weapon: weapon as u8,
         }
     }
 }
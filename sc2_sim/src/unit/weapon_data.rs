@@ -4,16 +4,15 @@ impl Weapon {
 pub const BROODLING_NEEDLECLAWS: Weapon = Weapon {
 kind: WeaponKind::Melee,
 damage: const_real!(4),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(0.1)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(0.8),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -25,20 +24,19 @@ effect: None,
 pub const LURKERBURROWED_LURKERMP: Weapon = Weapon {
 kind: WeaponKind::Hitscan,
 damage: const_real!(20),
-bonus_damage: const_real!(10),
-bonus_vs: Some(Flag::Armored),
+bonuses: [Some(Bonus { vs: Flag::Armored, damage: const_real!(10) }), None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(2), const_real!(1)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(8)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(2),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0),
 backswing: duration!(0.5),
 priority: Priority::Normal,
-multihit: Multihit::Single,
+multihit: Multihit::PosOffset(&[const_real!(1), const_real!(2), const_real!(3), const_real!(4), const_real!(5), const_real!(6), const_real!(7), const_real!(8), const_real!(9)]),
 search: SearchType::Single,
 effect: None,
 };
@@ -46,16 +44,15 @@ effect: None,
 pub const RAVAGER_RAVAGERWEAPON: Weapon = Weapon {
 kind: WeaponKind::Projectile,
 damage: const_real!(16),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(2), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(6)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.6),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.2),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -67,16 +64,15 @@ effect: None,
 pub const PROBE_PARTICLEBEAM: Weapon = Weapon {
 kind: WeaponKind::Melee,
 damage: const_real!(5),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(0), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(0.2)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.5),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -88,20 +84,19 @@ effect: None,
 pub const ZEALOT_PSIBLADES: Weapon = Weapon {
 kind: WeaponKind::Melee,
 damage: const_real!(8),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(0.1)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.2),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0),
 backswing: duration!(0.5),
 priority: Priority::Normal,
-multihit: Multihit::Instant(const_real!(2)),
+multihit: Multihit::TimeOffset(&[duration!(0), duration!(0.28)]),
 search: SearchType::Single,
 effect: None,
 };
@@ -109,16 +104,15 @@ effect: None,
 pub const HIGHTEMPLAR_HIGHTEMPLARWEAPON: Weapon = Weapon {
 kind: WeaponKind::Projectile,
 damage: const_real!(4),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(6)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.754),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -130,16 +124,15 @@ effect: None,
 pub const DARKTEMPLAR_WARPBLADES: Weapon = Weapon {
 kind: WeaponKind::Melee,
 damage: const_real!(45),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(5), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(0.1)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.694),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.361),
 backswing: duration!(1.333),
 priority: Priority::Normal,
@@ -151,16 +144,15 @@ effect: None,
 pub const INTERCEPTOR_INTERCEPTORBEAM: Weapon = Weapon {
 kind: WeaponKind::Hitscan,
 damage: const_real!(5),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Both,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(2)),
 range_slop: const_real!(1),
 arc: const_real!(19.6875),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(3),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(0), duration!(0)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -170,18 +162,17 @@ effect: None,
 };
 
 pub const ARCHON_PSIONICSHOCKWAVE: Weapon = Weapon {
-kind: WeaponKind::Melee,
+kind: WeaponKind::Hitscan,
 damage: const_real!(25),
-bonus_damage: const_real!(10),
-bonus_vs: Some(Flag::Biological),
+bonuses: [Some(Bonus { vs: Flag::Biological, damage: const_real!(10) }), None, None],
 can_target: Collision::Both,
-upgrade_inc: [const_real!(3), const_real!(1)],
+filters: TargetFilters::new(&[Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(3)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.754),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -193,16 +184,15 @@ effect: None,
 pub const PHOENIX_IONCANNONS: Weapon = Weapon {
 kind: WeaponKind::Hitscan,
 damage: const_real!(5),
-bonus_damage: const_real!(5),
-bonus_vs: Some(Flag::Light),
+bonuses: [Some(Bonus { vs: Flag::Light, damage: const_real!(5) }), None, None],
 can_target: Collision::Flying,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Air, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(5)),
 range_slop: const_real!(1),
 arc: const_real!(4.9987),
 arc_slop: const_real!(0),
 attack_speed: duration!(1.1),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -214,16 +204,15 @@ effect: None,
 pub const VOIDRAY_VOIDRAYSWARM: Weapon = Weapon {
 kind: WeaponKind::Hitscan,
 damage: const_real!(6),
-bonus_damage: const_real!(4),
-bonus_vs: Some(Flag::Armored),
+bonuses: [Some(Bonus { vs: Flag::Armored, damage: const_real!(4) }), None, None],
 can_target: Collision::Both,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(6)),
 range_slop: const_real!(2),
 arc: const_real!(0),
 arc_slop: const_real!(39.9902),
 attack_speed: duration!(0.5),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.75),
 priority: Priority::Normal,
@@ -235,16 +224,15 @@ effect: None,
 pub const STALKER_PARTICLEDISRUPTORS: Weapon = Weapon {
 kind: WeaponKind::Projectile,
 damage: const_real!(13),
-bonus_damage: const_real!(5),
-bonus_vs: Some(Flag::Armored),
+bonuses: [Some(Bonus { vs: Flag::Armored, damage: const_real!(5) }), None, None],
 can_target: Collision::Both,
-upgrade_inc: [const_real!(1), const_real!(1)],
+filters: TargetFilters::new(&[Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(6)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.87),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -254,18 +242,17 @@ effect: None,
 };
 
 pub const COLOSSUS_THERMALLANCES: Weapon = Weapon {
-kind: WeaponKind::Melee,
+kind: WeaponKind::Hitscan,
 damage: const_real!(10),
-bonus_damage: const_real!(5),
-bonus_vs: Some(Flag::Light),
+bonuses: [Some(Bonus { vs: Flag::Light, damage: const_real!(5) }), None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(1), const_real!(1)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(7)),
 range_slop: const_real!(0),
 arc: const_real!(90),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.5),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.0832),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -277,16 +264,15 @@ effect: None,
 pub const MOTHERSHIP_MOTHERSHIPBEAM: Weapon = Weapon {
 kind: WeaponKind::Hitscan,
 damage: const_real!(6),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Both,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(7)),
 range_slop: const_real!(1),
 arc: const_real!(360),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(2.21),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -298,16 +284,15 @@ effect: None,
 pub const SCV_FUSIONCUTTER: Weapon = Weapon {
 kind: WeaponKind::Melee,
 damage: const_real!(5),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(0), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(0.2)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.5),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -319,16 +304,15 @@ effect: None,
 pub const MARINE_GUASSRIFLE: Weapon = Weapon {
 kind: WeaponKind::Hitscan,
 damage: const_real!(6),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Both,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(5)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(0.8608),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.05),
 backswing: duration!(0.75),
 priority: Priority::Normal,
@@ -338,22 +322,21 @@ effect: None,
 };
 
 pub const REAPER_P38SCYTHEGUASSPISTOL: Weapon = Weapon {
-kind: WeaponKind::Melee,
+kind: WeaponKind::Hitscan,
 damage: const_real!(4),
-bonus_damage: const_real!(0),
-bonus_vs: Some(Flag::Light),
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(5)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.1),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0),
 backswing: duration!(0.75),
 priority: Priority::Normal,
-multihit: Multihit::Instant(const_real!(2)),
+multihit: Multihit::TimeOffset(&[duration!(0), duration!(0.122)]),
 search: SearchType::Single,
 effect: None,
 };
@@ -361,16 +344,15 @@ effect: None,
 pub const GHOST_C10CANISTERRIFLE: Weapon = Weapon {
 kind: WeaponKind::Hitscan,
 damage: const_real!(10),
-bonus_damage: const_real!(10),
-bonus_vs: Some(Flag::Light),
+bonuses: [Some(Bonus { vs: Flag::Light, damage: const_real!(10) }), None, None],
 can_target: Collision::Both,
-upgrade_inc: [const_real!(1), const_real!(1)],
+filters: TargetFilters::new(&[Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(6)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.5),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.083),
 backswing: duration!(1.167),
 priority: Priority::Normal,
@@ -382,16 +364,15 @@ effect: None,
 pub const SIEGETANK_90MMCANNONS: Weapon = Weapon {
 kind: WeaponKind::Hitscan,
 damage: const_real!(15),
-bonus_damage: const_real!(10),
-bonus_vs: Some(Flag::Armored),
+bonuses: [Some(Bonus { vs: Flag::Armored, damage: const_real!(10) }), None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(2), const_real!(1)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(7)),
 range_slop: const_real!(1),
 arc: const_real!(360),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.04),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -401,18 +382,17 @@ effect: None,
 };
 
 pub const SIEGETANKSIEGED_CRUCIOSHOCKCANNON: Weapon = Weapon {
-kind: WeaponKind::Melee,
+kind: WeaponKind::Hitscan,
 damage: const_real!(40),
-bonus_damage: const_real!(30),
-bonus_vs: Some(Flag::Armored),
+bonuses: [Some(Bonus { vs: Flag::Armored, damage: const_real!(30) }), None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(4), const_real!(1)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(2), const_real!(13)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(3),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -424,41 +404,39 @@ effect: None,
 pub const THOR_JAVELINMISSILELAUNCHERS: Weapon = Weapon {
 kind: WeaponKind::Hitscan,
 damage: const_real!(6),
-bonus_damage: const_real!(6),
-bonus_vs: Some(Flag::Light),
-can_target: Collision::Ground,
-upgrade_inc: [const_real!(1), const_real!(1)],
+bonuses: [Some(Bonus { vs: Flag::Light, damage: const_real!(6) }), None, None],
+can_target: Collision::Flying,
+filters: TargetFilters::new(&[Filter::Air, Filter::Visible], &[Filter::Ground, Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(10)),
 range_slop: const_real!(1),
 arc: const_real!(5.625),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(3),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
-multihit: Multihit::Instant(const_real!(4)),
+multihit: Multihit::TimeOffset(&[duration!(0), duration!(0.125), duration!(0.25), duration!(0.125)]),
 search: SearchType::Single,
 effect: None,
 };
 
 pub const THOR_THORSHAMMER: Weapon = Weapon {
-kind: WeaponKind::Melee,
+kind: WeaponKind::Hitscan,
 damage: const_real!(30),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(3), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(7)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.28),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.831),
 backswing: duration!(0.25),
 priority: Priority::Normal,
-multihit: Multihit::Instant(const_real!(2)),
+multihit: Multihit::TimeOffset(&[duration!(0), duration!(0.375)]),
 search: SearchType::Single,
 effect: None,
 };
@@ -466,16 +444,15 @@ effect: None,
 pub const THORALT_LANCEMISSILELAUNCHERS: Weapon = Weapon {
 kind: WeaponKind::Hitscan,
 damage: const_real!(25),
-bonus_damage: const_real!(10),
-bonus_vs: Some(Flag::Massive),
+bonuses: [Some(Bonus { vs: Flag::Massive, damage: const_real!(10) }), None, None],
 can_target: Collision::Flying,
-upgrade_inc: [const_real!(3), const_real!(1)],
+filters: TargetFilters::new(&[Filter::Air, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(11)),
 range_slop: const_real!(1),
 arc: const_real!(5.625),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.28),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -485,22 +462,21 @@ effect: None,
 };
 
 pub const THORALT_THORSHAMMER: Weapon = Weapon {
-kind: WeaponKind::Melee,
+kind: WeaponKind::Hitscan,
 damage: const_real!(30),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(3), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(7)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.28),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.831),
 backswing: duration!(0.25),
 priority: Priority::Normal,
-multihit: Multihit::Instant(const_real!(2)),
+multihit: Multihit::TimeOffset(&[duration!(0), duration!(0.375)]),
 search: SearchType::Single,
 effect: None,
 };
@@ -508,31 +484,29 @@ effect: None,
 pub const BANSHEE_BACKLASHROCKETS: Weapon = Weapon {
 kind: WeaponKind::Hitscan,
 damage: const_real!(12),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(6)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.25),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
-multihit: Multihit::Instant(const_real!(2)),
+multihit: Multihit::TimeOffset(&[duration!(0), duration!(0.15)]),
 search: SearchType::Single,
 effect: None,
 };
 
-pub const BATTLECRUISER_BATTLECRUISERWEAPONSWITCH: Weapon = Weapon {
-kind: WeaponKind::Hitscan,
+pub const BATTLECRUISER_ATSLASERBATTERY: Weapon = Weapon {
+kind: WeaponKind::Projectile,
 damage: const_real!(8),
-bonus_damage: const_real!(0),
-bonus_vs: None,
-can_target: Collision::Both,
-upgrade_inc: [const_real!(1), const_real!(0)],
+bonuses: [None, None, None],
+can_target: Collision::Ground,
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(6)),
 range_slop: const_real!(1),
 arc: const_real!(360),
@@ -550,10 +524,9 @@ effect: None,
 pub const BATTLECRUISER_ATALASERBATTERY: Weapon = Weapon {
 kind: WeaponKind::Projectile,
 damage: const_real!(5),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Flying,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Air, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(6)),
 range_slop: const_real!(1),
 arc: const_real!(360),
@@ -569,18 +542,17 @@ effect: None,
 };
 
 pub const VIKINGGROUND_TWINGATLINGCANNON: Weapon = Weapon {
-kind: WeaponKind::Melee,
+kind: WeaponKind::Hitscan,
 damage: const_real!(12),
-bonus_damage: const_real!(8),
-bonus_vs: Some(Flag::Mechanical),
+bonuses: [Some(Bonus { vs: Flag::Mechanical, damage: const_real!(8) }), None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(1), const_real!(1)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(6)),
 range_slop: const_real!(1),
 arc: const_real!(5.625),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -592,20 +564,19 @@ effect: None,
 pub const VIKINGAIR_LANZERTORPEDOES: Weapon = Weapon {
 kind: WeaponKind::Hitscan,
 damage: const_real!(10),
-bonus_damage: const_real!(4),
-bonus_vs: Some(Flag::Armored),
+bonuses: [Some(Bonus { vs: Flag::Armored, damage: const_real!(4) }), None, None],
 can_target: Collision::Flying,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Air, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(9)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(2),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.05),
 backswing: duration!(0.5),
 priority: Priority::Normal,
-multihit: Multihit::Instant(const_real!(2)),
+multihit: Multihit::TimeOffset(&[duration!(0), duration!(0.21)]),
 search: SearchType::Single,
 effect: None,
 };
@@ -613,16 +584,15 @@ effect: None,
 pub const DRONE_SPINES: Weapon = Weapon {
 kind: WeaponKind::Melee,
 damage: const_real!(5),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(0), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(0.2)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.5),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -634,16 +604,15 @@ effect: None,
 pub const ROACH_ROACHMELEE: Weapon = Weapon {
 kind: WeaponKind::Melee,
 damage: const_real!(16),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(2), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(0.5)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(2),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -655,16 +624,15 @@ effect: None,
 pub const ROACH_ACIDSALIVA: Weapon = Weapon {
 kind: WeaponKind::Projectile,
 damage: const_real!(16),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(2), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0.6), const_real!(4)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(2),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -676,16 +644,15 @@ effect: None,
 pub const ZERGLING_CLAWS: Weapon = Weapon {
 kind: WeaponKind::Melee,
 damage: const_real!(5),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(0.1)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(0.696),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -697,16 +664,15 @@ effect: None,
 pub const HYDRALISK_HYDRALISKMELEE: Weapon = Weapon {
 kind: WeaponKind::Melee,
 damage: const_real!(12),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(0.5)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(0.825),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.14),
 backswing: duration!(0.5607),
 priority: Priority::Normal,
@@ -718,16 +684,15 @@ effect: None,
 pub const HYDRALISK_NEEDLESPINES: Weapon = Weapon {
 kind: WeaponKind::Projectile,
 damage: const_real!(12),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Both,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(5)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(0.825),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.14),
 backswing: duration!(0.5607),
 priority: Priority::Normal,
@@ -739,16 +704,15 @@ effect: None,
 pub const MUTALISK_GLAIVEWURM: Weapon = Weapon {
 kind: WeaponKind::Projectile,
 damage: const_real!(9),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Both,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(3)),
 range_slop: const_real!(2),
 arc: const_real!(0),
 arc_slop: const_real!(45),
 attack_speed: duration!(1.5246),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -760,16 +724,15 @@ effect: None,
 pub const ULTRALISK_KAISERBLADES: Weapon = Weapon {
 kind: WeaponKind::Melee,
 damage: const_real!(35),
-bonus_damage: const_real!(0),
-bonus_vs: Some(Flag::Armored),
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(3), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(1)),
 range_slop: const_real!(1.25),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(0.86),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.3332),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -781,16 +744,15 @@ effect: None,
 pub const BANELING_VOLATILEBURST: Weapon = Weapon {
 kind: WeaponKind::Melee,
 damage: const_real!(16),
-bonus_damage: const_real!(19),
-bonus_vs: Some(Flag::Light),
+bonuses: [Some(Bonus { vs: Flag::Light, damage: const_real!(19) }), None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(0), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(0.25)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(0.833),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -802,16 +764,15 @@ effect: None,
 pub const BANELING_VOLATILEBURSTBUILDING: Weapon = Weapon {
 kind: WeaponKind::Melee,
 damage: const_real!(16),
-bonus_damage: const_real!(19),
-bonus_vs: Some(Flag::Light),
+bonuses: [Some(Bonus { vs: Flag::Light, damage: const_real!(19) }), None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(0), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(0.25)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(0.833),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -823,16 +784,15 @@ effect: None,
 pub const IMMORTAL_PHASEDISRUPTORS: Weapon = Weapon {
 kind: WeaponKind::Hitscan,
 damage: const_real!(20),
-bonus_damage: const_real!(30),
-bonus_vs: Some(Flag::Armored),
+bonuses: [Some(Bonus { vs: Flag::Armored, damage: const_real!(30) }), None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(2), const_real!(3)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(6)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.45),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -844,16 +804,15 @@ effect: None,
 pub const MARAUDER_PUNISHERGRENADES: Weapon = Weapon {
 kind: WeaponKind::Projectile,
 damage: const_real!(10),
-bonus_damage: const_real!(10),
-bonus_vs: Some(Flag::Armored),
+bonuses: [Some(Bonus { vs: Flag::Armored, damage: const_real!(10) }), None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(1), const_real!(1)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(6)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.5),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0),
 backswing: duration!(0),
 priority: Priority::Normal,
@@ -865,16 +824,15 @@ effect: None,
 pub const BROODLORD_BROODLINGSTRIKE: Weapon = Weapon {
 kind: WeaponKind::Hitscan,
 damage: const_real!(20),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(2), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(10)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(2.5),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -886,16 +844,15 @@ effect: None,
 pub const CORRUPTOR_PARASITESPORE: Weapon = Weapon {
 kind: WeaponKind::Projectile,
 damage: const_real!(14),
-bonus_damage: const_real!(6),
-bonus_vs: Some(Flag::Massive),
+bonuses: [Some(Bonus { vs: Flag::Massive, damage: const_real!(6) }), None, None],
 can_target: Collision::Flying,
-upgrade_inc: [const_real!(1), const_real!(1)],
+filters: TargetFilters::new(&[Filter::Air, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(6)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.9),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.0625),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -905,18 +862,17 @@ effect: None,
 };
 
 pub const SENTRY_DISRUPTIONBEAM: Weapon = Weapon {
-kind: WeaponKind::Melee,
+kind: WeaponKind::Hitscan,
 damage: const_real!(6),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Both,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(5)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -928,16 +884,15 @@ effect: None,
 pub const QUEEN_ACIDSPINES: Weapon = Weapon {
 kind: WeaponKind::Projectile,
 damage: const_real!(9),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Flying,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Air, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(7)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -947,43 +902,41 @@ effect: None,
 };
 
 pub const QUEEN_TALONS: Weapon = Weapon {
-kind: WeaponKind::Melee,
+kind: WeaponKind::Hitscan,
 damage: const_real!(4),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(3)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
-multihit: Multihit::Instant(const_real!(2)),
+multihit: Multihit::TimeOffset(&[duration!(0), duration!(0.3)]),
 search: SearchType::Single,
 effect: None,
 };
 
 pub const QUEEN_TALONSMISSILE: Weapon = Weapon {
-kind: WeaponKind::Melee,
+kind: WeaponKind::Hitscan,
 damage: const_real!(4),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(3), const_real!(5)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
-multihit: Multihit::Instant(const_real!(2)),
+multihit: Multihit::TimeOffset(&[duration!(0), duration!(0.3)]),
 search: SearchType::Single,
 effect: None,
 };
@@ -991,31 +944,29 @@ effect: None,
 pub const HELLION_INFERNALFLAMETHROWER: Weapon = Weapon {
 kind: WeaponKind::Hitscan,
 damage: const_real!(8),
-bonus_damage: const_real!(6),
-bonus_vs: Some(Flag::Light),
+bonuses: [Some(Bonus { vs: Flag::Light, damage: const_real!(6) }), None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(1), const_real!(1)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(5)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(2.5),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.25),
 backswing: duration!(0.75),
 priority: Priority::Normal,
-multihit: Multihit::Single,
+multihit: Multihit::PosOffset(&[const_real!(0.25), const_real!(0.5), const_real!(0.75), const_real!(1), const_real!(1.25), const_real!(1.5), const_real!(1.75), const_real!(2), const_real!(2.25), const_real!(2.5), const_real!(2.75), const_real!(3), const_real!(3.25), const_real!(3.5), const_real!(3.75), const_real!(4), const_real!(4.25), const_real!(4.5), const_real!(4.75), const_real!(5), const_real!(5.25), const_real!(5.5), const_real!(5.75), const_real!(6), const_real!(6.5)]),
 search: SearchType::Single,
 effect: None,
 };
 
 pub const ORACLE_ORACLE: Weapon = Weapon {
-kind: WeaponKind::Melee,
+kind: WeaponKind::Hitscan,
 damage: const_real!(15),
-bonus_damage: const_real!(7),
-bonus_vs: Some(Flag::Light),
+bonuses: [Some(Bonus { vs: Flag::Light, damage: const_real!(7) }), None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(0), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(4)),
 range_slop: const_real!(0),
 arc: const_real!(0),
@@ -1033,16 +984,15 @@ effect: None,
 pub const HELLBAT_HELLIONTANK: Weapon = Weapon {
 kind: WeaponKind::Hitscan,
 damage: const_real!(18),
-bonus_damage: const_real!(0),
-bonus_vs: Some(Flag::Light),
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(2), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(2)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(2),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -1054,16 +1004,15 @@ effect: None,
 pub const LOCUST_LOCUSTMPMELEE: Weapon = Weapon {
 kind: WeaponKind::Melee,
 damage: const_real!(10),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(0.5)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(0.6),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.2666),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -1075,16 +1024,15 @@ effect: None,
 pub const LOCUST_LOCUSTMP: Weapon = Weapon {
 kind: WeaponKind::Projectile,
 damage: const_real!(10),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(3)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(0.6),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.2666),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -1096,16 +1044,15 @@ effect: None,
 pub const TEMPEST_TEMPEST: Weapon = Weapon {
 kind: WeaponKind::Projectile,
 damage: const_real!(30),
-bonus_damage: const_real!(22),
-bonus_vs: Some(Flag::Massive),
+bonuses: [Some(Bonus { vs: Flag::Massive, damage: const_real!(22) }), None, None],
 can_target: Collision::Flying,
-upgrade_inc: [const_real!(3), const_real!(2)],
+filters: TargetFilters::new(&[Filter::Air, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(14)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(3.3),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -1117,16 +1064,15 @@ effect: None,
 pub const TEMPEST_TEMPESTGROUND: Weapon = Weapon {
 kind: WeaponKind::Projectile,
 damage: const_real!(40),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(4), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(10)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(3.3),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -1138,16 +1084,15 @@ effect: None,
 pub const CYCLONE_TYPHOONMISSILEPOD: Weapon = Weapon {
 kind: WeaponKind::Hitscan,
 damage: const_real!(11),
-bonus_damage: const_real!(3),
-bonus_vs: Some(Flag::Mechanical),
+bonuses: [Some(Bonus { vs: Flag::Mechanical, damage: const_real!(3) }), None, None],
 can_target: Collision::Both,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(6)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(0.812),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.05),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -1156,40 +1101,18 @@ search: SearchType::Single,
 effect: None,
 };
 
-pub const CYCLONE_CYCLONEFAKEWEAPON: Weapon = Weapon {
-kind: WeaponKind::Melee,
-damage: const_real!(20),
-bonus_damage: const_real!(0),
-bonus_vs: None,
-can_target: Collision::Both,
-upgrade_inc: [const_real!(0), const_real!(0)],
-range: RangeInclusive::new(const_real!(0), const_real!(6)),
-range_slop: const_real!(1),
-arc: const_real!(0),
-arc_slop: const_real!(11.25),
-attack_speed: duration!(1),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
-damage_point: duration!(0.167),
-backswing: duration!(0.5),
-priority: Priority::Normal,
-multihit: Multihit::Single,
-search: SearchType::Single,
-effect: None,
-};
-
 pub const LOCUSTFLYING_LOCUSTMPFLYINGSWOOPWEAPON: Weapon = Weapon {
-kind: WeaponKind::Melee,
+kind: WeaponKind::Hitscan,
 damage: const_real!(10),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(6)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(0.8),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -1199,18 +1122,17 @@ effect: None,
 };
 
 pub const ADEPT_ADEPT: Weapon = Weapon {
-kind: WeaponKind::Melee,
+kind: WeaponKind::Projectile,
 damage: const_real!(10),
-bonus_damage: const_real!(12),
-bonus_vs: Some(Flag::Light),
+bonuses: [Some(Bonus { vs: Flag::Light, damage: const_real!(12) }), None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(1), const_real!(1)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(4)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(2.25),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -1222,37 +1144,35 @@ effect: None,
 pub const LIBERATOR_LIBERATORMISSILELAUNCHERS: Weapon = Weapon {
 kind: WeaponKind::Hitscan,
 damage: const_real!(5),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Flying,
-upgrade_inc: [const_real!(1), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Air, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(5)),
 range_slop: const_real!(1),
 arc: const_real!(0),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.8),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.167),
 backswing: duration!(0.5),
 priority: Priority::Normal,
-multihit: Multihit::Instant(const_real!(2)),
+multihit: Multihit::TimeOffset(&[duration!(0.05), duration!(0.05)]),
 search: SearchType::Single,
 effect: None,
 };
 
 pub const LIBERATORSIEGED_LIBERATORAGWEAPON: Weapon = Weapon {
-kind: WeaponKind::Melee,
+kind: WeaponKind::Hitscan,
 damage: const_real!(75),
-bonus_damage: const_real!(0),
-bonus_vs: None,
+bonuses: [None, None, None],
 can_target: Collision::Ground,
-upgrade_inc: [const_real!(5), const_real!(0)],
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Structure, Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
 range: RangeInclusive::new(const_real!(0), const_real!(10)),
 range_slop: const_real!(0),
 arc: const_real!(360),
 arc_slop: const_real!(11.25),
 attack_speed: duration!(1.6),
-random_delay: RangeInclusive::new(duration!(-0.0625), duration!(1.25)),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
 damage_point: duration!(0.125),
 backswing: duration!(0.5),
 priority: Priority::Normal,
@@ -1261,4 +1181,104 @@ search: SearchType::Single,
 effect: None,
 };
 
+pub const MISSILETURRET_LONGBOLTMISSILE: Weapon = Weapon {
+kind: WeaponKind::Hitscan,
+damage: const_real!(12),
+bonuses: [None, None, None],
+can_target: Collision::Flying,
+filters: TargetFilters::new(&[Filter::Air, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
+range: RangeInclusive::new(const_real!(0), const_real!(7)),
+range_slop: const_real!(0),
+arc: const_real!(0),
+arc_slop: const_real!(11.25),
+attack_speed: duration!(0.8608),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
+damage_point: duration!(0.167),
+backswing: duration!(0.5),
+priority: Priority::Normal,
+multihit: Multihit::TimeOffset(&[duration!(0), duration!(0.1)]),
+search: SearchType::Single,
+effect: None,
+};
+
+pub const SPINECRAWLER_IMPALERTENTACLE: Weapon = Weapon {
+kind: WeaponKind::Projectile,
+damage: const_real!(25),
+bonuses: [Some(Bonus { vs: Flag::Armored, damage: const_real!(5) }), None, None],
+can_target: Collision::Ground,
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
+range: RangeInclusive::new(const_real!(0), const_real!(7)),
+range_slop: const_real!(0),
+arc: const_real!(0),
+arc_slop: const_real!(11.25),
+attack_speed: duration!(1.85),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
+damage_point: duration!(0.3332),
+backswing: duration!(0.5),
+priority: Priority::Normal,
+multihit: Multihit::Single,
+search: SearchType::Single,
+effect: None,
+};
+
+pub const SPORECRAWLER_ACIDSPEW: Weapon = Weapon {
+kind: WeaponKind::Projectile,
+damage: const_real!(15),
+bonuses: [Some(Bonus { vs: Flag::Biological, damage: const_real!(15) }), None, None],
+can_target: Collision::Flying,
+filters: TargetFilters::new(&[Filter::Air, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
+range: RangeInclusive::new(const_real!(0), const_real!(7)),
+range_slop: const_real!(0),
+arc: const_real!(0),
+arc_slop: const_real!(11.25),
+attack_speed: duration!(0.8608),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
+damage_point: duration!(0.167),
+backswing: duration!(0.5),
+priority: Priority::Normal,
+multihit: Multihit::Single,
+search: SearchType::Single,
+effect: None,
+};
+
+pub const PLANETARYFORTRESS_TWINIBIKSCANNON: Weapon = Weapon {
+kind: WeaponKind::Hitscan,
+damage: const_real!(40),
+bonuses: [None, None, None],
+can_target: Collision::Ground,
+filters: TargetFilters::new(&[Filter::Ground, Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
+range: RangeInclusive::new(const_real!(0), const_real!(6)),
+range_slop: const_real!(1),
+arc: const_real!(90),
+arc_slop: const_real!(11.25),
+attack_speed: duration!(2),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
+damage_point: duration!(0.167),
+backswing: duration!(0.5),
+priority: Priority::Normal,
+multihit: Multihit::Single,
+search: SearchType::Single,
+effect: None,
+};
+
+pub const PHOTONCANNON_PHOTONCANNON: Weapon = Weapon {
+kind: WeaponKind::Projectile,
+damage: const_real!(20),
+bonuses: [None, None, None],
+can_target: Collision::Both,
+filters: TargetFilters::new(&[Filter::Visible], &[Filter::Stasis, Filter::Dead, Filter::Hidden, Filter::Invulnerable]),
+range: RangeInclusive::new(const_real!(0), const_real!(7)),
+range_slop: const_real!(0),
+arc: const_real!(0),
+arc_slop: const_real!(11.25),
+attack_speed: duration!(1.25),
+random_delay: RangeInclusive::new(duration!(-0.0625), duration!(0.125)),
+damage_point: duration!(0.167),
+backswing: duration!(0.5),
+priority: Priority::Normal,
+multihit: Multihit::Single,
+search: SearchType::Single,
+effect: None,
+};
+
 }
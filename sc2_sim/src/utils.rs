@@ -48,6 +48,14 @@ impl Pos {
         let y = other.y - self.y;
         (x * x) + (y * y)
     }
+
+    /// True if `other` is within `range` of `self`. Safe to use on points that are far enough apart
+    /// that `dist_squared` would overflow
+    pub fn within(&self, other: Self, range: Real) -> bool {
+        let x = (other.x - self.x).abs();
+        let y = (other.y - self.y).abs();
+        x <= range && y <= range && (x * x) + (y * y) <= range * range
+    }
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -86,8 +94,45 @@ impl CollCircle {
 
     // }
 
-    pub fn collision_angle(&self, other: Self) -> Real {
-        self.pos.x
+    /// The angle (in radians) from the center of `self` to the center of `other`. Returns `None` if
+    /// the circles share a center.
+    pub fn collision_angle(&self, other: Self) -> Option<Real> {
+        let x = other.pos.x - self.pos.x;
+        let y = other.pos.y - self.pos.y;
+
+        (x != 0 || y != 0).then(|| Real::atan2(y, x))
+    }
+}
+
+/// Converts degrees to radians. `Real::to_radians` loses too much precision for arc checks
+pub fn radians(degrees: Real) -> Real {
+    real!(degrees.as_f64().to_radians())
+}
+
+/// Wraps an angle (in radians) to the range [-pi, pi]
+pub fn wrap_angle(mut angle: Real) -> Real {
+    while angle > Real::PI {
+        angle -= Real::TAU;
+    }
+    while angle < -Real::PI {
+        angle += Real::TAU;
+    }
+
+    angle
+}
+
+/// The signed shortest rotation (in radians) from `from` to `to`
+pub fn angle_diff(from: Real, to: Real) -> Real {
+    wrap_angle(to - from)
+}
+
+/// Rotates `from` towards `to` by at most `step` radians
+pub fn turn_towards(from: Real, to: Real, step: Real) -> Real {
+    let diff = angle_diff(from, to);
+    if diff.abs() <= step {
+        to
+    } else {
+        wrap_angle(from + step * diff.signum())
     }
 }
 
@@ -0,0 +1,95 @@
+use crate::{
+    army::{ActionState, Army},
+    terrain::Terrain,
+    unit::Collision,
+    *,
+};
+
+impl Army {
+    /// Updates which enemy units this army can see.
+    ///
+    /// An enemy is visible if it's within the sight radius of any friendly unit. Cloaked and
+    /// burrowed units additionally have to be within the radius of a friendly detector. With
+    /// terrain, ground units on low ground can't see up onto high ground (air units can).
+    pub(crate) fn update_vision(&mut self, enemy: &Army, terrain: Option<&Terrain>) {
+        // (position, sight, detection)
+        let viewers: Vec<(CollCircle, Real, Real)> = self
+            .units
+            .iter()
+            .enumerate()
+            .filter(|(_, u)| {
+                u.is_alive() && !matches!(u.state, ActionState::Pending(_) | ActionState::Cargo(_))
+            })
+            .map(|(h, _)| {
+                let base = self.unit_from_handle(h);
+                (self.positions[h], base.sight, base.detection())
+            })
+            .collect();
+
+        let visible = (0..enemy.units.len())
+            .map(|t| {
+                let target = &enemy.units[t];
+                let t_circle = enemy.positions[t];
+
+                let sees = |viewer: &CollCircle, range: Real| {
+                    range > 0
+                        && viewer.pos.within(t_circle.pos, range + t_circle.r)
+                        && (viewer.plane == Collision::Flying
+                            || terrain.is_none_or(|ter| ter.can_see(viewer.pos, t_circle.pos)))
+                };
+
+                viewers.iter().any(|(v, sight, _)| sees(v, *sight))
+                    && (!(target.invisible || target.burrowed)
+                        || viewers.iter().any(|(v, _, detection)| sees(v, *detection)))
+            })
+            .collect();
+
+        self.visible = visible;
+    }
+
+    /// True if this army can see the enemy unit at `handle`
+    pub fn can_see(&self, handle: usize) -> bool {
+        self.visible.get(handle).copied().unwrap_or(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::prelude::*;
+
+    use super::*;
+    use crate::unit::Unit;
+
+    fn marine_at(x: Real) -> Army {
+        let mut army = Army::default();
+        army.add_unit_at(Unit::MARINE, 1, Pos { x, y: real!(0) });
+        army
+    }
+
+    #[test]
+    fn sight_gates_targeting() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut army = marine_at(real!(0));
+        let sight = Unit::MARINE.sight;
+
+        // too far away to see
+        let mut enemy = marine_at(sight * 2);
+        army.update_vision(&enemy, None);
+        army.issue_commands(&enemy, real!(0), None, &mut rng);
+        assert!(!army.can_see(0));
+        assert_eq!(army.units[0].target, None);
+
+        enemy.positions[0].pos.x = sight - const_real!(1);
+        army.update_vision(&enemy, None);
+        army.issue_commands(&enemy, real!(0), None, &mut rng);
+        assert!(army.can_see(0));
+        assert_eq!(army.units[0].target, Some(0));
+
+        // the target is dropped once it's out of sight again
+        enemy.positions[0].pos.x = sight * 2;
+        army.update_vision(&enemy, None);
+        army.issue_commands(&enemy, real!(0), None, &mut rng);
+        assert!(!army.can_see(0));
+        assert_eq!(army.units[0].target, None);
+    }
+}
@@ -1,32 +1,182 @@
-use std::{fs::File, io::Write};
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, ExitCode, Stdio},
+};
 
-use parser::{init_units, Tag, UNIT_MAP};
-use quanta::Clock;
+use parser::{set_mods_root, set_user_mods, Tag};
 use sc2_xml::*;
-use write::{write_units, write_weapons};
+use write::{write_abilities, write_units, write_upgrades, write_weapons};
 
-pub fn main() {
-    let clock = Clock::new();
-    let now = clock.now();
-    let all_units = &UNIT_MAP;
-    let stalker = all_units.get("BroodlingDefault").unwrap();
-    dbg!(&stalker.attrs);
-    let units = mp_units();
-    let output = write_units(&units);
+const USAGE: &str = "\
+Generates sc2_sim's data tables from the game's xml data
 
-    let mut file = File::create("unit_data.rs").unwrap();
-    file.write_all(output.as_bytes()).unwrap();
+Usage: sc2_xml [OPTIONS] [MOD_DIR]...
 
+Arguments:
+  [MOD_DIR]...           Extra mods (e.g. balance test mods) layered on top of the game's data,
+                         in order
 
-    let output = write_weapons(&units);
+Options:
+      --mods <DIR>       Folder containing core.sc2mod, liberty.sc2mod, ...
+                         [default: <sc2_xml crate>/../mods]
+  -o, --out <DIR>        Where the tables are written
+                         [default: <sc2_xml crate>/../sc2_sim/src/unit]
+  -t, --tables <LIST>    Comma separated tables to generate: units, weapons, upgrades,
+                         abilities
+                         [default: all of them]
+      --check            Don't write anything, fail if the existing tables are out of date
+  -h, --help             Print this message
+";
 
-    let mut file = File::create("weapon_data.rs").unwrap();
-    file.write_all(output.as_bytes()).unwrap();
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Table {
+    Units,
+    Weapons,
+    Upgrades,
+    Abilities,
+}
+
+impl Table {
+    const ALL: [Table; 4] = [
+        Table::Units,
+        Table::Weapons,
+        Table::Upgrades,
+        Table::Abilities,
+    ];
+
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "units" => Some(Table::Units),
+            "weapons" => Some(Table::Weapons),
+            "upgrades" => Some(Table::Upgrades),
+            "abilities" => Some(Table::Abilities),
+            _ => None,
+        }
+    }
+
+    fn file_name(self) -> &'static str {
+        match self {
+            Table::Units => "unit_data.rs",
+            Table::Weapons => "weapon_data.rs",
+            Table::Upgrades => "upgrade_data.rs",
+            Table::Abilities => "abil_data.rs",
+        }
+    }
+
+    fn generate(self, units: &[(&'static &'static str, &'static Tag)]) -> String {
+        match self {
+            Table::Units => write_units(units),
+            Table::Weapons => write_weapons(units),
+            Table::Upgrades => write_upgrades(units),
+            Table::Abilities => write_abilities(units),
+        }
+    }
+}
+
+struct Args {
+    out: PathBuf,
+    tables: Vec<Table>,
+    check: bool,
+}
+
+/// Parses the command line and hands the mod folders over to the parser. `Ok(None)` means there's
+/// nothing left to do (e.g. `--help`)
+fn parse_args() -> Result<Option<Args>, String> {
+    let mut args = std::env::args().skip(1);
+    let mut out = PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/../sc2_sim/src/unit"));
+    let mut tables = Table::ALL.to_vec();
+    let mut check = false;
+    let mut user_mods = Vec::new();
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("missing value for {arg}"));
+        match arg.as_str() {
+            "--mods" => set_mods_root(PathBuf::from(value()?)).map_err(|e| e.to_string())?,
+            "-o" | "--out" => out = PathBuf::from(value()?),
+            "-t" | "--tables" => {
+                tables = value()?
+                    .split(',')
+                    .map(|x| Table::parse(x.trim()).ok_or(format!("unknown table {x}")))
+                    .collect::<Result<_, _>>()?;
+            }
+            "--check" => check = true,
+            "-h" | "--help" => {
+                print!("{USAGE}");
+                return Ok(None);
+            }
+            x if x.starts_with('-') => return Err(format!("unknown option {x}")),
+            _ => user_mods.push(PathBuf::from(arg)),
+        }
+    }
+
+    set_user_mods(user_mods).map_err(|e| e.to_string())?;
+
+    Ok(Some(Args { out, tables, check }))
+}
+
+/// Runs `src` through rustfmt so that formatting doesn't matter when checking for stale tables.
+/// Falls back to the unformatted source if rustfmt isn't available
+fn normalize(src: &str) -> String {
+    let child = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn();
+
+    let Ok(mut child) = child else {
+        return src.to_owned();
+    };
+    let mut stdin = child.stdin.take().unwrap();
+    stdin.write_all(src.as_bytes()).unwrap();
+    drop(stdin);
+
+    match child.wait_with_output() {
+        Ok(x) if x.status.success() => String::from_utf8(x.stdout).unwrap(),
+        _ => src.to_owned(),
+    }
+}
+
+fn is_stale(path: &Path, generated: &str) -> bool {
+    std::fs::read_to_string(path).map_or(true, |x| normalize(&x) != normalize(generated))
+}
+
+pub fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(Some(x)) => x,
+        Ok(None) => return ExitCode::SUCCESS,
+        Err(e) => {
+            eprint!("error: {e}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut units = mp_units();
+    units.extend(get_structures());
 
-    let dur = now.elapsed();
-    dbg!(dur);
+    let mut stale = false;
+    for table in args.tables {
+        let output = table.generate(&units);
+        let path = args.out.join(table.file_name());
 
+        if args.check {
+            if is_stale(&path, &output) {
+                eprintln!("{} is out of date", path.display());
+                stale = true;
+            }
+        } else {
+            std::fs::create_dir_all(&args.out)
+                .unwrap_or_else(|e| panic!("could not create {}: {e}", args.out.display()));
+            std::fs::write(&path, output)
+                .unwrap_or_else(|e| panic!("could not write {}: {e}", path.display()));
+            eprintln!("wrote {}", path.display());
+        }
+    }
 
-    // let val = all_units.get("Ultralisk").unwrap();
-    // dbg!(val);
+    if stale {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
 }
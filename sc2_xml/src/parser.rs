@@ -1,13 +1,15 @@
 #![allow(unused_must_use)]
 #![allow(clippy::single_match)]
 
-use std::ops::Deref;
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    sync::{LazyLock, OnceLock},
+};
 
 use roxmltree::{Children, Document, Node};
 
-use paste::paste;
 // use sc2_sim::{unit::*, *};
-use static_init::dynamic;
 
 use crate::Map;
 
@@ -15,90 +17,66 @@ use crate::Map;
 //                                        Data Definitions                                        //
 // ---------------------------------------------------------------------------------------------- //
 
-/// This minor abomination is necessary because .sc2mod files use a "versioning" system where only
-/// subsequent changes are recorded. Thus, we need all the prior changes in all the prior files.
-/// The files are grouped by data-type (e.g. weapondata, unitdata, moverdata). WoL, HotS, and LotV
-/// are the game's 3 expansions, and Multi is the game's current balance patch.
+/// .sc2mod files use a "versioning" system where only subsequent changes are recorded. Thus, we
+/// need all the prior changes in all the prior files. The files are grouped by data-type (e.g.
+/// weapondata, unitdata, moverdata). Core holds the engine's class defaults and base templates,
+/// WoL, HotS, and LotV are the game's 3 expansions, and Multi is the game's current balance patch.
 ///
-/// This macro includes those files as static strings, creates roxmltree::Documents from them, and
-/// creates Maps for each that link the top level ids to their corresponding nodes (if present) in
-/// the form `[Option<Node<'static, 'static>>; 4]`, where each index refers to the WoL, HotS, LotV,
-/// and Multi data in that order.
-macro_rules! include_data {
-    ($x: ident, $y: literal) => {
-        paste!(
-            pub const [<_ $x _WOL>]: &str = include_str!(concat!(r"..\..\mods\liberty.sc2mod\base.sc2data\gamedata\", $y, ".xml"));
-            pub const [<_ $x _HOTS>]: &str = include_str!(concat!(r"..\..\mods\swarm.sc2mod\base.sc2data\gamedata\", $y, ".xml"));
-            pub const [<_ $x _LOTV>]: &str = include_str!(concat!(r"..\..\mods\void.sc2mod\base.sc2data\gamedata\", $y, ".xml"));
-            pub const [<_ $x _MULTI>]: &str = include_str!(concat!(r"..\..\mods\voidmulti.sc2mod\base.sc2data\gamedata\", $y, ".xml"));
-
-            #[dynamic]
-            static [<$x _WOL>]: Document<'static> = Document::parse([<_ $x _WOL>]).unwrap();
-            #[dynamic]
-            static [<$x _HOTS>]: Document<'static> = Document::parse([<_ $x _HOTS>]).unwrap();
-            #[dynamic]
-            static [<$x _LOTV>]: Document<'static> = Document::parse([<_ $x _LOTV>]).unwrap();
-            #[dynamic]
-            static [<$x _MULTI>]: Document<'static> = Document::parse([<_ $x _MULTI>]).unwrap();
-
-            #[dynamic]
-            static [<$x S>]: Map<&'static str, [Option<Node<'static, 'static>>; 4]> = {
-                let mut result: Map<&str, [Option<Node<'_, '_>>; 4]> = Map::default();
-                // first one needs to be .deref() for type inference to work properly
-                for (i, doc) in [[<$x _WOL>].deref(), &[<$x _HOTS>], &[<$x _LOTV>], &[<$x _MULTI>]].into_iter().enumerate() {
-                    // skip over the <Catalog> that contains everything
-                    for node in doc.root().children().next().unwrap().children() {
-                        if let Some(id) = node.attribute("id") {
-                            if let Some(v) = result.get_mut(id) {
-                                v[i] = Some(node);
-                            } else {
-                                let mut v = [None, None, None, None];
-                                v[i] = Some(node);
-                                result.insert(id, v);
-                            }
-                        }
-                    }
-                }
-                result
-            };
-        );
-    }
+/// These are the game's mods in load order, relative to the mods root (see `set_mods_root`)
+const GAME_MODS: [(Layer, &str); 5] = [
+    (Layer::Core, "core.sc2mod"),
+    (Layer::Liberty, "liberty.sc2mod"),
+    (Layer::Swarm, "swarm.sc2mod"),
+    (Layer::Void, "void.sc2mod"),
+    (Layer::VoidMulti, "voidmulti.sc2mod"),
+];
+
+// lazy so that the mod folders can be set at runtime before the catalogs are built
+pub static UNIT_MAP: LazyLock<Map<&'static str, Tag>> = LazyLock::new(init_units);
+pub static WEAPON_MAP: LazyLock<Map<&'static str, Tag>> = LazyLock::new(init_weapons);
+pub static EFFECT_MAP: LazyLock<Map<&'static str, Tag>> = LazyLock::new(init_effects);
+pub static ABIL_MAP: LazyLock<Map<&'static str, Tag>> = LazyLock::new(init_abils);
+pub static UPGRADE_MAP: LazyLock<Map<&'static str, Tag>> = LazyLock::new(init_upgrades);
+pub static MOVERS_MAP: LazyLock<Map<&'static str, Tag>> = LazyLock::new(init_movers);
+
+/// The data layer a value was set in, in load order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Layer {
+    /// Defaults hard-coded in the engine, see `ENGINE_DEFAULTS`
+    Engine,
+    /// core.sc2mod
+    Core,
+    /// liberty.sc2mod (WoL)
+    Liberty,
+    /// swarm.sc2mod (HotS)
+    Swarm,
+    /// void.sc2mod (LotV)
+    Void,
+    /// voidmulti.sc2mod (current balance patch)
+    VoidMulti,
+    /// A mod given to `set_user_mods`, by position
+    User(u8),
 }
 
-include_data!(UNIT, "unitdata");
-include_data!(WEAPON, "weapondata");
-include_data!(EFFECT, "effectdata");
-include_data!(ABIL, "abildata");
-include_data!(UPGRADE, "upgradedata");
-include_data!(MOVER, "moverdata");
-
-#[dynamic]
-pub static UNIT_MAP: Map<&'static str, Tag> = init_units();
-#[dynamic]
-pub static WEAPON_MAP: Map<&'static str, Tag> = init_weapons();
-#[dynamic]
-pub static EFFECT_MAP: Map<&'static str, Tag> = init_effects();
-#[dynamic(lazy)]
-pub static ABIL_MAP: Map<&'static str, Tag> = init_abils();
-#[dynamic]
-pub static UPGRADE_MAP: Map<&'static str, Tag> = init_upgrades();
-#[dynamic]
-pub static MOVERS_MAP: Map<&'static str, Tag> = init_movers();
-
 #[derive(Debug, Default, Clone)]
 pub struct Tag {
     pub kind: &'static str,
     pub attrs: Map<&'static str, &'static str>,
     pub children: Map<&'static str, Tag>,
+    /// Every value each attribute has had, oldest first, with the layer that set it. The last
+    /// entry is the current value. Values inherited from a parent or class default keep the layer
+    /// they were set in
+    pub history: Map<&'static str, Vec<(Layer, &'static str)>>,
 }
 
 impl Tag {
-    fn new(kind: &'static str, attrs: Map<&'static str, &'static str>) -> Self {
-        Self {
+    fn new(kind: &'static str, attrs: Map<&'static str, &'static str>, layer: Layer) -> Self {
+        let mut tag = Self {
             kind,
-            attrs,
-            children: Default::default(),
-        }
+            ..Default::default()
+        };
+        update_attrs(&attrs, &mut tag, layer);
+        tag
     }
 
     pub fn val_of(&self, child: &str) -> &'static str {
@@ -120,26 +98,74 @@ impl Tag {
     pub fn id(&self) -> &'static str {
         self.attrs["id"]
     }
+
+    /// The layer that last set `attr`
+    pub fn layer_of(&self, attr: &str) -> Option<Layer> {
+        self.history.get(attr)?.last().map(|x| x.0)
+    }
+
+    /// The layer that last set `value` of `child`, e.g. `weapon.child_layer("Range")`
+    pub fn child_layer(&self, child: &str) -> Option<Layer> {
+        self.children.get(child)?.layer_of("value")
+    }
+
+    /// RECURSIVE
+    ///
+    /// The latest layer that set anything in this tag or its children
+    pub fn last_layer(&self) -> Option<Layer> {
+        self.history
+            .values()
+            .filter_map(|x| x.last().map(|y| y.0))
+            .chain(self.children.values().filter_map(|x| x.last_layer()))
+            .max()
+    }
+
+    /// RECURSIVE
+    ///
+    /// Flattens the history of every attribute in this tag and its children, keyed by their path
+    /// (e.g. `Cost.Vespene.value`). Mostly useful for dumping how a unit's stats changed over the
+    /// game's patches
+    pub fn provenance(&self) -> Vec<(String, &[(Layer, &'static str)])> {
+        let mut result: Vec<(String, &[(Layer, &'static str)])> = self
+            .history
+            .iter()
+            .map(|(attr, hist)| (attr.to_string(), hist.as_slice()))
+            .collect();
+
+        for (name, child) in &self.children {
+            result.extend(
+                child
+                    .provenance()
+                    .into_iter()
+                    .map(|(path, hist)| (format!("{name}.{path}"), hist)),
+            );
+        }
+
+        result
+    }
 }
 
 /// RECURSIVE
 ///
 /// Traverses child nodes and populates their map entries. Recurses when encoutering a nested structure or array,
 /// returns on leaves and non-nested arrays.
-fn init_children(children: Children<'static, 'static>, map: &mut Map<&'static str, Tag>) {
+fn init_children(
+    children: Children<'static, 'static>,
+    map: &mut Map<&'static str, Tag>,
+    layer: Layer,
+) {
     for child in children.filter(|x| x.is_element()) {
         let name = child.tag_name().name();
         let attrs: Map<&str, &str> = child.attributes().map(|x| (x.name(), x.value())).collect();
 
         // There are some arrays that don't align to these conditions, but I don't need the data
         // from them
-        if child.tag_name().name().ends_with("Array") || name == "Flags" || name == "Attributes" || name == "CostResource" || name == "Collide" {
+        if child.tag_name().name().ends_with("Array") || name == "Flags" || name == "Attributes" || name == "CostResource" || name == "Collide" || name == "AttributeBonus" || name == "Options" {
             // `entry` acts as the "container" for the array, the elements are stored in its
             // `children` map
             let entry = map.entry(name).or_insert(Tag {
                 kind: name,
-                attrs: Default::default(),
-                children: Default::default(),
+                ..Default::default()
             });
             let elements = &mut entry.children;
 
@@ -151,21 +177,15 @@ fn init_children(children: Children<'static, 'static>, map: &mut Map<&'static st
                         // If the numeric index doesn't exist in the map, we insert and use the
                         // numeric index is used as a key since there isn't really an alternative
                         if elements.get_index_entry(x).is_none() {
-                            elements.insert(
-                                idx,
-                                Tag {
-                                    kind: name,
-                                    attrs,
-                                    children: Default::default(),
-                                },
-                            );
+                            elements.insert(idx, Tag::new(name, attrs, layer));
                         } else {
-                            update_attrs(&attrs, &mut elements[x].attrs)
+                            update_attrs(&attrs, &mut elements[x], layer)
                         }
                         if child.has_children() {
                             init_children(
                                 child.children(),
                                 &mut elements.get_index_mut(x).unwrap().1.children,
+                                layer,
                             )
                         }
                     }
@@ -173,10 +193,10 @@ fn init_children(children: Children<'static, 'static>, map: &mut Map<&'static st
                     Err(_) => {
                         let entry = elements
                             .entry(idx)
-                            .and_modify(|x| update_attrs(&attrs, &mut x.attrs))
-                            .or_insert(Tag::new(idx, attrs));
+                            .and_modify(|x| update_attrs(&attrs, x, layer))
+                            .or_insert(Tag::new(idx, attrs, layer));
                         if child.has_children() {
-                            init_children(child.children(), &mut entry.children)
+                            init_children(child.children(), &mut entry.children, layer)
                         }
                     }
                 }
@@ -184,20 +204,20 @@ fn init_children(children: Children<'static, 'static>, map: &mut Map<&'static st
                 // the extra bit of nonsense is because we need &'static str and this is more
                 // "robust" than defining a const array for number -> str conversion. The leak
                 // hardly matters for such a short running program
-                elements.insert(
+                let (idx, _) = elements.insert_full(
                     elements.len().to_string().leak(),
-                    Tag {
-                        kind: name,
-                        attrs,
-                        children: Default::default(),
-                    },
+                    Tag::new(name, attrs, layer),
                 );
+                // e.g. a morph's stages, whose durations are nested in each stage
+                if child.has_children() {
+                    init_children(child.children(), &mut elements[idx].children, layer)
+                }
             }
         } else if !child.has_children() {
             map
                 .entry(name)
-                .and_modify(|x| update_attrs(&attrs, &mut x.attrs))
-                .or_insert(Tag::new(name, attrs));
+                .and_modify(|x| update_attrs(&attrs, x, layer))
+                .or_insert(Tag::new(name, attrs, layer));
             // if let Some(tag) = map.get_mut(name) {
             //     update_attrs(&attrs, &mut tag.attrs)
             // } else {
@@ -232,287 +252,402 @@ fn init_children(children: Children<'static, 'static>, map: &mut Map<&'static st
 
             let entry = map
                 .entry(name)
-                .and_modify(|x| update_attrs(&attrs, &mut x.attrs))
-                .or_insert(Tag::new(name, attrs));
+                .and_modify(|x| update_attrs(&attrs, x, layer))
+                .or_insert(Tag::new(name, attrs, layer));
 
-            init_children(child.children(), &mut entry.children)
+            init_children(child.children(), &mut entry.children, layer)
         }
     }
 }
 
-fn update_attrs(src: &Map<&'static str, &'static str>, dst: &mut Map<&'static str, &'static str>) {
+fn update_attrs(src: &Map<&'static str, &'static str>, dst: &mut Tag, layer: Layer) {
     for (k, v) in src {
-        dst.insert(k, v);
+        dst.attrs.insert(k, v);
+        dst.history.entry(k).or_default().push((layer, v));
     }
 }
 
 pub fn init_units() -> Map<&'static str, Tag> {
-    let mut map: Map<&'static str, Tag> = Map::default();
+    init_catalog("unitdata")
+}
 
-    for doc in [UNIT_WOL.deref(), &UNIT_HOTS, &UNIT_LOTV, &UNIT_MULTI] {
-        for node in doc.root().children().next().unwrap().children() {
-            let id = node.attribute("id");
-            let attrs: Map<&str, &str> = node.attributes().map(|x| (x.name(), x.value())).collect();
-            if id.is_none() {
-                continue;
-            }
+pub fn init_weapons() -> Map<&'static str, Tag> {
+    init_catalog("weapondata")
+}
 
-            let id = id.unwrap();
-            if let Some(t) = map.get_mut(id) {
-                update_attrs(&attrs, &mut t.attrs)
-            } else {
-                map.insert(id, Tag::new(node.tag_name().name(), attrs));
-            }
+pub fn init_effects() -> Map<&'static str, Tag> {
+    init_catalog("effectdata")
+}
 
-            let base = map.get_mut(id).unwrap();
+pub fn init_abils() -> Map<&'static str, Tag> {
+    init_catalog("abildata")
+}
 
-            init_children(node.children(), &mut base.children);
-        }
-    }
+pub fn init_upgrades() -> Map<&'static str, Tag> {
+    init_catalog("upgradedata")
+}
 
-    map
+pub fn init_movers() -> Map<&'static str, Tag> {
+    init_catalog("moverdata")
 }
 
-pub fn init_weapons() -> Map<&'static str, Tag> {
-    let mut map: Map<&'static str, Tag> = Map::default();
-
-    for doc in [
-        WEAPON_WOL.deref(),
-        &WEAPON_HOTS,
-        &WEAPON_LOTV,
-        &WEAPON_MULTI,
-    ] {
-        for node in doc.root().children().next().unwrap().children() {
-            let id = node.attribute("id");
-            let attrs: Map<&str, &str> = node.attributes().map(|x| (x.name(), x.value())).collect();
-            if id.is_none() || node.has_attribute("default") {
-                continue;
-            }
+// ---------------------------------------------------------------------------------------------- //
+//                                          Data Loading                                          //
+// ---------------------------------------------------------------------------------------------- //
 
-            let id = id.unwrap();
-            if let Some(t) = map.get_mut(id) {
-                update_attrs(&attrs, &mut t.attrs)
-            } else {
-                map.insert(id, Tag::new(node.tag_name().name(), attrs));
-            }
+static MODS_ROOT: OnceLock<PathBuf> = OnceLock::new();
+static USER_MODS: OnceLock<Vec<PathBuf>> = OnceLock::new();
 
-            let base = map.get_mut(id).unwrap();
-
-            init_children(node.children(), &mut base.children);
-
-            // base class defaults found in core.sc2mod. These only populate if they were "missing"
-            // from the xml data
-            base.children
-                .entry("DisplayEffect")
-                .or_insert(Tag::new("DisplayEffect", Map::from_iter([("value", id)])));
-            base.children
-                .entry("MinScanRange")
-                .or_insert(Tag::new("MinScanRange", Map::from_iter([("value", "5")])));
-            base.children
-                .entry("Range")
-                .or_insert(Tag::new("Range", Map::from_iter([("value", "5")])));
-            base.children
-                .entry("RangeSlop")
-                .or_insert(Tag::new("RangeSlop", Map::from_iter([("value", "1")])));
-            base.children
-                .entry("ArcSlop")
-                .or_insert(Tag::new("ArcSlop", Map::from_iter([("value", "11.25")])));
-            base.children
-                .entry("Period")
-                .or_insert(Tag::new("Period", Map::from_iter([("value", "0.8332")])));
-            base.children.entry("DamagePoint").or_insert(Tag::new(
-                "DamagePoint",
-                Map::from_iter([("value", "0.167")]),
-            ));
-            base.children
-                .entry("Backswing")
-                .or_insert(Tag::new("Backswing", Map::from_iter([("value", "0.5")])));
-            base.children.entry("RandomDelayMin").or_insert(Tag::new(
-                "RandomDelayMin",
-                Map::from_iter([("value", "-0.0625")]),
-            ));
-            base.children.entry("RandomDelayMax").or_insert(Tag::new(
-                "RandomDelayMax",
-                Map::from_iter([("value", "1.25")]),
-            ));
-            base.children
-                .entry("Effect")
-                .or_insert(Tag::new("Effect", Map::from_iter([("value", id)])));
-        }
+/// Sets the folder holding the game's mods (`core.sc2mod`, `liberty.sc2mod`, ...), which defaults
+/// to the `mods` folder of this repo. Every one of the game's mods has to be in it.
+///
+/// The catalogs read this once when they're first used, so it has to be called before that
+pub fn set_mods_root(dir: PathBuf) -> Result<(), ModsError> {
+    if let Some((_, name)) = GAME_MODS.iter().find(|(_, x)| !dir.join(x).is_dir()) {
+        return Err(ModsError::NotFound(dir.join(name)));
     }
+    MODS_ROOT.set(dir).map_err(|_| ModsError::AlreadySet)
+}
 
-    map
+/// Layers extra mods (balance test mods, a custom map's data, ...) on top of the game's own data,
+/// in order. Each dir can be a mod/map folder containing `base.sc2data`, or the `base.sc2data`
+/// folder itself. Data files a mod doesn't have are skipped.
+///
+/// Same as `set_mods_root`, this has to be called before the catalogs are used
+pub fn set_user_mods(dirs: Vec<PathBuf>) -> Result<(), ModsError> {
+    if let Some(dir) = dirs.iter().find(|x| !x.is_dir()) {
+        return Err(ModsError::NotFound(dir.clone()));
+    }
+    USER_MODS.set(dirs).map_err(|_| ModsError::AlreadySet)
 }
 
-pub fn init_effects() -> Map<&'static str, Tag> {
-    let mut map: Map<&'static str, Tag> = Map::default();
-
-    for doc in [
-        EFFECT_WOL.deref(),
-        &EFFECT_HOTS,
-        &EFFECT_LOTV,
-        &EFFECT_MULTI,
-    ] {
-        for node in doc.root().children().next().unwrap().children() {
-            let id = node.attribute("id");
-            let mut attrs: Map<&str, &str> =
-                node.attributes().map(|x| (x.name(), x.value())).collect();
-            if id.is_none() || node.has_attribute("default") {
-                continue;
-            }
+/// Why `set_mods_root` or `set_user_mods` rejected their mods
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModsError {
+    /// The dir doesn't exist (or isn't a directory)
+    NotFound(PathBuf),
+    /// The mods were already set, or the catalogs were already built without them
+    AlreadySet,
+}
 
-            attrs.insert("tagname", node.tag_name().name());
+impl fmt::Display for ModsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ModsError::NotFound(dir) => write!(f, "mod directory {} not found", dir.display()),
+            ModsError::AlreadySet => write!(f, "mods were already set"),
+        }
+    }
+}
 
-            let id = id.unwrap();
-            if let Some(t) = map.get_mut(id) {
-                update_attrs(&attrs, &mut t.attrs)
-            } else {
-                map.insert(id, Tag::new(node.tag_name().name(), attrs));
-            }
+/// Parses `file` (e.g. "unitdata") from every layer that has it, in load order. All of the game's
+/// mods need to have it, user mods don't
+fn layer_docs(file: &str) -> Vec<(Layer, &'static Document<'static>)> {
+    let root =
+        MODS_ROOT.get_or_init(|| PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/../mods")));
+    let mut result = Vec::new();
+
+    for (layer, name) in GAME_MODS {
+        let dir = root.join(name);
+        let path = data_file(&dir, file)
+            .unwrap_or_else(|| panic!("{file}.xml not found in {}", dir.display()));
+        result.push((layer, load_doc(&path)));
+    }
+
+    // the dirs were checked by `set_user_mods`
+    for (i, dir) in USER_MODS.get_or_init(Vec::new).iter().enumerate() {
+        if let Some(path) = data_file(dir, file) {
+            result.push((Layer::User(i as u8), load_doc(&path)));
+        }
+    }
 
-            let base = map.get_mut(id).unwrap();
+    result
+}
 
-            if node.tag_name().name() == "CEffectLaunchMissile" {
-                // it's either this or change all the static strs to Strings. This shouldn't cause
-                // too many problems, especially for a program that's so shortlived
-                let temp: &'static str = format!("{id}Weapon").leak();
+/// Finds `<dir>/base.sc2data/gamedata/<file>.xml`, `dir` may also be the `base.sc2data` folder
+fn data_file(dir: &Path, file: &str) -> Option<PathBuf> {
+    let data = find_entry(dir, "base.sc2data").unwrap_or_else(|| dir.to_owned());
+    find_entry(&find_entry(&data, "gamedata")?, &format!("{file}.xml"))
+}
 
-                base.children.insert(
-                    "AmmoUnit",
-                    Tag::new("AmmoUnit", Map::from_iter([("value", temp)])),
-                );
-            }
+fn load_doc(path: &Path) -> &'static Document<'static> {
+    // same deal as the leaks in `init_children`, the documents need to outlive everything
+    let text: &'static str = std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("could not read {}: {e}", path.display()))
+        .leak();
+    let doc =
+        Document::parse(text).unwrap_or_else(|e| panic!("could not parse {}: {e}", path.display()));
 
-            if let Some(&parent_class) = base.attrs.get("parent") {
-                match parent_class {
-                    "DU_WEAP" => {
-                        base.children.insert(
-                            "Kind",
-                            Tag::new("Kind", Map::from_iter([("value", "Melee")])),
-                        );
-                    }
-                    "DU_WEAP_MISSILE" => {
-                        base.children.insert(
-                            "Kind",
-                            Tag::new("Kind", Map::from_iter([("value", "Ranged")])),
-                        );
-                    }
-                    "DU_WEAP_SPLASH" => {
-                        base.children.insert(
-                            "Kind",
-                            Tag::new("Kind", Map::from_iter([("value", "Splash")])),
-                        );
-                    }
-                    _ => (),
-                };
-            }
+    Box::leak(Box::new(doc))
+}
 
-            init_children(node.children(), &mut base.children)
-        }
-    }
+/// Case insensitive lookup of `name` in `dir`, since the editor isn't consistent about it (e.g.
+/// `Base.SC2Data/GameData/UnitData.xml`)
+fn find_entry(dir: &Path, name: &str) -> Option<PathBuf> {
+    std::fs::read_dir(dir)
+        .ok()?
+        .filter_map(|x| x.ok())
+        .find(|x| x.file_name().to_string_lossy().eq_ignore_ascii_case(name))
+        .map(|x| x.path())
+}
+
+// ---------------------------------------------------------------------------------------------- //
+//                                          Inheritance                                           //
+// ---------------------------------------------------------------------------------------------- //
+
+/// Defaults the engine applies that aren't written down anywhere in core.sc2mod, as
+/// `(class, field, value)`
+const ENGINE_DEFAULTS: [(&str, &str, &str); 2] = [
+    ("CMoverMissile", "Acceleration", "3200"),
+    ("CMoverMissile", "MaxSpeed", "18.75"),
+];
 
-    map
+/// Builds a fully resolved catalog from the same data file (e.g. "unitdata") of every mod, in load
+/// order (core first, user mods last).
+///
+/// Every entry starts as a copy of its `parent=` entry, or of its class default if it has no
+/// parent. Class defaults are the `default="1"` tags without an id. They stack along the class
+/// hierarchy, which we recover from the class names: a `CEffectDamage` gets the `CEffect` defaults
+/// and then the `CEffectDamage` ones. `default="1"` tags with an id are templates that are only
+/// used as parents, but they stay in the catalog since they're regular entries otherwise.
+///
+/// Parents are resolved with all mods applied, so a later mod changing a template also changes
+/// every entry that inherits from it, just like in the editor. `##id##` is replaced by the id of
+/// the entry the value ends up in.
+fn init_catalog(file: &str) -> Map<&'static str, Tag> {
+    build_catalog(layer_docs(file))
 }
 
-pub fn init_abils() -> Map<&'static str, Tag> {
-    let mut map: Map<&'static str, Tag> = Map::default();
+/// `init_catalog` for already parsed documents, in load order
+fn build_catalog(docs: Vec<(Layer, &'static Document<'static>)>) -> Map<&'static str, Tag> {
+    let mut class_defaults: Map<&'static str, Vec<(Layer, Node<'static, 'static>)>> =
+        Map::default();
+    let mut entries: Map<&'static str, Vec<(Layer, Node<'static, 'static>)>> = Map::default();
 
-    for doc in [ABIL_WOL.deref(), &ABIL_HOTS, &ABIL_LOTV, &ABIL_MULTI] {
+    for (layer, doc) in docs {
+        // skip over the <Catalog> that contains everything
         for node in doc.root().children().next().unwrap().children() {
-            let id = node.attribute("id");
-            let mut attrs: Map<&str, &str> =
-                node.attributes().map(|x| (x.name(), x.value())).collect();
-            if id.is_none() || node.has_attribute("default") {
+            // <const> tags are named values for the editor's expressions, not catalog entries
+            if node.tag_name().name() == "const" {
                 continue;
             }
 
-            let id = id.unwrap();
-            if let Some(t) = map.get_mut(id) {
-                update_attrs(&attrs, &mut t.attrs)
-            } else {
-                map.insert(id, Tag::new(node.tag_name().name(), attrs));
+            if let Some(id) = node.attribute("id") {
+                entries.entry(id).or_default().push((layer, node));
+            } else if node.has_attribute("default") {
+                class_defaults
+                    .entry(node.tag_name().name())
+                    .or_default()
+                    .push((layer, node));
             }
-
-            let base = map.get_mut(id).unwrap();
-
-            init_children(node.children(), &mut base.children)
         }
     }
 
-    map
+    let mut resolved = Map::default();
+    for id in entries.keys() {
+        resolve_entry(id, &entries, &class_defaults, &mut resolved);
+    }
+
+    // keep the file order rather than the order the parents happened to be resolved in
+    entries
+        .keys()
+        .map(|id| {
+            let mut tag = resolved.swap_remove(id).unwrap();
+            replace_id(&mut tag, id);
+            (*id, tag)
+        })
+        .collect()
 }
 
-pub fn init_upgrades() -> Map<&'static str, Tag> {
-    let mut map: Map<&'static str, Tag> = Map::default();
-
-    for doc in [
-        UPGRADE_WOL.deref(),
-        &UPGRADE_HOTS,
-        &UPGRADE_LOTV,
-        &UPGRADE_MULTI,
-    ] {
-        for node in doc.root().children().next().unwrap().children() {
-            let id = node.attribute("id");
-            let attrs: Map<&str, &str> = node.attributes().map(|x| (x.name(), x.value())).collect();
-            if id.is_none() {
-                continue;
-            }
+/// RECURSIVE
+///
+/// Resolves the parent chain of `id` before applying the entry's own nodes on top of it
+fn resolve_entry(
+    id: &'static str,
+    entries: &Map<&'static str, Vec<(Layer, Node<'static, 'static>)>>,
+    class_defaults: &Map<&'static str, Vec<(Layer, Node<'static, 'static>)>>,
+    resolved: &mut Map<&'static str, Tag>,
+) {
+    if resolved.contains_key(id) {
+        return;
+    }
 
-            let id = id.unwrap();
-            if let Some(parent) = attrs.get("parent").and_then(|parent| map.get(parent)) {
-                let parent_children = parent.children.clone();
-                map.insert(id, Tag::new(node.tag_name().name(), attrs));
-                map.get_mut(id).unwrap().children = parent_children;
-            } else if let Some(t) = map.get_mut(id) {
-                update_attrs(&attrs, &mut t.attrs)
-            } else {
-                map.insert(id, Tag::new(node.tag_name().name(), attrs));
-            }
+    let nodes = &entries[id];
+    let kind = nodes[0].1.tag_name().name();
 
-            let base = map.get_mut(id).unwrap();
+    // later mods sometimes redeclare the parent, the last one wins
+    let parent = nodes
+        .iter()
+        .rev()
+        .find_map(|x| x.1.attribute("parent"))
+        .filter(|x| *x != id && entries.contains_key(x));
 
-            init_children(node.children(), &mut base.children)
+    let mut tag = match parent {
+        Some(parent) => {
+            resolve_entry(parent, entries, class_defaults, resolved);
+            resolved[parent].clone()
         }
+        None => class_default(kind, class_defaults),
+    };
+
+    tag.kind = kind;
+    tag.attrs = Map::default();
+    tag.history = Map::default();
+    for &(layer, node) in nodes {
+        let attrs: Map<&str, &str> = node.attributes().map(|x| (x.name(), x.value())).collect();
+        update_attrs(&attrs, &mut tag, layer);
+        init_children(node.children(), &mut tag.children, layer);
     }
 
-    map
+    resolved.insert(id, tag);
 }
 
-pub fn init_movers() -> Map<&'static str, Tag> {
-    let mut map: Map<&'static str, Tag> = Map::default();
+/// Stacks the defaults of every class `kind` derives from. Class names extend their base class'
+/// name by whole words (`CAbil` -> `CAbilEffect` -> `CAbilEffectTarget`), so `CAbilQueue` isn't
+/// mistaken for a base of `CAbilQueueable`
+fn class_default(
+    kind: &'static str,
+    class_defaults: &Map<&'static str, Vec<(Layer, Node<'static, 'static>)>>,
+) -> Tag {
+    let mut bases: Vec<_> = class_defaults
+        .iter()
+        .filter(|(class, _)| {
+            kind.strip_prefix(**class)
+                .is_some_and(|x| x.is_empty() || x.starts_with(|c: char| c.is_ascii_uppercase()))
+        })
+        .collect();
+    bases.sort_by_key(|(class, _)| class.len());
+
+    let mut tag = Tag::new(kind, Map::default(), Layer::Engine);
+    for (class, nodes) in bases {
+        for &(layer, node) in nodes {
+            init_children(node.children(), &mut tag.children, layer);
+        }
 
-    for doc in [MOVER_WOL.deref(), &MOVER_HOTS, &MOVER_LOTV, &MOVER_MULTI] {
-        for node in doc.root().children().next().unwrap().children() {
-            let id = node.attribute("id");
-            let mut attrs: Map<&str, &str> =
-                node.attributes().map(|x| (x.name(), x.value())).collect();
-            if id.is_none() || node.has_attribute("default") {
-                continue;
-            }
+        for (_, field, value) in ENGINE_DEFAULTS.iter().filter(|x| x.0 == *class) {
+            tag.children.entry(field).or_insert(Tag::new(
+                field,
+                Map::from_iter([("value", *value)]),
+                Layer::Engine,
+            ));
+        }
+    }
 
-            let id = id.unwrap();
-            if let Some(t) = map.get_mut(id) {
-                update_attrs(&attrs, &mut t.attrs)
-            } else {
-                map.insert(id, Tag::new(node.tag_name().name(), attrs));
-            }
+    tag
+}
 
-            let base = map.get_mut(id).unwrap();
+/// RECURSIVE
+///
+/// Replaces the `##id##` placeholder in every attribute value and its history. The leaks are fine
+/// for the same reason as in `init_children`
+fn replace_id(tag: &mut Tag, id: &str) {
+    let vals = tag
+        .attrs
+        .values_mut()
+        .chain(tag.history.values_mut().flatten().map(|x| &mut x.1));
+    for val in vals {
+        if val.contains("##id##") {
+            *val = val.replace("##id##", id).leak();
+        }
+    }
 
-            if node.tag_name().name() == "CMoverMissile" {
-                base.children.insert(
-                    "Acceleration",
-                    Tag::new("Acceleration", Map::from_iter([("value", "3200")])),
-                );
-                base.children.insert(
-                    "MaxSpeed",
-                    Tag::new("MaxSpeed", Map::from_iter([("value", "18.75")])),
-                );
-            }
+    for child in tag.children.values_mut() {
+        replace_id(child, id);
+    }
+}
 
-            init_children(node.children(), &mut base.children)
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc(text: &'static str) -> &'static Document<'static> {
+        Box::leak(Box::new(Document::parse(text).unwrap()))
+    }
+
+    const CORE: &str = r#"<Catalog>
+        <CWeapon default="1">
+            <Range value="1"/>
+            <Period value="2"/>
+        </CWeapon>
+        <CWeaponLegacy default="1">
+            <Arc value="0"/>
+        </CWeaponLegacy>
+        <CWeaponLegacy id="Template" default="1">
+            <Range value="5"/>
+            <Name value="Weapon/Name/##id##"/>
+        </CWeaponLegacy>
+        <CWeaponLegacy id="Gun" parent="Template">
+            <Period value="1.5"/>
+        </CWeaponLegacy>
+    </Catalog>"#;
+
+    const MULTI: &str = r#"<Catalog>
+        <CWeaponLegacy id="Gun">
+            <Range value="6"/>
+        </CWeaponLegacy>
+    </Catalog>"#;
+
+    const USER: &str = r#"<Catalog>
+        <CWeaponLegacy id="Gun">
+            <Period value="1"/>
+        </CWeaponLegacy>
+    </Catalog>"#;
+
+    #[test]
+    fn inheritance() {
+        let catalog = build_catalog(vec![(Layer::Core, doc(CORE))]);
+        let gun = &catalog["Gun"];
+
+        // the parent's own value beats the class default, which fills in the rest
+        assert_eq!(gun.val_of("Range"), "5");
+        assert_eq!(gun.val_of("Period"), "1.5");
+        assert_eq!(gun.val_of("Arc"), "0");
+        assert_eq!(gun.val_of("Name"), "Weapon/Name/Gun");
+        assert_eq!(catalog["Template"].val_of("Period"), "2");
     }
 
-    map
+    #[test]
+    fn provenance() {
+        let catalog = build_catalog(vec![
+            (Layer::Core, doc(CORE)),
+            (Layer::VoidMulti, doc(MULTI)),
+        ]);
+        let gun = &catalog["Gun"];
+
+        assert_eq!(gun.val_of("Range"), "6");
+        assert_eq!(gun.child_layer("Range"), Some(Layer::VoidMulti));
+        assert_eq!(gun.child_layer("Period"), Some(Layer::Core));
+        assert_eq!(gun.layer_of("id"), Some(Layer::VoidMulti));
+        assert_eq!(gun.last_layer(), Some(Layer::VoidMulti));
+        assert_eq!(catalog["Template"].last_layer(), Some(Layer::Core));
+
+        let provenance = gun.provenance();
+        let (_, range) = provenance
+            .iter()
+            .find(|(path, _)| path == "Range.value")
+            .unwrap();
+        // class default, then the template, then the balance patch
+        assert_eq!(
+            *range,
+            [
+                (Layer::Core, "1"),
+                (Layer::Core, "5"),
+                (Layer::VoidMulti, "6")
+            ]
+        );
+    }
+
+    #[test]
+    fn user_mod() {
+        let catalog = build_catalog(vec![
+            (Layer::Core, doc(CORE)),
+            (Layer::VoidMulti, doc(MULTI)),
+            (Layer::User(0), doc(USER)),
+        ]);
+        let gun = &catalog["Gun"];
+
+        assert_eq!(gun.val_of("Period"), "1");
+        assert_eq!(gun.child_layer("Period"), Some(Layer::User(0)));
+        assert_eq!(gun.val_of("Range"), "6");
+        assert_eq!(gun.last_layer(), Some(Layer::User(0)));
+    }
 }
@@ -3,34 +3,23 @@
 use crate::*;
 use std::fmt::Write;
 
-pub fn write_units(units: &[(&'static &'static str, &'static Tag)]) -> String {
+pub fn write_units(_units: &[(&'static &'static str, &'static Tag)]) -> String {
     let mut output = String::new();
     writeln!(
         output,
-        "use crate::{{const_real, duration, rate, unit::*,}};\n\nimpl Unit {{"
+        "use std::borrow::Cow;\n\nuse crate::{{const_real, duration, rate, unit::*,}};\n\nimpl Unit {{"
     );
 
+    let sources = unit_sources();
+    let mut names = Vec::new();
+
     // --------------------------------------- identifiers -------------------------------------- //
-    for name in VALID_UNITS {
+    for &name in VALID_UNITS.iter().chain(VALID_STRUCTURES.iter()) {
         let unit = UNIT_MAP.get(name).unwrap();
 
-        let name = match name {
-            "LurkerMP" => "Lurker",
-            "LurkerMPBurrowed" => "LurkerBurrowed",
-            "LurkerMPEgg" => "LurkerEgg",
-            "ThorAP" => "ThorAlt",
-            "VikingAssault" => "VikingGround",
-            "VikingFighter" => "VikingAir",
-            "SwarmHostMP" => "SwarmHost",
-            "LocustMP" => "Locust",
-            "LocustMPFlying" => "LocustFlying",
-            // "QueenMP" => "Queen",
-            "HellionTank" => "Hellbat",
-            "LiberatorAG" => "LiberatorSieged",
-            "BroodlingDefault" => "Broodling",
-
-            x => x,
-        };
+        let id = name;
+        let name = base_name(id);
+        names.push(name);
 
         writeln!(output, "pub const {}: Unit = Unit {{", name.to_uppercase());
         writeln!(output, "base: Base::{name},");
@@ -100,7 +89,7 @@ pub fn write_units(units: &[(&'static &'static str, &'static Tag)]) -> String {
         // --------------------------------------- health --------------------------------------- //
         writeln!(output,
             "hull: Health {{ max: const_real!({}), regen: rate!({}), delay: duration!({}), armor: const_real!({}), }},",
-            unit.try_val_of("LifeMax").unwrap_or("1"),
+            unit.val_of("LifeMax"),
             unit.try_val_of("LifeRegenRate").unwrap_or("0"),
             unit.try_val_of("LifeRegenDelay").unwrap_or("0"),
             unit.try_val_of("LifeArmor").unwrap_or("0"),
@@ -115,7 +104,7 @@ pub fn write_units(units: &[(&'static &'static str, &'static Tag)]) -> String {
 
         // -------------------------------------- movement -------------------------------------- //
         writeln!(output,
-            "movement: Movement {{ speed: rate!({}), accel: rate!({}), decel: rate!({}), turn_rate: rate!({}), lateral_accel: rate!({}), }},",
+            "movement: Movement {{ speed: rate!({}), accel: rate!({}), decel: rate!({}), turn_rate: rate!({}), lateral_accel: rate!({}), creep_speed: const_real!({}), }},",
             unit.try_val_of("Speed").unwrap_or("0"),
             unit.try_val_of("Acceleration").unwrap_or("1000"),
             unit.try_val_of("Deceleration").unwrap_or("0"),
@@ -125,37 +114,79 @@ pub fn write_units(units: &[(&'static &'static str, &'static Tag)]) -> String {
                 // "PhotonCannon" | "Colossus" | "Hellion" => "999.8437",
                 // "Cyclone" => "1440",
                 // "PlanetaryFortress" => "90",
-                _ => unit.try_val_of("TurningRate").unwrap_or("720")
+                _ => unit.val_of("TurningRate")
             },
             unit.try_val_of("LateralAcceleration").unwrap_or("0"),
+            unit.try_val_of("SpeedMultiplierCreep").unwrap_or("1"),
         );
 
         // ---------------------------------------- cost ---------------------------------------- //
+        let production = production(id, &sources, MODE_SWITCH_DEPTH);
+        let build_time = production.as_ref().map_or(0.0, |x| x.time);
+
         if let Some(cost_array) = &unit.children.get("CostResource") {
             writeln!(output,
                 "cost: Cost {{ minerals: const_real!({}), gas: const_real!({}), supply: const_real!({}), build_time: duration!({}), }},",
                 cost_array.try_val_of("Minerals").unwrap_or("0"),
                 cost_array.try_val_of("Vespene").unwrap_or("0"),
                 &unit.try_val_of("Food").map(|x| x.strip_prefix('-').unwrap_or("0")).unwrap_or("0"), // Food value is negative so we need to strip off the minus sign
-                "0.0", // TODO
+                build_time,
             );
         } else {
             writeln!(output,
-                "cost: Cost {{ minerals: const_real!(0), gas: const_real!(0), supply: const_real!(0), build_time: duration!(0), }},",
+                "cost: Cost {{ minerals: const_real!(0), gas: const_real!(0), supply: const_real!(0), build_time: duration!({build_time}), }},",
             );
         }
 
+        // ------------------------------------- production ------------------------------------- //
+        let production = production.unwrap_or_default();
+        let morph_from = production
+            .morph_from
+            .filter(|x| VALID_UNITS.contains(x) || VALID_STRUCTURES.contains(x))
+            .map_or("None".to_owned(), |x| {
+                format!("Some(Base::{})", base_name(x))
+            });
+        writeln!(output,
+            "production: Production {{ producer: \"{}\", train_time: duration!({}), warp_cooldown: {}, morph_from: {morph_from}, morph_cost: Cost {{ minerals: const_real!({}), gas: const_real!({}), supply: const_real!({}), build_time: duration!({}), }}, }},",
+            production.producer,
+            production.time,
+            production.warp_cooldown.map_or("None".to_owned(), |x| format!("Some(duration!({x}))")),
+            production.morph_cost.minerals,
+            production.morph_cost.gas,
+            production.morph_cost.supply,
+            if production.morph_from.is_some() { production.time } else { 0.0 },
+        );
+
         // ---------------------------------------- misc ---------------------------------------- //
+        writeln!(output, "size: const_real!({}),", unit.val_of("Radius"));
+
         writeln!(
             output,
-            "size: const_real!({}),",
-            unit.try_val_of("Radius").unwrap_or("0.5")
+            "cargo_size: const_real!({}),",
+            unit.try_val_of("CargoSize").unwrap_or("0")
         );
 
+        let transport = unit
+            .children
+            .get("AbilArray")
+            .into_iter()
+            .flat_map(|x| x.children.values())
+            .filter_map(|x| x.attrs.get("Link"))
+            .filter_map(|link| ABIL_MAP.get(link))
+            .find(|abil| abil.kind == "CAbilTransport");
         writeln!(
             output,
-            "cargo_size: const_real!({}),",
-            unit.try_val_of("CargoSize").unwrap_or("0")
+            "cargo_capacity: const_real!({}),",
+            transport
+                .and_then(|x| x.try_val_of("TotalCargoSpace"))
+                .unwrap_or("0")
+        );
+        writeln!(
+            output,
+            "max_cargo_size: const_real!({}),",
+            transport
+                .and_then(|x| x.try_val_of("MaxCargoSize"))
+                .unwrap_or("0")
         );
 
         writeln!(
@@ -166,7 +197,7 @@ pub fn write_units(units: &[(&'static &'static str, &'static Tag)]) -> String {
         writeln!(
             output,
             "push_priority: const_real!({}),",
-            unit.try_val_of("PushPriority").unwrap_or("10")
+            unit.val_of("PushPriority")
         );
 
         writeln!(
@@ -181,54 +212,46 @@ pub fn write_units(units: &[(&'static &'static str, &'static Tag)]) -> String {
             unit.try_val_of("EnergyMax").unwrap_or("0")
         );
 
-        // --------------------------------------- weapons -------------------------------------- //
-        let temp = Map::default();
-        let weapon_array = unit
-            .children
-            .get("WeaponArray")
-            .map(|x| &x.children)
-            .unwrap_or_else(|| {
-                if name == "BroodlingDefault" {
-                    UNIT_MAP["Broodling"].child_array("WeaponArray")
-                } else {
-                    &temp
-                }
-            });
-
-        let mut count = 0;
-        write!(output, "weapons: [");
-
-        for (k, v) in weapon_array {
-            if k.is_empty()
-                || name == "Carrier" // ignore carrier's pseudo "launch interceptors" weapon
-                || v.attrs.get("Link").is_none()
-                || v.attrs["Link"].is_empty()
-                || v.attrs["Link"] == "Talons"
-                // absolute hack because queen is the only unit that truly has 3 weapons and i'm beyond caring
-                || v.attrs["Link"].ends_with("Fake")
-            {
-                continue;
-            }
-            count += 1;
-            if count > 2 {
-                panic!("More than 2 weapons for unit {name}");
-            }
+        let priority = match unit.try_val_of("AttackTargetPriority").unwrap_or("20") {
+            "0" => "None",
+            "10" => "Cocoon",
+            "11" => "Building",
+            "19" => "Low",
+            "20" => "Normal",
+            x => panic!("unknown target priority {x} for unit {name}"),
+        };
+        writeln!(output, "target_priority: ThreatLevel::{priority},");
 
-            writeln!(
-                output,
-                "Some(Weapon::{}_{}), ",
-                name.to_ascii_uppercase(),
-                v.attrs["Link"].to_ascii_uppercase()
-            );
-        }
-        if count == 0 {
-            writeln!(output, "None, None,");
-        } else if count == 1 {
-            writeln!(output, "None,");
-        }
+        // --------------------------------------- weapons -------------------------------------- //
+        let links = unit_weapons(id);
+        let weapons: Vec<String> = links
+            .iter()
+            .map(|l| {
+                format!(
+                    "Weapon::{}_{}",
+                    name.to_ascii_uppercase(),
+                    l.to_ascii_uppercase()
+                )
+            })
+            .collect();
+
+        writeln!(output, "weapons: Cow::Borrowed(&[{}]),", weapons.join(", "));
+        writeln!(output, "}};\n");
+    }
 
-        writeln!(output, "],\n}};\n");
+    writeln!(
+        output,
+        "/// Returns the default stats for `base`, or `None` if there's no generated data for it \
+        (e.g.\n/// `Base::Custom`)"
+    );
+    writeln!(output, "pub fn from_base(base: Base) -> Option<Self> {{");
+    writeln!(output, "Some(match base {{");
+    for name in names {
+        writeln!(output, "Base::{name} => Self::{},", name.to_uppercase());
     }
+    writeln!(output, "_ => return None,");
+    writeln!(output, "}})");
+    writeln!(output, "}}");
 
     writeln!(output, "}}");
 
@@ -242,29 +265,12 @@ pub fn write_weapons(units: &[(&'static &'static str, &'static Tag)]) -> String
         "use crate::{{const_real, duration, unit::*,}};\n\nimpl Weapon {{"
     );
 
-    let upgrades = get_upgrades();
-
     for (name, unit) in units {
         // skip carrier because interceptor launch isn't really a weapon
-        if !VALID_UNITS.contains(name) || **name == "Carrier" {
+        if !(VALID_UNITS.contains(name) || VALID_STRUCTURES.contains(name)) || **name == "Carrier" {
             continue;
         }
-        let name = match **name {
-            "LurkerMP" => "Lurker",
-            "LurkerMPBurrowed" => "LurkerBurrowed",
-            "LurkerMPEgg" => "LurkerEgg",
-            "ThorAP" => "ThorAlt",
-            "VikingAssault" => "VikingGround",
-            "VikingFighter" => "VikingAir",
-            "SwarmHostMP" => "SwarmHost",
-            "LocustMP" => "Locust",
-            "LocustMPFlying" => "LocustFlying",
-            // "QueenMP" => "Queen",
-            "HellionTank" => "Hellbat",
-            "LiberatorAG" => "LiberatorSieged",
-            "BroodlingDefault" => "Broodling",
-            x => x,
-        };
+        let name = base_name(name);
 
         // let if + let else should be a crime
         let Some(weapons) = (if name != "Broodling" {
@@ -279,26 +285,10 @@ pub fn write_weapons(units: &[(&'static &'static str, &'static Tag)]) -> String
             continue;
         };
 
-        for (_idx, w) in &weapons.children {
-            if w.attrs.get("Link").is_none()
-                || w.attrs["Link"].is_empty() // links are blank if weapon was removed
-                || w.attrs["Link"].ends_with("Fake")
-            // handles things like siege tank's "90mmCannonsFake" placeholder
-            {
-                continue;
-            }
-
-            let w_name = w.attrs["Link"];
-
+        for w_name in weapon_links(&weapons.children) {
             let weapon = WEAPON_MAP.get(w_name).unwrap();
-            let de_name = weapon
-                .try_val_of("DisplayEffect")
-                .unwrap_or_else(|| weapon.attrs["id"]);
-
-            let display_effect = match de_name {
-                "VolatileBurst" => &EFFECT_MAP["VolatileBurstU"],
-                _ => EFFECT_MAP.get(de_name).unwrap(),
-            };
+            let de_name = display_effect_id(weapon);
+            let display_effect = EFFECT_MAP.get(de_name).unwrap();
 
             let e_name = weapon
                 .try_val_of("Effect")
@@ -310,28 +300,19 @@ pub fn write_weapons(units: &[(&'static &'static str, &'static Tag)]) -> String
                 output,
                 "pub const {}_{}: Weapon = Weapon {{",
                 name.to_ascii_uppercase(),
-                w.attrs["Link"].to_ascii_uppercase()
+                w_name.to_ascii_uppercase()
             );
 
             // ---------------------------------- melee/ranged ---------------------------------- //
 
-            // we have to check parent first and possibly overwrite it because of things like viking
-            let mut kind = if let Some(p) = display_effect.attrs.get("parent") {
-                match *p {
-                    "DU_WEAP_MISSILE" | "DU_WEAP" => "Hitscan",
-                    _ => "Melee",
-                }
-            } else {
+            // the damage effect's Kind can't be used here, melee splash attacks (e.g. ultralisk,
+            // baneling) have Kind "Splash" just like siege tanks do
+            let mut kind = if weapon_option(weapon, "Melee").unwrap_or(false) {
                 "Melee"
+            } else {
+                "Hitscan"
             };
 
-            if let Some(x) = display_effect.try_val_of("Kind") {
-                match x {
-                    "Ranged" | "Splash" => kind = "Hitscan",
-                    _ => kind = "Melee",
-                }
-            }
-
             // check for LaunchMissile effect, indicating a non-hitscan weapon
             if kind == "Hitscan" && w_effect.is_some_and(|x| x.kind == "CEffectLaunchMissile")
                 || weapon.children.get("PeriodicEffectArray").is_some_and(|x| {
@@ -349,36 +330,45 @@ pub fn write_weapons(units: &[(&'static &'static str, &'static Tag)]) -> String
 
             assert_eq!(display_effect.kind, "CEffectDamage");
 
-            let dmg = display_effect.try_val_of("Amount").unwrap_or_else(|| {
-                // some weapons like roach melee attack derive most of their stats from a parent
-                // so sometimes the Amount value isn't present in the display effect we have
-
-                let parent = display_effect.attrs["parent"];
-                EFFECT_MAP[parent].val_of("Amount")
-            });
+            let dmg = display_effect.val_of("Amount");
 
             writeln!(output, "damage: const_real!({dmg}),",);
 
-            let (bonus_damage, bonus_vs) =
-                if let Some(bonus) = display_effect.children.get("AttributeBonus") {
-                    (
-                        bonus.attrs["value"],
-                        format!("Some(Flag::{})", bonus.attrs["index"]),
-                    )
-                } else {
-                    ("0", "None".to_owned())
-                };
+            // later mods remove bonuses by setting them to 0
+            let mut bonuses: Vec<String> = display_effect
+                .children
+                .get("AttributeBonus")
+                .map(|b| {
+                    b.children
+                        .values()
+                        .filter(|x| x.attrs["value"].parse::<f64>().is_ok_and(|v| v != 0.0))
+                        .map(|x| {
+                            format!(
+                                "Some(Bonus {{ vs: Flag::{}, damage: const_real!({}) }})",
+                                x.attrs["index"], x.attrs["value"]
+                            )
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            assert!(
+                bonuses.len() <= MAX_BONUSES,
+                "too many attribute bonuses on {w_name}"
+            );
+            bonuses.resize(MAX_BONUSES, "None".to_owned());
 
-            writeln!(output, "bonus_damage: const_real!({bonus_damage}),");
-            writeln!(output, "bonus_vs: {bonus_vs},");
+            writeln!(output, "bonuses: [{}],", bonuses.join(", "));
 
             // ------------------------------------- target ------------------------------------- //
 
+            // required filters come before the `;`, excluded ones after
             let filters = weapon.val_of("TargetFilters");
+            let (require, exclude) = filters.split_once(';').unwrap_or((filters, ""));
 
-            let can_target = if filters.contains("Ground") {
+            let can_target = if require.split(',').any(|x| x == "Ground") {
                 "Ground"
-            } else if filters.contains("Air") {
+            } else if require.split(',').any(|x| x == "Air") {
                 "Flying"
             } else {
                 "Both"
@@ -386,23 +376,28 @@ pub fn write_weapons(units: &[(&'static &'static str, &'static Tag)]) -> String
 
             writeln!(output, "can_target: Collision::{can_target},");
 
-            // ------------------------------------ upgrades ------------------------------------ //
-
-            // upgrade lookup fails on units with weapons that don't upgrade (i.e. workers)
-            // so we need to make sure we have a default for that
-            let up = upgrades.get(de_name).cloned().unwrap_or((0.0, 0.0));
+            // filters that only matter for things the sim doesn't model (e.g. Missile, Ally) are
+            // dropped
+            let known = |list: &str| {
+                list.split(',')
+                    .filter(|x| TARGET_FILTERS.contains(x))
+                    .map(|x| format!("Filter::{x}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
 
             writeln!(
                 output,
-                "upgrade_inc: [const_real!({}), const_real!({})],",
-                up.0, up.1
+                "filters: TargetFilters::new(&[{}], &[{}]),",
+                known(require),
+                known(exclude)
             );
 
             // -------------------------------- range/arc + slop -------------------------------- //
 
             let min_range = weapon.try_val_of("MinimumRange").unwrap_or("0");
-            let range = weapon.try_val_of("Range").unwrap_or("5");
-            let slop = weapon.try_val_of("RangeSlop").unwrap_or("0");
+            let range = weapon.val_of("Range");
+            let slop = weapon.val_of("RangeSlop");
 
             writeln!(
                 output,
@@ -411,19 +406,20 @@ pub fn write_weapons(units: &[(&'static &'static str, &'static Tag)]) -> String
             writeln!(output, "range_slop: const_real!({slop}),");
 
             let arc = weapon.try_val_of("Arc").unwrap_or("0");
-            let arc_slop = weapon.try_val_of("ArcSlop").unwrap_or("11.25");
+            let arc_slop = weapon.val_of("ArcSlop");
 
             writeln!(output, "arc: const_real!({arc}),");
             writeln!(output, "arc_slop: const_real!({arc_slop}),");
 
             // -------------------------------------- speed ------------------------------------- //
 
-            let speed = weapon.try_val_of("Period").unwrap_or("0.8332");
+            let speed = weapon.val_of("Period");
 
             writeln!(output, "attack_speed: duration!({speed}),");
 
-            let delay_min = weapon.try_val_of("RandomDelayMin").unwrap_or("-0.0625");
-            let delay_max = weapon.try_val_of("RandomDelayMax").unwrap_or("0.125");
+            // only legacy weapons have a random delay, strafing ones (interceptors) don't
+            let delay_min = weapon.try_val_of("RandomDelayMin").unwrap_or("0");
+            let delay_max = weapon.try_val_of("RandomDelayMax").unwrap_or("0");
 
             writeln!(
                 output,
@@ -432,8 +428,8 @@ pub fn write_weapons(units: &[(&'static &'static str, &'static Tag)]) -> String
 
             // ------------------------------ dmg point & backswing ----------------------------- //
 
-            let dmg_point = weapon.try_val_of("DamagePoint").unwrap_or("0.167");
-            let backswing = weapon.try_val_of("Backswing").unwrap_or("0.5");
+            let dmg_point = weapon.val_of("DamagePoint");
+            let backswing = weapon.val_of("Backswing");
 
             writeln!(output, "damage_point: duration!({dmg_point}),");
             writeln!(output, "backswing: duration!({backswing}),");
@@ -451,17 +447,39 @@ pub fn write_weapons(units: &[(&'static &'static str, &'static Tag)]) -> String
             // ----------------------------- multihit/search/effect ----------------------------- //
 
             /*
-                I'm just gonna manually enter the details of these. The potential layouts and nested
-                effects and other nonsense is more annoying to deal with than a bit of manual entry.
-                Most units that hit more than once, and any unit with a bouncing/AoE attack will
-                require weapon adjustments.
+                Bouncing/AoE attacks still need weapon adjustments by hand, but hits spread out over
+                time or space come from the weapon's persistent effect when it has one
             */
 
-            if let Some(p) = weapon.try_val_of("DisplayAttackCount") {
-                writeln!(output, "multihit: Multihit::Instant(const_real!({p})),");
-            } else {
-                writeln!(output, "multihit: Multihit::Single,");
-            }
+            let multihit = match persistent_hits(e_name) {
+                Some(Hits::Time(periods))
+                    if periods.iter().all(|x| x.parse::<f64>() == Ok(0.0)) =>
+                {
+                    format!("Multihit::Instant(const_real!({}))", periods.len())
+                }
+                Some(Hits::Time(periods)) => format!(
+                    "Multihit::TimeOffset(&[{}])",
+                    periods
+                        .iter()
+                        .map(|x| format!("duration!({x})"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                Some(Hits::Pos(dists)) => format!(
+                    "Multihit::PosOffset(&[{}])",
+                    dists
+                        .iter()
+                        .map(|x| format!("const_real!({x})"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                None => match weapon.try_val_of("DisplayAttackCount") {
+                    Some(p) => format!("Multihit::Instant(const_real!({p}))"),
+                    None => "Multihit::Single".to_owned(),
+                },
+            };
+
+            writeln!(output, "multihit: {multihit},");
 
             writeln!(output, "search: SearchType::Single,");
 
@@ -476,55 +494,659 @@ pub fn write_weapons(units: &[(&'static &'static str, &'static Tag)]) -> String
     output
 }
 
-fn get_upgrades() -> Map<&'static str, (f32, f32)> {
-    let mut upgrades = Map::default();
+pub fn write_upgrades(_units: &[(&'static &'static str, &'static Tag)]) -> String {
+    let mut output = String::new();
+    writeln!(
+        output,
+        "use crate::{{const_real, rate, unit::*,}};\n\nimpl Upgrade {{"
+    );
+
+    // the units (and weapon indices) that fire each weapon and damage effect
+    let mut weapons: Map<&str, Vec<(&str, usize)>> = Map::default();
+    let mut effects: Map<&str, Vec<(&str, usize)>> = Map::default();
+    for &id in VALID_UNITS.iter().chain(VALID_STRUCTURES.iter()) {
+        for (i, link) in unit_weapons(id).into_iter().enumerate() {
+            weapons.entry(link).or_default().push((id, i));
+            effects
+                .entry(display_effect_id(&WEAPON_MAP[link]))
+                .or_default()
+                .push((id, i));
+        }
+    }
+
+    let mut names = Vec::new();
+    for (&id, upgrade) in UPGRADE_MAP.iter() {
+        if upgrade.attrs.contains_key("default") {
+            continue;
+        }
+        // (unit, field, op, macro, value)
+        let mut resolved: Vec<(&str, String, &str, &str, f64)> = Vec::new();
+        let entries = upgrade
+            .children
+            .get("EffectArray")
+            .into_iter()
+            .flat_map(|x| x.children.values());
+        for entry in entries {
+            // references look like "Unit,Marine,LifeMax" or "Effect,GuassRifle,Amount"
+            let Some(mut parts) = entry.attrs.get("Reference").map(|x| x.splitn(3, ',')) else {
+                continue;
+            };
+            let (Some(catalog), Some(link), Some(field)) =
+                (parts.next(), parts.next(), parts.next())
+            else {
+                continue;
+            };
+
+            // non-numeric values (icons, models, filters) are never one of the fields below
+            let Some(mut value) = entry
+                .attrs
+                .get("Value")
+                .map_or(Some(0.0), |x| x.parse::<f64>().ok())
+            else {
+                continue;
+            };
+            let op = match entry.attrs.get("Operation").copied().unwrap_or("Add") {
+                "Add" => "Add",
+                "Subtract" => {
+                    value = -value;
+                    "Add"
+                }
+                "Multiply" => "Multiply",
+                "Set" => "Set",
+                _ => continue,
+            };
+
+            // speeds and regen are per game second, like in the unit table
+            let targets: Vec<(&str, String, &str)> = match (catalog, field) {
+                ("Unit", _)
+                    if !(VALID_UNITS.contains(&link) || VALID_STRUCTURES.contains(&link)) =>
+                {
+                    continue
+                }
+                ("Unit", field) => {
+                    let (field, scale) = match field {
+                        "LifeMax" => ("HullMax", "const_real"),
+                        "LifeArmor" => ("HullArmor", "const_real"),
+                        "LifeRegenRate" => ("HullRegen", "rate"),
+                        "ShieldsMax" => ("ShieldsMax", "const_real"),
+                        "ShieldArmor" => ("ShieldArmor", "const_real"),
+                        "EnergyStart" => ("EnergyStart", "const_real"),
+                        "EnergyMax" => ("EnergyMax", "const_real"),
+                        "Speed" => ("Speed", "rate"),
+                        "Acceleration" => ("Accel", "rate"),
+                        "SpeedMultiplierCreep" => ("CreepSpeed", "const_real"),
+                        _ => continue,
+                    };
+                    vec![(link, field.to_owned(), scale)]
+                }
+                ("Weapon", "Range") => weapons
+                    .get(link)
+                    .into_iter()
+                    .flatten()
+                    .map(|&(unit, i)| (unit, format!("Range({i})"), "const_real"))
+                    .collect(),
+                ("Weapon", "RateMultiplier") => weapons
+                    .get(link)
+                    .into_iter()
+                    .flatten()
+                    .map(|&(unit, i)| (unit, format!("AttackRate({i})"), "const_real"))
+                    .collect(),
+                ("Effect", "Amount") => effects
+                    .get(link)
+                    .into_iter()
+                    .flatten()
+                    .map(|&(unit, i)| (unit, format!("Damage({i})"), "const_real"))
+                    .collect(),
+                ("Effect", field) if field.starts_with("AttributeBonus[") => {
+                    let flag = &field["AttributeBonus[".len()..field.len() - 1];
+                    effects
+                        .get(link)
+                        .into_iter()
+                        .flatten()
+                        .map(|&(unit, i)| (unit, format!("Bonus({i}, Flag::{flag})"), "const_real"))
+                        .collect()
+                }
+                _ => continue,
+            };
+
+            for (unit, field, scale) in targets {
+                // multipliers don't have units
+                let scale = if op == "Multiply" {
+                    "const_real"
+                } else {
+                    scale
+                };
 
-    for (up_name, up) in UPGRADE_MAP.iter() {
-        if !WEAP_UPGRADES.contains(up_name) {
+                // some upgrades add to the same field more than once (e.g. adrenal glands' rate
+                // multiplier), which the sim applies as a single addition
+                match resolved
+                    .iter_mut()
+                    .find(|x| op == "Add" && x.2 == "Add" && x.0 == unit && x.1 == field)
+                {
+                    Some(x) => x.4 += value,
+                    None => resolved.push((unit, field, op, scale, value)),
+                }
+            }
+        }
+
+        if resolved.is_empty() {
             continue;
         }
 
-        let eff_array = &up.children["EffectArray"].children;
+        // newer upgrades don't have a race, they get it from the units they change
+        let race = upgrade
+            .try_val_of("Race")
+            .unwrap_or_else(|| UNIT_MAP[resolved[0].0].val_of("Race"));
+        let faction = match race {
+            "Prot" => "Protoss",
+            "Terr" => "Terran",
+            "Zerg" => "Zerg",
+            _ => continue,
+        };
+
+        let name = id.to_uppercase();
+        assert!(!names.contains(&name), "upgrade name {name} is used twice");
+
+        writeln!(output, "pub const {name}: Upgrade = Upgrade {{");
+        writeln!(output, "name: \"{id}\",");
+        writeln!(output, "faction: Faction::{faction},");
+        writeln!(output, "effects: &[");
+        for (unit, field, op, scale, value) in resolved {
+            writeln!(
+                output,
+                "UpgradeEffect {{ unit: Base::{}, field: UpgradeField::{field}, op: UpgradeOp::{op}, value: {scale}!({value}), }},",
+                base_name(unit)
+            );
+        }
+        writeln!(output, "],");
+        writeln!(output, "}};\n");
+
+        names.push(name);
+    }
+
+    writeln!(output, "pub const ALL: &[Upgrade] = &[");
+    for name in names {
+        writeln!(output, "Self::{name},");
+    }
+    writeln!(output, "];");
 
-        for (_idx, elmt) in eff_array {
-            if elmt.attrs.get("Reference").is_none() {
+    writeln!(output, "}}");
+
+    output
+}
+
+pub fn write_abilities(_units: &[(&'static &'static str, &'static Tag)]) -> String {
+    let mut output = String::new();
+    writeln!(
+        output,
+        "use crate::{{const_real, duration, unit::*,}};\n\nimpl Ability {{"
+    );
+
+    let mut names = Vec::new();
+    for &unit in VALID_UNITS.iter().chain(VALID_STRUCTURES.iter()) {
+        let links = UNIT_MAP[unit]
+            .children
+            .get("AbilArray")
+            .into_iter()
+            .flat_map(|x| x.children.values())
+            .filter_map(|x| x.attrs.get("Link"));
+
+        for &id in links {
+            let Some(abil) = ABIL_MAP.get(id) else {
+                continue;
+            };
+            // only spells, not moving, training, morphing, etc.
+            if !matches!(abil.kind, "CAbilEffectInstant" | "CAbilEffectTarget") {
                 continue;
             }
-            let mut reference = elmt.attrs["Reference"].split(',');
-            if reference.next().is_some_and(|x| x == "Effect") {
-                let link = reference.next().unwrap();
-                let entry: &mut (f32, f32) = upgrades.entry(link).or_default();
-                let modifier = reference.next().unwrap();
-
-                let value = *elmt.attrs.get("Value").unwrap_or(&"0");
-                match modifier {
-                    "Amount" => entry.0 = value.parse().unwrap(),
-                    _ => entry.1 = value.parse().unwrap(),
-                }
+            let name = id.to_uppercase();
+            if names.contains(&name) {
+                continue;
+            }
+
+            let cost = abil.children.get("Cost");
+            let vital = |index: &str| {
+                cost.and_then(|x| x.children.get("Vital"))
+                    .filter(|x| x.attrs.get("index") == Some(&index))
+                    .map_or("0", |x| x.attrs["value"])
+            };
+            // cooldowns shared with another ability (e.g. the marauder's stim) only have a link
+            let cooldown = cost
+                .and_then(|x| x.children.get("Cooldown"))
+                .and_then(|x| x.attrs.get("TimeUse"))
+                .copied()
+                .unwrap_or("0");
+
+            writeln!(output, "pub const {name}: Ability = Ability {{");
+            writeln!(output, "name: \"{id}\",");
+            writeln!(output, "energy_cost: const_real!({}),", vital("Energy"));
+            writeln!(output, "life_cost: const_real!({}),", vital("Life"));
+            writeln!(output, "cooldown: duration!({cooldown}),");
+            writeln!(
+                output,
+                "range: const_real!({}),",
+                abil.try_val_of("Range").unwrap_or("0")
+            );
+            writeln!(output, "}};\n");
+
+            names.push(name);
+        }
+    }
+
+    writeln!(output, "pub const ALL: &[Ability] = &[");
+    for name in names {
+        writeln!(output, "Self::{name},");
+    }
+    writeln!(output, "];");
+
+    writeln!(output, "}}");
+
+    output
+}
+
+/// The weapons a unit is generated with, in the order of `Unit::weapons`
+fn unit_weapons(id: &str) -> Vec<&'static str> {
+    // carrier's only weapon launches the interceptors, which are units of their own
+    if id == "Carrier" {
+        return Vec::new();
+    }
+    let unit = match id {
+        "BroodlingDefault" => &UNIT_MAP["Broodling"],
+        _ => &UNIT_MAP[id],
+    };
+    unit.children
+        .get("WeaponArray")
+        .map_or_else(Vec::new, |x| weapon_links(&x.children))
+}
+
+/// The damage effect a weapon's damage and upgrades are read from
+fn display_effect_id(weapon: &Tag) -> &'static str {
+    match weapon
+        .try_val_of("DisplayEffect")
+        .unwrap_or_else(|| weapon.id())
+    {
+        // the baneling's display effect is a set, the damage is in the unit version
+        "VolatileBurst" => "VolatileBurstU",
+        x => x,
+    }
+}
+
+/// The links of the weapons a unit actually fires, in the order the engine prefers them. Removed
+/// weapons are skipped, as are placeholders like the siege tank's "90mmCannonsFake" whose effect
+/// doesn't deal any damage. Weapons that start disabled (e.g. the oracle's) are kept since the sim
+/// doesn't model the abilities that toggle them
+fn weapon_links(weapon_array: &Map<&'static str, Tag>) -> Vec<&'static str> {
+    let mut links = Vec::new();
+
+    for w in weapon_array.values() {
+        let Some(&link) = w.attrs.get("Link") else {
+            continue;
+        };
+        // links are blank if the weapon was removed by emptying it rather than with `removed`
+        if link.is_empty() || w.attrs.get("removed").is_some_and(|x| *x == "1") {
+            continue;
+        }
+
+        // the battlecruiser's only weapon picks between the old ATS and ATA weapons with an effect
+        // switch on the target's plane, which is the same as having both
+        let fired: &[&'static str] = match link {
+            "BattlecruiserWeaponSwitch" => &["ATSLaserBattery", "ATALaserBattery"],
+            _ => std::slice::from_ref(&link),
+        };
+
+        for &link in fired {
+            let weapon = &WEAPON_MAP[link];
+            let e_name = weapon.try_val_of("Effect").unwrap_or_else(|| weapon.id());
+            let placeholder = EFFECT_MAP.get(e_name).is_some_and(|e| {
+                e.kind == "CEffectDamage"
+                    && e.try_val_of("Amount")
+                        .is_none_or(|x| x.parse::<f64>().is_ok_and(|v| v == 0.0))
+            });
+            if !placeholder {
+                links.push(link);
             }
         }
     }
 
-    upgrades
+    links
+}
+
+/// How a persistent effect spreads its hits out
+enum Hits {
+    /// One entry per hit, the delay after the previous hit (or after the persistent is created, for
+    /// the first one). Same as `Multihit::TimeOffset`
+    Time(Vec<&'static str>),
+    /// The distance in front of the attacker of each search
+    Pos(Vec<String>),
+}
+
+/// Follows `effect` to a `CEffectCreatePersistent` that hits more than once. Period and offset
+/// arrays shorter than `PeriodCount` repeat.
+///
+/// Effect sets are only followed if they contain a single effect. Sets of several persistents (e.g.
+/// the colossus' two lances, or the mothership's secondary beams) run them side by side, which
+/// `DisplayAttackCount` already describes
+fn persistent_hits(effect: &str) -> Option<Hits> {
+    let tag = EFFECT_MAP.get(effect)?;
+    match tag.kind {
+        "CEffectSet" => {
+            let effects = &tag.children.get("EffectArray")?.children;
+            if effects.len() != 1 {
+                return None;
+            }
+            persistent_hits(effects[0].attrs.get("value")?)
+        }
+        "CEffectCreatePersistent" => {
+            let count: usize = tag.try_val_of("PeriodCount")?.parse().ok()?;
+            if count < 2 {
+                return None;
+            }
+            let values = |array: &str| -> Vec<&'static str> {
+                tag.children
+                    .get(array)
+                    .map(|x| x.children.values().map(|y| y.attrs["value"]).collect())
+                    .unwrap_or_default()
+            };
+
+            // offsets are "x,y,z" relative to the attacker, with -y pointing forward. Offsets that
+            // don't move forward (e.g. sideways sweeps) aren't modelled
+            let dists: Vec<f64> = values("PeriodicOffsetArray")
+                .iter()
+                .map(|x| -x.split(',').nth(1).unwrap_or("0").parse::<f64>().unwrap())
+                .collect();
+            if dists.iter().any(|&x| x > 0.0) {
+                let dists = dists
+                    .iter()
+                    .cycle()
+                    .take(count)
+                    .map(|x| x.to_string())
+                    .collect();
+                return Some(Hits::Pos(dists));
+            }
+
+            let periods = values("PeriodicPeriodArray");
+            if periods.is_empty() {
+                return None;
+            }
+            Some(Hits::Time(
+                periods.into_iter().cycle().take(count).collect(),
+            ))
+        }
+        _ => None,
+    }
 }
 
-const WEAP_UPGRADES: [&str; 16] = [
-    "TerranInfantryWeapons",
-    "TerranVehicleWeapons",
-    "TerranShipWeapons",
-    "ProtossGroundWeapons",
-    "ProtossAirWeapons",
-    "ZergMeleeWeapons",
-    "ZergMissileWeapons",
-    "ZergFlyerWeapons",
-    "TerranInfantryWeaponsLevel1",
-    "TerranVehicleWeaponsLevel1",
-    "TerranShipWeaponsLevel1",
-    "ProtossGroundWeaponsLevel1",
-    "ProtossAirWeaponsLevel1",
-    "ZergMeleeWeaponsLevel1",
-    "ZergMissileWeaponsLevel1",
-    "ZergFlyerWeaponsLevel1",
+/// The `Base` variant name of a unit id
+fn base_name(id: &str) -> &str {
+    match id {
+        "LurkerMP" => "Lurker",
+        "LurkerMPBurrowed" => "LurkerBurrowed",
+        "LurkerMPEgg" => "LurkerEgg",
+        "ThorAP" => "ThorAlt",
+        "VikingAssault" => "VikingGround",
+        "VikingFighter" => "VikingAir",
+        "SwarmHostMP" => "SwarmHost",
+        "LocustMP" => "Locust",
+        "LocustMPFlying" => "LocustFlying",
+        // "QueenMP" => "Queen",
+        "HellionTank" => "Hellbat",
+        "LiberatorAG" => "LiberatorSieged",
+        "BroodlingDefault" => "Broodling",
+        x => x,
+    }
+}
+
+/// One ability that creates a unit
+#[derive(Debug, Clone, Copy)]
+struct Source {
+    /// The ability's class, e.g. `CAbilTrain` or `CAbilMorph`
+    kind: &'static str,
+    /// The unit with the ability
+    producer: &'static str,
+    time: f64,
+    /// The warp gate's cooldown after warping the unit in. Only set for `CAbilWarpTrain`
+    warp_cooldown: Option<f64>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Resources {
+    minerals: f64,
+    gas: f64,
+    supply: f64,
+}
+
+impl Resources {
+    /// Unit costs are totals, e.g. a baneling costs 50/25 including the zergling it came from
+    fn of(id: &str) -> Self {
+        let unit = &UNIT_MAP[id];
+        let cost = |x| {
+            unit.children
+                .get("CostResource")
+                .and_then(|c| c.try_val_of(x))
+                .map_or(0.0, |v| v.parse().unwrap())
+        };
+        Self {
+            minerals: cost("Minerals"),
+            gas: cost("Vespene"),
+            supply: unit
+                .try_val_of("Food")
+                .map_or(0.0, |x| -x.parse::<f64>().unwrap())
+                .max(0.0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Production {
+    producer: &'static str,
+    time: f64,
+    warp_cooldown: Option<f64>,
+    morph_from: Option<&'static str>,
+    /// What the morph costs on top of the unit it morphs from
+    morph_cost: Resources,
+}
+
+/// How many mode switches (sieging, burrowing, ...) are followed back to a unit that's produced
+const MODE_SWITCH_DEPTH: u32 = 2;
+
+/// Every train, build, warp, morph and merge ability, keyed by the unit it creates
+fn unit_sources() -> Map<&'static str, Vec<Source>> {
+    // templates (`default="1"`) share their abilities with every unit built on them
+    let mut producers: Map<&'static str, Vec<&'static str>> = Map::default();
+    for (&id, unit) in UNIT_MAP.iter() {
+        if unit.attrs.contains_key("default") {
+            continue;
+        }
+        let links = unit
+            .children
+            .get("AbilArray")
+            .into_iter()
+            .flat_map(|x| x.children.values())
+            .filter_map(|x| x.attrs.get("Link"));
+        for &link in links {
+            producers.entry(link).or_default().push(id);
+        }
+    }
+
+    let mut sources: Map<&'static str, Vec<Source>> = Map::default();
+    for (abil_id, abil) in ABIL_MAP.iter() {
+        let Some(candidates) = producers.get(abil_id) else {
+            continue;
+        };
+        // morphs list the cocoons/eggs the unit passes through before the unit itself
+        let infos: Vec<&Tag> = match abil.kind {
+            "CAbilTrain" | "CAbilBuild" | "CAbilWarpTrain" | "CAbilMorph" => abil
+                .children
+                .get("InfoArray")
+                .map(|x| x.children.values().collect())
+                .unwrap_or_default(),
+            "CAbilMerge" => abil.children.get("Info").into_iter().collect(),
+            _ => continue,
+        };
+
+        // build and warp abilities store the unit as an attribute, train abilities as a child
+        // that later mods remove rather than blank out
+        let units: Vec<Option<&'static str>> = infos
+            .iter()
+            .map(|info| {
+                info.attrs.get("Unit").copied().or_else(|| {
+                    info.children
+                        .get("Unit")
+                        .filter(|x| x.attrs.get("removed").is_none_or(|r| *r != "1"))
+                        .and_then(|x| x.attrs.get("value").copied())
+                })
+            })
+            .collect();
+
+        // cocoons carry the morph ability too (to cancel it), so skip anything the ability makes.
+        // Of the rest, units the sim knows about come first, e.g. overlords over the overlords
+        // with the speed upgrade
+        let Some(&producer) = candidates
+            .iter()
+            .filter(|x| !units.contains(&Some(**x)))
+            .min_by_key(|x| !(VALID_UNITS.contains(x) || VALID_STRUCTURES.contains(x)))
+        else {
+            continue;
+        };
+
+        let last = infos.len().saturating_sub(1);
+        for (i, (info, unit)) in infos.into_iter().zip(units).enumerate() {
+            let Some(unit) = unit.filter(|x| !x.is_empty()) else {
+                continue;
+            };
+            if abil.kind == "CAbilMorph" && i != last {
+                continue;
+            }
+
+            let time = if abil.kind == "CAbilMorph" {
+                let stats = info
+                    .children
+                    .get("SectionArray")
+                    .and_then(|x| x.children.get("Stats"))
+                    .and_then(|x| x.children.get("DurationArray"));
+                ["Delay", "Duration"]
+                    .iter()
+                    .filter_map(|x| stats.and_then(|s| s.children.get(*x)))
+                    .map(|x| x.attrs["value"].parse::<f64>().unwrap())
+                    .fold(0.0, |acc, x| acc + x)
+            } else {
+                info.attrs.get("Time").map_or(0.0, |x| x.parse().unwrap())
+            };
+
+            // newer versions moved the warp gate cooldown from the cooldown to a charge
+            let warp_cooldown = (abil.kind == "CAbilWarpTrain").then(|| {
+                let charge = info
+                    .children
+                    .get("Charge")
+                    .and_then(|x| x.try_val_of("TimeUse"));
+                let cooldown = info
+                    .children
+                    .get("Cooldown")
+                    .and_then(|x| x.attrs.get("TimeUse"));
+                [charge, cooldown.copied()]
+                    .into_iter()
+                    .flatten()
+                    .map(|x| x.parse::<f64>().unwrap())
+                    .find(|&x| x != 0.0)
+                    .unwrap_or(0.0)
+            });
+
+            sources.entry(unit).or_default().push(Source {
+                kind: abil.kind,
+                producer,
+                time,
+                warp_cooldown,
+            });
+        }
+    }
+
+    sources
+}
+
+/// Where a unit comes from. Trained and built units come from their producer, morphs (e.g.
+/// baneling, archon) from the unit they morph from. Mode switches that don't cost anything (e.g.
+/// sieging, burrowing) share the production of the unit they switch from, up to `depth` switches
+/// away
+fn production(
+    id: &'static str,
+    sources: &Map<&'static str, Vec<Source>>,
+    depth: u32,
+) -> Option<Production> {
+    let list = sources.get(id)?;
+
+    let warp_cooldown = list.iter().find_map(|x| x.warp_cooldown);
+    if let Some(x) = list
+        .iter()
+        .find(|x| matches!(x.kind, "CAbilTrain" | "CAbilBuild"))
+    {
+        return Some(Production {
+            producer: x.producer,
+            time: x.time,
+            warp_cooldown,
+            ..Default::default()
+        });
+    }
+
+    let mut morphs = list
+        .iter()
+        .filter(|x| matches!(x.kind, "CAbilMorph" | "CAbilMerge"));
+
+    for x in morphs.clone() {
+        let (total, from) = (Resources::of(id), Resources::of(x.producer));
+        let morph_cost = match x.kind {
+            // merges consume whole units, so they don't cost anything extra
+            "CAbilMerge" => Resources::default(),
+            // mode switch
+            _ if total == from => continue,
+            _ => Resources {
+                minerals: total.minerals - from.minerals,
+                gas: total.gas - from.gas,
+                supply: total.supply - from.supply,
+            },
+        };
+        return Some(Production {
+            producer: x.producer,
+            time: x.time,
+            warp_cooldown: None,
+            morph_from: Some(x.producer),
+            morph_cost,
+        });
+    }
+
+    if depth == 0 {
+        return None;
+    }
+    morphs.find_map(|x| production(x.producer, sources, depth - 1))
+}
+
+/// The value of one of the weapon's `Options` flags, if the weapon sets it
+fn weapon_option(weapon: &Tag, option: &str) -> Option<bool> {
+    weapon
+        .children
+        .get("Options")
+        .and_then(|x| x.children.get(option))
+        .map(|x| x.attrs["value"] == "1")
+}
+
+const MAX_BONUSES: usize = 3;
+
+/// Names of the `TargetFilters` entries that map to `Filter`s in the sim
+const TARGET_FILTERS: [&str; 15] = [
+    "Ground",
+    "Air",
+    "Light",
+    "Armored",
+    "Biological",
+    "Mechanical",
+    "Massive",
+    "Psionic",
+    "Structure",
+    "Heroic",
+    "Visible",
+    "Stasis",
+    "Invulnerable",
+    "Hidden",
+    "Dead",
 ];
 
 const VALID_UNITS: [&str; 70] = [
@@ -600,3 +1222,14 @@ const VALID_UNITS: [&str; 70] = [
     "Liberator",
     "LiberatorAG",
 ];
+
+/// Static defense. These are written out like any other unit, they just can't move
+const VALID_STRUCTURES: [&str; 7] = [
+    "PhotonCannon",
+    "ShieldBattery",
+    "Bunker",
+    "MissileTurret",
+    "PlanetaryFortress",
+    "SpineCrawler",
+    "SporeCrawler",
+];